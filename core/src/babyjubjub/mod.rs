@@ -9,10 +9,12 @@ use ff::{
     Field, PrimeField,
 };
 use constants::*;
-pub use constants::BASE_POINT;
+pub use constants::{BASE_POINT, ORDER, SUBORDER};
+pub use scalar::Fs;
 use serde::{Serialize, Deserialize};
 
-mod constants;
+pub(crate) mod constants;
+mod scalar;
 #[cfg(test)]
 mod tests;
 
@@ -67,13 +69,6 @@ impl Sub for Point {
     }
 }
 
-impl Point {
-    const O: Point = Point {
-        x: Fr::ZERO,
-        y: Fr::ONE,
-    };
-}
-
 impl Point {
     pub const ZERO: Point = Point {
         x: Fr::ZERO,
@@ -111,6 +106,19 @@ impl Point {
         r.affine()
     }
 
+    /// Whether the point satisfies `a * x^2 + y^2 = 1 + d * x^2 * y^2`.
+    pub fn is_on_curve(&self) -> bool {
+        let x2 = self.x.square();
+        let y2 = self.y.square();
+        A * x2 + y2 == Fr::ONE + D * x2 * y2
+    }
+
+    /// Whether the point is on the curve and in the prime order subgroup
+    /// generated by [`BASE_POINT`].
+    pub fn in_subgroup(&self) -> bool {
+        self.is_on_curve() && self.mul_scalar(&SUBORDER) == Point::ZERO
+    }
+
     pub fn compress(&self) -> [u8; 32] {
         let mut repr = self.y.to_repr().0;
         let sign = repr[31] | 0x80;
@@ -259,11 +267,10 @@ impl Deref for PublicKey {
 }
 
 impl PublicKey {
+    /// The joint key of every seat, three for sanma or four.
     pub fn aggregate(others: Vec<PublicKey>) -> PublicKey {
-        assert_eq!(others.len(), 4);
-        let a = others[0].0 + others[1].0;
-        let b = others[2].0 + others[3].0;
-        PublicKey((a + b).affine())
+        assert!(!others.is_empty());
+        PublicKey(others.iter().fold(Point::ZERO.projective(), |acc, pk| acc + pk.0).affine())
     }
 
     pub fn to_bytes(&self) -> [u8; 32] {
@@ -0,0 +1,56 @@
+use std::hash::Hash;
+use ff::*;
+use num_bigint::BigUint;
+use serde::{Serialize, Deserialize};
+
+use crate::bn128::{Fr, FrRepr};
+
+/// Scalar field of the prime order subgroup generated by `BASE_POINT`.
+#[derive(PrimeField)]
+#[PrimeFieldModulus = "2736030358979909402780800718157159386076813972158567259200215660948447373041"]
+#[PrimeFieldGenerator = "31"]
+#[PrimeFieldReprEndianness = "little"]
+#[derive(Hash)]
+#[allow(clippy::derived_hash_with_manual_eq)]
+pub struct Fs([u64; 4]);
+
+impl Fs {
+    pub fn from_bigint(n: &BigUint) -> Fs {
+        let modulus = BigUint::parse_bytes(&Fs::MODULUS.as_bytes()[2..], 16).unwrap();
+        let mut repr = FsRepr::default();
+        let bytes = (n % modulus).to_bytes_le();
+        repr.0[..bytes.len()].copy_from_slice(&bytes);
+        Fs::from_repr(repr).unwrap()
+    }
+
+    /// Reduce a base field element modulo the subgroup order.
+    pub fn from_fr(n: &Fr) -> Fs {
+        Fs::from_bigint(&n.to_bigint())
+    }
+
+    /// The same integer as a base field element, for use with
+    /// [`Point::mul_scalar`](super::Point::mul_scalar).
+    pub fn to_fr(&self) -> Fr {
+        Fr::from_repr(FrRepr(self.to_repr().0)).unwrap()
+    }
+
+    pub fn to_bigint(&self) -> BigUint {
+        BigUint::from_bytes_le(&self.to_repr().0)
+    }
+}
+
+impl Serialize for Fs {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_bigint().to_str_radix(10))
+    }
+}
+
+impl<'de> Deserialize<'de> for Fs {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de> {
+        let s = String::deserialize(deserializer)?;
+        Fs::from_str_vartime(&s)
+            .ok_or_else(|| serde::de::Error::custom("invalid scalar"))
+    }
+}
@@ -184,4 +184,21 @@ fn test_point_decompress1() {
     e_px_bytes.copy_from_slice(&expected_px_raw);
     let expected_px: Fr = Fr::from_repr_vartime(FrRepr(e_px_bytes)).unwrap();
     assert_eq!(&p.x, &expected_px);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_in_subgroup() {
+    assert!(Point::ZERO.in_subgroup());
+    assert!(BASE_POINT.in_subgroup());
+    assert!(BASE_POINT.mul_scalar(&Fr::from(12345u64)).in_subgroup());
+
+    // (0, -1) has order 2
+    let two_torsion = Point::new(Fr::ZERO, -Fr::ONE);
+    assert!(two_torsion.is_on_curve());
+    assert!(!two_torsion.in_subgroup());
+    assert!(!(BASE_POINT + two_torsion).affine().in_subgroup());
+
+    let off_curve = Point::new(BASE_POINT.x, BASE_POINT.y + Fr::ONE);
+    assert!(!off_curve.is_on_curve());
+    assert!(!off_curve.in_subgroup());
+}
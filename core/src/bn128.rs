@@ -9,6 +9,7 @@ use serde::{Serialize, Deserialize};
 #[PrimeFieldGenerator = "7"]
 #[PrimeFieldReprEndianness = "little"]
 #[derive(Hash)]
+#[allow(clippy::derived_hash_with_manual_eq)]
 pub struct Fr([u64; 4]);
 
 impl phf_shared::PhfHash for Fr {
@@ -29,7 +30,7 @@ impl phf_shared::FmtConst for Fr {
 
 impl phf_shared::PhfBorrow<Fr> for Fr {
     fn borrow(&self) -> &Fr {
-        &self
+        self
     }
 }
 
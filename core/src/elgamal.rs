@@ -2,6 +2,7 @@ use serde::{Serialize, Deserialize};
 
 use crate::babyjubjub::{PublicKey, SecretKey, Point, BASE_POINT};
 use crate::bn128::Fr;
+use ff::Field;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MaskedMessage {
@@ -18,19 +19,37 @@ impl MaskedMessage {
     }
 
     pub fn remask(&self, agg_pk: &PublicKey, randomness: &Fr) -> MaskedMessage {
-        let c0 = BASE_POINT.mul_scalar(&randomness) + self.c0;
-        let c1 = agg_pk.mul_scalar(&randomness) + self.c1;
+        let c0 = BASE_POINT.mul_scalar(randomness) + self.c0;
+        let c1 = agg_pk.mul_scalar(randomness) + self.c1;
         MaskedMessage{
             c0: c0.affine(), c1: c1.affine()
         }
     }
 
+    /// Remask with fresh randomness, e.g. before passing a single tile on to
+    /// another player. Returns the randomness used so the step can be audited.
+    pub fn rerandomize(&self, agg_pk: &PublicKey) -> (MaskedMessage, Fr) {
+        let randomness = Fr::random(rand::thread_rng());
+        (self.remask(agg_pk, &randomness), randomness)
+    }
+
     pub fn unmask(&self, sk: &SecretKey) -> MaskedMessage {
         MaskedMessage {
             c0: self.c0,
             c1: (self.c1 - self.c0.mul_scalar(&sk.0)).affine()
         }
     }
+
+    /// One key's share of the mask, sent to whoever should learn the tile.
+    /// Removing it is the same as [`MaskedMessage::unmask`] with that key.
+    pub fn decryption_share(&self, sk: &SecretKey) -> Point {
+        self.c0.mul_scalar(&sk.0)
+    }
+
+    /// The plaintext point, given the decryption share of every key.
+    pub fn decrypt(&self, shares: &[Point]) -> Point {
+        shares.iter().fold(self.c1, |m, share| (m - *share).affine())
+    }
 }
 
 
@@ -42,6 +61,7 @@ mod tests {
     use ff::Field;
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test() {
         let sks: [SecretKey; 4] = std::array::from_fn(|_| SecretKey::random());
         let pks = sks.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
@@ -61,4 +81,26 @@ mod tests {
             println!("{}: {:?}", idx, tile)
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_rerandomize() {
+        let sks: [SecretKey; 4] = std::array::from_fn(|_| SecretKey::random());
+        let agg_pk = PublicKey::aggregate(sks.iter().map(|sk| sk.public_key()).collect());
+        let masked = MaskedMessage::new(TILES[42].point).remask(&agg_pk, &Fr::random(rand::thread_rng()));
+
+        let (rerandomized, randomness) = masked.rerandomize(&agg_pk);
+        assert_ne!(rerandomized, masked);
+        assert_eq!(rerandomized, masked.remask(&agg_pk, &randomness));
+        let unmasked = sks.iter().fold(rerandomized, |acc, sk| acc.unmask(sk));
+        assert_eq!(lookup_tile(&unmasked.c1.x).unwrap(), TILES[42]);
+    }
+
+    #[test]
+    fn test_sanma_keys() {
+        let sks: [SecretKey; 3] = std::array::from_fn(|_| SecretKey::random());
+        let agg_pk = PublicKey::aggregate(sks.iter().map(|sk| sk.public_key()).collect());
+        let masked = MaskedMessage::new(TILES[42].point).remask(&agg_pk, &Fr::random(rand::thread_rng()));
+        let unmasked = sks.iter().fold(masked, |acc, sk| acc.unmask(sk));
+        assert_eq!(lookup_tile(&unmasked.c1.x).unwrap(), TILES[42]);
+    }
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rules::MultipleRon;
+
+use super::Action;
+
+/// What happens to a discard once every seat has answered.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Resolution {
+    /// Nobody claimed it, play moves on.
+    Pass,
+    /// The seats that win on it, in turn order from the discarder.
+    Ron(Vec<usize>),
+    /// A chi, pon or daiminkan.
+    Call { seat: usize, action: Action },
+    /// Three seats called ron where that is an abortive draw.
+    TripleRon,
+}
+
+fn rank(action: &Action) -> u8 {
+    match action {
+        Action::Ron => 3,
+        Action::Pon(_) | Action::Daiminkan(_) => 2,
+        Action::Chi(_) => 1,
+        _ => 0,
+    }
+}
+
+/// Resolve the answers of every seat to the discard of `from`, `None` while
+/// a seat other than the discarder has not answered.
+///
+/// Ron beats pon and kan, which beat chi. Between several rons
+/// `multiple_ron` decides, and any other tie goes to the seat first in turn
+/// order after the discarder. Only the answers decide the outcome, never
+/// the order they arrived in, so every client resolves them alike.
+pub fn resolve_claims(from: usize, answers: &[Option<Action>], multiple_ron: MultipleRon) -> Option<Resolution> {
+    let players = answers.len();
+    let in_turn_order: Vec<(usize, Action)> = (1..players)
+        .map(|i| (from + i) % players)
+        .map(|seat| answers[seat].map(|action| (seat, action)))
+        .collect::<Option<_>>()?;
+
+    let ron: Vec<usize> =
+        in_turn_order.iter().filter(|(_, action)| *action == Action::Ron).map(|(seat, _)| *seat).collect();
+    if !ron.is_empty() {
+        return Some(match multiple_ron {
+            MultipleRon::Atamahane => Resolution::Ron(vec![ron[0]]),
+            MultipleRon::DoubleRon if ron.len() >= 3 => Resolution::TripleRon,
+            MultipleRon::DoubleRon | MultipleRon::TripleRon => Resolution::Ron(ron),
+        });
+    }
+
+    // `max_by_key` keeps the last of equal keys, so search from the far end
+    let best = in_turn_order.into_iter().rev().filter(|(_, action)| rank(action) > 0).max_by_key(|(_, a)| rank(a));
+    Some(best.map_or(Resolution::Pass, |(seat, action)| Resolution::Call { seat, action }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(from: usize, claims: &[(usize, Action)]) -> Vec<Option<Action>> {
+        let mut answers = vec![Some(Action::Pass); 4];
+        answers[from] = None;
+        for &(seat, action) in claims {
+            answers[seat] = Some(action);
+        }
+        answers
+    }
+
+    #[test]
+    fn test_priority() {
+        let chi = Action::Chi([1, 2]);
+        let pon = Action::Pon([3, 4]);
+        let kan = Action::Daiminkan([5, 6, 7]);
+        let resolve = |from, claims: &[(usize, Action)]| {
+            resolve_claims(from, &answers(from, claims), MultipleRon::DoubleRon).unwrap()
+        };
+        assert_eq!(resolve(0, &[]), Resolution::Pass);
+        assert_eq!(resolve(0, &[(1, chi), (3, pon)]), Resolution::Call { seat: 3, action: pon });
+        assert_eq!(resolve(0, &[(1, chi), (2, kan)]), Resolution::Call { seat: 2, action: kan });
+        assert_eq!(resolve(2, &[(3, chi)]), Resolution::Call { seat: 3, action: chi });
+        assert_eq!(resolve(0, &[(1, pon), (3, Action::Ron)]), Resolution::Ron(vec![3]));
+
+        let mut pending = answers(0, &[]);
+        pending[2] = None;
+        assert_eq!(resolve_claims(0, &pending, MultipleRon::DoubleRon), None);
+    }
+
+    #[test]
+    fn test_multiple_ron() {
+        let two = answers(1, &[(0, Action::Ron), (3, Action::Ron)]);
+        let three = answers(1, &[(0, Action::Ron), (2, Action::Ron), (3, Action::Ron)]);
+        // in turn order from seat 1 the first is seat 3
+        assert_eq!(resolve_claims(1, &two, MultipleRon::Atamahane), Some(Resolution::Ron(vec![3])));
+        assert_eq!(resolve_claims(1, &two, MultipleRon::DoubleRon), Some(Resolution::Ron(vec![3, 0])));
+        assert_eq!(resolve_claims(1, &three, MultipleRon::Atamahane), Some(Resolution::Ron(vec![2])));
+        assert_eq!(resolve_claims(1, &three, MultipleRon::DoubleRon), Some(Resolution::TripleRon));
+        assert_eq!(resolve_claims(1, &three, MultipleRon::TripleRon), Some(Resolution::Ron(vec![2, 3, 0])));
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::babyjubjub::{Point, SecretKey};
+
+use super::Wall;
+
+/// Tiles in a starting hand, the dealer's fourteenth is their first draw.
+pub const HAND_TILES: usize = 13;
+
+/// A seat's decryption share for one dealt tile, sent privately to the seat
+/// that owns it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecryptionShare {
+    /// Deck index of the tile.
+    pub index: usize,
+    /// Seat the share is from.
+    pub from: usize,
+    pub share: Point,
+}
+
+impl DecryptionShare {
+    pub fn new(wall: &Wall, index: usize, from: usize, sk: &SecretKey) -> Self {
+        Self { index, from, share: wall.tile(index).decryption_share(sk) }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DealError {
+    UnknownSeat(usize),
+    /// The tile is not in any starting hand.
+    NotDealt(usize),
+    /// The owner of a tile never sends a share for it.
+    OwnTile { index: usize, seat: usize },
+    Duplicate { index: usize, from: usize },
+}
+
+impl Display for DealError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DealError::UnknownSeat(seat) => write!(f, "unknown seat {seat}"),
+            DealError::NotDealt(index) => write!(f, "tile {index} was not dealt"),
+            DealError::OwnTile { index, seat } => write!(f, "seat {seat} sent a share for its own tile {index}"),
+            DealError::Duplicate { index, from } => write!(f, "seat {from} already sent a share for tile {index}"),
+        }
+    }
+}
+
+impl std::error::Error for DealError {}
+
+/// The starting hands as deck indices, and the decryption shares each seat
+/// is still waiting on to read its own.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deal {
+    /// Deck indices of every seat's hand, in the order dealt.
+    hands: Vec<Vec<usize>>,
+    /// Shares for every dealt tile, by the seat they came from.
+    shares: BTreeMap<usize, Vec<Option<Point>>>,
+}
+
+impl Deal {
+    /// Deal from the live wall in the real order, four tiles to each seat
+    /// three times round starting with the dealer, then one each. `None` if
+    /// the wall runs out.
+    pub fn new(wall: &mut Wall, players: usize, dealer: usize) -> Option<Self> {
+        let mut hands = vec![vec![]; players];
+        for take in [4, 4, 4, 1] {
+            for seat in (0..players).map(|i| (dealer + i) % players) {
+                for _ in 0..take {
+                    hands[seat].push(wall.draw()?);
+                }
+            }
+        }
+        let shares = hands.iter().flatten().map(|&index| (index, vec![None; players])).collect();
+        Some(Self { hands, shares })
+    }
+
+    pub fn players(&self) -> usize {
+        self.hands.len()
+    }
+
+    /// Deck indices dealt to `seat`, which every other seat sends shares for.
+    pub fn hand(&self, seat: usize) -> &[usize] {
+        &self.hands[seat]
+    }
+
+    pub fn owner(&self, index: usize) -> Option<usize> {
+        self.hands.iter().position(|hand| hand.contains(&index))
+    }
+
+    /// Record a share, checking it is one the owner of the tile is waiting
+    /// on. The share itself can only be checked by the owner decoding it.
+    pub fn add_share(&mut self, share: DecryptionShare) -> Result<(), DealError> {
+        let DecryptionShare { index, from, share } = share;
+        if from >= self.players() {
+            return Err(DealError::UnknownSeat(from));
+        }
+        let owner = self.owner(index).ok_or(DealError::NotDealt(index))?;
+        if owner == from {
+            return Err(DealError::OwnTile { index, seat: from });
+        }
+        let slot = &mut self.shares.get_mut(&index).expect("every dealt tile has shares")[from];
+        if slot.is_some() {
+            return Err(DealError::Duplicate { index, from });
+        }
+        *slot = Some(share);
+        Ok(())
+    }
+
+    /// Shares `seat` is still waiting on, as `(index, from)`.
+    pub fn missing(&self, seat: usize) -> Vec<(usize, usize)> {
+        self.hands[seat]
+            .iter()
+            .flat_map(|&index| {
+                let shares = &self.shares[&index];
+                (0..self.players())
+                    .filter(move |&from| from != seat && shares[from].is_none())
+                    .map(move |from| (index, from))
+            })
+            .collect()
+    }
+
+    /// Deck indices `from` still has to send shares for.
+    pub fn owed_by(&self, from: usize) -> Vec<usize> {
+        (0..self.players())
+            .filter(|&seat| seat != from)
+            .flat_map(|seat| self.hands[seat].iter().copied())
+            .filter(|index| self.shares[index][from].is_none())
+            .collect()
+    }
+
+    /// Every share for the hand of `seat` has arrived.
+    pub fn is_ready(&self, seat: usize) -> bool {
+        self.missing(seat).is_empty()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        (0..self.players()).all(|seat| self.is_ready(seat))
+    }
+
+    /// The plaintext points of the hand of `seat`, removing its own share with
+    /// `sk`. `None` until every other share has arrived.
+    pub fn reveal(&self, wall: &Wall, seat: usize, sk: &SecretKey) -> Option<Vec<Point>> {
+        self.hands[seat]
+            .iter()
+            .map(|&index| {
+                let tile = wall.tile(index);
+                let mut shares = self.shares[&index]
+                    .iter()
+                    .enumerate()
+                    .filter(|&(from, _)| from != seat)
+                    .map(|(_, share)| *share)
+                    .collect::<Option<Vec<Point>>>()?;
+                shares.push(tile.decryption_share(sk));
+                Some(tile.decrypt(&shares))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ff::Field;
+
+    use super::*;
+    use crate::babyjubjub::PublicKey;
+    use crate::bn128::Fr;
+    use crate::tile::{get_richi_tiles, DeckSpec};
+
+    #[test]
+    fn test_order() {
+        let mut wall = Wall::new(get_richi_tiles(), 4, 5).unwrap();
+        let deal = Deal::new(&mut wall, 4, 1).unwrap();
+        // the live wall starts at tile 10, the dealer is seat 1
+        assert_eq!(deal.hand(1), [10, 11, 12, 13, 26, 27, 28, 29, 42, 43, 44, 45, 58]);
+        assert_eq!(deal.hand(2), [14, 15, 16, 17, 30, 31, 32, 33, 46, 47, 48, 49, 59]);
+        assert_eq!(deal.hand(0), [22, 23, 24, 25, 38, 39, 40, 41, 54, 55, 56, 57, 61]);
+        assert!(deal.hands.iter().all(|hand| hand.len() == HAND_TILES));
+        assert_eq!(wall.peek(), Some(62));
+        assert_eq!(deal.owner(58), Some(1));
+        assert_eq!(deal.owner(62), None);
+    }
+
+    #[test]
+    fn test_shares() {
+        let sks: [SecretKey; 4] = std::array::from_fn(|_| SecretKey::random());
+        let agg_pk = PublicKey::aggregate(sks.iter().map(|sk| sk.public_key()).collect());
+        let deck = DeckSpec::riichi().build();
+        let masked = deck.plaintext().iter().map(|m| m.remask(&agg_pk, &Fr::random(rand::thread_rng()))).collect();
+        let mut wall = Wall::new(masked, 4, 9).unwrap();
+        let mut deal = Deal::new(&mut wall, 4, 0).unwrap();
+
+        assert_eq!(deal.missing(2).len(), 3 * HAND_TILES);
+        assert_eq!(deal.owed_by(2).len(), 3 * HAND_TILES);
+        let index = deal.hand(0)[0];
+        assert_eq!(
+            deal.add_share(DecryptionShare::new(&wall, index, 0, &sks[0])),
+            Err(DealError::OwnTile { index, seat: 0 })
+        );
+        assert_eq!(
+            deal.add_share(DecryptionShare::new(&wall, wall.peek().unwrap(), 1, &sks[1])),
+            Err(DealError::NotDealt(wall.peek().unwrap()))
+        );
+        deal.add_share(DecryptionShare::new(&wall, index, 1, &sks[1])).unwrap();
+        assert_eq!(
+            deal.add_share(DecryptionShare::new(&wall, index, 1, &sks[1])),
+            Err(DealError::Duplicate { index, from: 1 })
+        );
+        assert!(deal.reveal(&wall, 0, &sks[0]).is_none());
+
+        for (from, sk) in sks.iter().enumerate() {
+            for index in deal.owed_by(from) {
+                deal.add_share(DecryptionShare::new(&wall, index, from, sk)).unwrap();
+            }
+        }
+        assert!(deal.is_complete());
+        for (seat, sk) in sks.iter().enumerate() {
+            let points = deal.reveal(&wall, seat, sk).unwrap();
+            for (point, &index) in points.iter().zip(deal.hand(seat)) {
+                assert_eq!(deck.lookup(&point.x), Some(deck.tiles()[index]));
+            }
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::babyjubjub::PublicKey;
+use crate::rules::{MatchLength, RuleSet};
+use crate::shuffle::ShuffleSession;
+use crate::tile::Honor;
+
+use super::round::RIICHI_DEPOSIT;
+use super::{Deal, Round, RoundEnd, RoundError, RoundResult, Table, Wall};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MatchError {
+    /// The match is over, no more hands are played.
+    Finished,
+    /// The round has not ended, or cannot be settled yet.
+    Unsettled,
+    /// The round was not played with this hand's dealer, round wind and
+    /// table, e.g. one already recorded.
+    WrongHand,
+}
+
+impl Display for MatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchError::Finished => write!(f, "the match is already over"),
+            MatchError::Unsettled => write!(f, "the round can't be settled yet"),
+            MatchError::WrongHand => write!(f, "the round is not the current hand of the match"),
+        }
+    }
+}
+
+impl std::error::Error for MatchError {}
+
+/// A seat's place, by points with ties going to the seat first from the
+/// first dealer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Standing {
+    pub seat: usize,
+    /// 0 for first place.
+    pub place: usize,
+    /// Points on the table, leftover riichi sticks included for first
+    /// place.
+    pub points: i32,
+    /// Points over `return_points`, with uma and oka.
+    pub score: i32,
+}
+
+/// A whole game, hand after hand, from the starting points to the final
+/// placements.
+///
+/// Seat 0 is the first dealer. Every hand is shuffled in a fresh
+/// [`ShuffleSession`], played as a [`Round`] and recorded with
+/// [`Match::record`], which moves the dealer on unless they keep the seat.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Match {
+    rules: RuleSet,
+    agg_pk: PublicKey,
+    table: Table,
+    round_wind: Honor,
+    dealer: usize,
+    results: Vec<RoundResult>,
+    finished: bool,
+}
+
+impl Match {
+    pub fn new(rules: RuleSet, agg_pk: PublicKey) -> Self {
+        let table = Table::new(&rules);
+        Self { rules, agg_pk, table, round_wind: Honor::East, dealer: 0, results: vec![], finished: false }
+    }
+
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    pub fn players(&self) -> usize {
+        self.rules.players as usize
+    }
+
+    /// Points, honba and riichi sticks going into the next hand.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    pub fn round_wind(&self) -> Honor {
+        self.round_wind
+    }
+
+    pub fn dealer(&self) -> usize {
+        self.dealer
+    }
+
+    /// Every hand played so far.
+    pub fn results(&self) -> &[RoundResult] {
+        &self.results
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// A new shuffle of the deck the rules play with, for the next hand.
+    pub fn shuffle_session(&self) -> Result<ShuffleSession, MatchError> {
+        if self.finished {
+            return Err(MatchError::Finished);
+        }
+        Ok(ShuffleSession::new(self.agg_pk, self.rules.deck().plaintext(), self.players()))
+    }
+
+    /// The next hand, on the wall built from the finished shuffle.
+    pub fn round(&self, wall: Wall, deal: &Deal) -> Result<Round, RoundError> {
+        Round::new(self.rules.clone(), wall, deal, self.table.clone(), self.dealer, self.round_wind)
+    }
+
+    /// Settle a finished hand and move on to the next one, or end the
+    /// match.
+    pub fn record(&mut self, round: &Round) -> Result<&RoundResult, MatchError> {
+        if self.finished {
+            return Err(MatchError::Finished);
+        }
+        if round.dealer() != self.dealer || round.round_wind() != self.round_wind || round.start() != &self.table {
+            return Err(MatchError::WrongHand);
+        }
+        let result = round.result().ok_or(MatchError::Unsettled)?;
+        self.settle(round.table(), result);
+        Ok(self.results.last().expect("just recorded"))
+    }
+
+    /// Apply `result` to `table`, the points with this hand's riichi
+    /// deposits taken, and advance.
+    fn settle(&mut self, table: &Table, result: RoundResult) {
+        let players = self.players();
+        let last_wind = match self.rules.length {
+            MatchLength::Tonpuusen => Honor::East,
+            MatchLength::Hanchan => Honor::South,
+        };
+        let all_last = self.round_wind >= last_wind && self.dealer == players - 1;
+        let won = matches!(result.end, RoundEnd::Win(_));
+
+        self.table.points = table.points.iter().zip(&result.deltas).map(|(points, delta)| points + delta).collect();
+        self.table.riichi_sticks = result.riichi_sticks;
+        self.table.honba = if result.renchan || !won { self.table.honba + 1 } else { 0 };
+        let renchan = result.renchan;
+        self.results.push(result);
+
+        let reached = self.table.points.iter().any(|&p| p >= self.rules.return_points);
+        let top = self.standings()[0].seat;
+        if self.rules.tobi && self.table.points.iter().any(|&p| p < 0) {
+            self.finished = true;
+        } else if self.round_wind > last_wind && reached {
+            // sudden death in the extension
+            self.finished = true;
+        } else if all_last && renchan {
+            let dealer_ahead = top == self.dealer && (!self.rules.west_extension || reached);
+            self.finished = self.rules.agari_yame && dealer_ahead;
+        }
+        if self.finished || renchan {
+            return;
+        }
+        self.dealer += 1;
+        if self.dealer == players {
+            self.dealer = 0;
+            let extend = self.rules.west_extension && self.round_wind == last_wind && !reached;
+            if self.round_wind >= last_wind && !extend {
+                self.finished = true;
+            } else {
+                self.round_wind = Honor::ALL[self.round_wind as usize + 1];
+            }
+        }
+    }
+
+    /// Places as things stand, final once the match is over.
+    pub fn standings(&self) -> Vec<Standing> {
+        let players = self.players();
+        let mut seats: Vec<usize> = (0..players).collect();
+        seats.sort_by_key(|&seat| -self.table.points[seat]);
+        let oka = (self.rules.return_points - self.rules.starting_points) * players as i32;
+        seats
+            .into_iter()
+            .enumerate()
+            .map(|(place, seat)| {
+                let mut points = self.table.points[seat];
+                let mut score = self.rules.uma.get(place).copied().unwrap_or(0);
+                if place == 0 {
+                    points += RIICHI_DEPOSIT * self.table.riichi_sticks as i32;
+                    score += oka;
+                }
+                score += points - self.rules.return_points;
+                Standing { seat, place, points, score }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{AbortiveDraw, Action, Phase, RoundEnd};
+
+    fn result(end: RoundEnd, deltas: [i32; 4], renchan: bool) -> RoundResult {
+        RoundResult {
+            end,
+            deltas: deltas.to_vec(),
+            scores: vec![],
+            tenpai: vec![false; 4],
+            nagashi: vec![],
+            renchan,
+            riichi_sticks: 0,
+        }
+    }
+
+    fn play(game: &mut Match, result: RoundResult) {
+        let table = game.table().clone();
+        game.settle(&table, result);
+    }
+
+    fn draw(renchan: bool) -> RoundResult {
+        result(RoundEnd::ExhaustiveDraw, [0; 4], renchan)
+    }
+
+    /// The wall and deal of an unshuffled plaintext deck, with `dealer`
+    /// dealt first.
+    fn deal(game: &Match, dealer: usize) -> (Wall, Deal) {
+        let tiles = game.rules().deck().plaintext();
+        let mut wall = Wall::new(tiles, game.players(), 7).unwrap();
+        let deal = Deal::new(&mut wall, game.players(), dealer).unwrap();
+        (wall, deal)
+    }
+
+    fn reveal_all(game: &Match, round: &mut Round) {
+        for (index, tile) in game.rules().deck().tiles().into_iter().enumerate() {
+            round.reveal(index, tile).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_progression() {
+        let mut game = Match::new(RuleSet::tenhou(), PublicKey::default());
+        assert!(game.shuffle_session().is_ok());
+
+        // a dealer win repeats with a honba, a non-dealer win moves on
+        play(&mut game, result(RoundEnd::Win(vec![0]), [12000, -4000, -4000, -4000], true));
+        assert_eq!((game.dealer(), game.table().honba), (0, 1));
+        play(&mut game, draw(false));
+        assert_eq!((game.dealer(), game.table().honba), (1, 2));
+        play(&mut game, result(RoundEnd::Abortive(AbortiveDraw::SuufonRenda), [0; 4], true));
+        assert_eq!((game.dealer(), game.table().honba), (1, 3));
+        play(&mut game, result(RoundEnd::Win(vec![2]), [0, -1000, 1000, 0], false));
+        assert_eq!((game.dealer(), game.table().honba), (2, 0));
+        play(&mut game, draw(false));
+        play(&mut game, draw(false));
+        assert_eq!((game.round_wind(), game.dealer()), (Honor::South, 0));
+        for _ in 0..3 {
+            play(&mut game, draw(false));
+        }
+        // the last hand, seat 0 is ahead
+        assert_eq!(game.dealer(), 3);
+        play(&mut game, draw(false));
+        assert!(game.is_finished());
+        assert_eq!(game.shuffle_session().err(), Some(MatchError::Finished));
+
+        let standings = game.standings();
+        assert_eq!(standings.iter().map(|s| s.seat).collect::<Vec<_>>(), [0, 2, 3, 1]);
+        assert_eq!(standings[0].points, 37000);
+        // 37000 - 30000 + 20000 uma + 20000 oka
+        assert_eq!(standings[0].score, 47000);
+        assert_eq!(standings.iter().map(|s| s.score).sum::<i32>(), 0);
+    }
+
+    #[test]
+    fn test_match_end() {
+        // busting ends the match at once
+        let mut game = Match::new(RuleSet::tenhou(), PublicKey::default());
+        play(&mut game, result(RoundEnd::Win(vec![0]), [26000, -26000, 0, 0], true));
+        assert!(game.is_finished());
+
+        // nobody at 30000 after South 4 goes into West, and ends as soon as
+        // someone is
+        let mut game = Match::new(RuleSet::tenhou(), PublicKey::default());
+        for _ in 0..8 {
+            play(&mut game, draw(false));
+        }
+        assert!(!game.is_finished());
+        assert_eq!((game.round_wind(), game.dealer()), (Honor::West, 0));
+        play(&mut game, result(RoundEnd::Win(vec![1]), [-5000, 5000, 0, 0], false));
+        assert!(game.is_finished());
+
+        // the dealer ahead in the last hand may stop
+        let rules = RuleSet { length: MatchLength::Tonpuusen, ..RuleSet::tenhou() };
+        let mut game = Match::new(rules, PublicKey::default());
+        for _ in 0..3 {
+            play(&mut game, draw(false));
+        }
+        play(&mut game, result(RoundEnd::Win(vec![3]), [-6000, -6000, -6000, 18000], true));
+        assert!(game.is_finished());
+
+        // without a West extension or agari yame the dealer plays on, and
+        // the match ends with the dealer's seat
+        let mut game = Match::new(RuleSet::wrc(), PublicKey::default());
+        for _ in 0..7 {
+            play(&mut game, draw(false));
+        }
+        play(&mut game, draw(true));
+        assert!(!game.is_finished());
+        play(&mut game, draw(false));
+        assert!(game.is_finished());
+    }
+
+    #[test]
+    fn test_record() {
+        let mut game = Match::new(RuleSet::tenhou(), PublicKey::default());
+        let (wall, deal) = self::deal(&game, 0);
+        let mut round = game.round(wall, &deal).unwrap();
+        reveal_all(&game, &mut round);
+        assert_eq!(game.record(&round).err(), Some(MatchError::Unsettled));
+
+        // a hand dealt by another seat is not the current one
+        let (wall, other) = self::deal(&game, 1);
+        let table = game.table().clone();
+        let wrong = Round::new(game.rules().clone(), wall, &other, table, 1, Honor::East).unwrap();
+        assert_eq!(game.record(&wrong).err(), Some(MatchError::WrongHand));
+
+        // play every draw out
+        while !round.is_finished() {
+            match *round.phase() {
+                Phase::Draw { seat } => round.apply(seat, Action::Tsumo).unwrap(),
+                Phase::Discard { seat, .. } => {
+                    round.apply(seat, Action::Discard(round.seat(seat).drawn.unwrap())).unwrap()
+                }
+                _ => {
+                    for seat in 0..4 {
+                        if round.legal_actions(seat).contains(&Action::Pass) {
+                            round.apply(seat, Action::Pass).unwrap();
+                        }
+                    }
+                }
+            }
+        }
+        let expected = round.result().unwrap();
+        assert_eq!(expected.end, RoundEnd::ExhaustiveDraw);
+        assert_eq!(game.record(&round).unwrap(), &expected);
+        assert_eq!(game.results(), std::slice::from_ref(&expected));
+
+        let points: Vec<i32> = round.table().points.iter().zip(&expected.deltas).map(|(p, d)| p + d).collect();
+        assert_eq!(game.table().points, points);
+        assert_eq!(game.table().honba, 1);
+        assert_eq!(game.dealer(), if expected.renchan { 0 } else { 1 });
+
+        // the same round again does not start from the table it left
+        assert_eq!(game.record(&round).err(), Some(MatchError::WrongHand));
+        assert_eq!((game.results().len(), game.table().honba), (1, 1));
+
+        // nor does a round of the right dealer on the first hand's table
+        let (wall, deal) = self::deal(&game, game.dealer());
+        let stale = Table::new(game.rules());
+        let mut replay = Round::new(game.rules().clone(), wall, &deal, stale, game.dealer(), Honor::East).unwrap();
+        reveal_all(&game, &mut replay);
+        assert_eq!(game.record(&replay).err(), Some(MatchError::WrongHand));
+    }
+}
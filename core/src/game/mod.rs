@@ -0,0 +1,15 @@
+pub mod claims;
+pub mod deal;
+pub mod match_state;
+pub mod result;
+pub mod round;
+pub mod wall;
+
+pub use claims::{resolve_claims, Resolution};
+pub use deal::{Deal, DealError, DecryptionShare, HAND_TILES};
+pub use match_state::{Match, MatchError, Standing};
+pub use result::{noten_payments, RoundResult, NOTEN_PAYMENT};
+pub use round::{
+    AbortiveDraw, Action, Phase, Round, RoundEnd, RoundError, RoundMeld, SeatState, Source, Table, Win, RIICHI_DEPOSIT,
+};
+pub use wall::{Wall, WallError, DEAD_WALL, MAX_KANS, MAX_RINSHAN};
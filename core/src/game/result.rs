@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+
+use crate::scoring::{count_dora, dora_from_indicator, score_hand, seat_wind, Payment, SanmaTsumo, Score, ScoredHand};
+use crate::tile::{BaseTile, Honor, Tile};
+
+use super::{Phase, Round, RoundEnd, Win};
+
+/// Points paid in all from noten to tenpai seats at an exhaustive draw.
+pub const NOTEN_PAYMENT: i32 = 3000;
+
+/// How a finished hand is settled.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundResult {
+    pub end: RoundEnd,
+    /// Points moved by seat, on top of the riichi deposits already taken
+    /// into [`Round::table`].
+    pub deltas: Vec<i32>,
+    /// Each win scored, in the order of [`Round::wins`].
+    pub scores: Vec<ScoredHand>,
+    /// Seats shown tenpai at an exhaustive draw.
+    pub tenpai: Vec<bool>,
+    /// Seats paid nagashi mangan.
+    pub nagashi: Vec<usize>,
+    /// The dealer keeps the seat for the next hand.
+    pub renchan: bool,
+    /// Riichi sticks left on the table for the next hand.
+    pub riichi_sticks: u32,
+}
+
+impl Round {
+    /// Settle the hand once it is finished, `None` before that or while a
+    /// dora indicator a win counts is not revealed.
+    pub fn result(&self) -> Option<RoundResult> {
+        let Phase::Finished(end) = self.phase() else {
+            return None;
+        };
+        let players = self.players();
+        let table = self.table();
+        let mut result = RoundResult {
+            end: end.clone(),
+            deltas: vec![0; players],
+            scores: vec![],
+            tenpai: vec![false; players],
+            nagashi: vec![],
+            renchan: true,
+            riichi_sticks: table.riichi_sticks,
+        };
+        match end {
+            RoundEnd::Win(seats) => {
+                for (i, win) in self.wins().iter().enumerate() {
+                    let scored = self.score(win)?;
+                    // the sticks go to the first winner from the discarder
+                    let sticks = if i == 0 { table.riichi_sticks } else { 0 };
+                    let payment = self.payment(&scored.score, win.seat, win.from, table.honba, sticks);
+                    add(&mut result.deltas, &payment.deltas);
+                    result.scores.push(scored);
+                }
+                result.renchan = seats.contains(&self.dealer());
+                result.riichi_sticks = 0;
+            }
+            RoundEnd::ExhaustiveDraw => {
+                result.tenpai = (0..players).map(|seat| self.is_tenpai(seat)).collect();
+                result.renchan = result.tenpai[self.dealer()];
+                if self.rules().nagashi_mangan {
+                    result.nagashi = (0..players).filter(|&seat| self.is_nagashi(seat)).collect();
+                }
+                if result.nagashi.is_empty() {
+                    result.deltas = noten_payments(&result.tenpai);
+                }
+                let mangan = Score::new(5, 30, 0, &self.rules().scoring);
+                for &seat in &result.nagashi {
+                    let payment = self.payment(&mangan, seat, None, 0, 0);
+                    add(&mut result.deltas, &payment.deltas);
+                }
+            }
+            RoundEnd::Abortive(_) => {}
+        }
+        Some(result)
+    }
+
+    /// Score a win with its dora, ura dora for riichi, and a dora for every
+    /// north set aside, plus any the indicators point at.
+    fn score(&self, win: &Win) -> Option<ScoredHand> {
+        let rules = self.rules();
+        let faces = |indices: Vec<usize>| indices.into_iter().map(|i| self.face(i)).collect::<Option<Vec<BaseTile>>>();
+        let indicators = faces(self.wall().dora_indicators())?;
+        let ura = if win.ctx.riichi && rules.ura_dora { faces(self.wall().ura_indicators())? } else { vec![] };
+        let wind = seat_wind(win.seat, self.dealer(), self.players());
+        let dora = count_dora(&win.hand, &indicators, &ura, wind, &rules.dora);
+        let north = Some(Tile::Honor(Honor::North));
+        let on_north =
+            indicators.iter().chain(&ura).filter(|i| dora_from_indicator(i.tile(), rules.dora.sanma) == north).count();
+        let kita = self.seat(win.seat).kita.len() as u32 * (1 + on_north as u32);
+        score_hand(&win.hand, &win.ctx, dora.total() + kita, rules)
+    }
+
+    /// Payments for a win, or nagashi mangan, with a sanma tsumo paid as
+    /// the rules say.
+    fn payment(&self, score: &Score, winner: usize, from: Option<usize>, honba: u32, sticks: u32) -> Payment {
+        let rules = self.rules();
+        let mut payment = Payment::new(score, self.players(), winner, self.dealer(), from, honba, sticks);
+        if rules.is_sanma() && from.is_none() && rules.scoring.sanma_tsumo == SanmaTsumo::Bisection {
+            payment.bisect_north(score, winner, self.dealer());
+        }
+        payment
+    }
+
+    /// Every discard of `seat` a terminal or honor, and none of them called.
+    fn is_nagashi(&self, seat: usize) -> bool {
+        let discards = &self.seat(seat).discards;
+        !discards.is_empty()
+            && discards.iter().all(|d| !d.called && self.face(d.index).is_some_and(|t| t.tile().is_yaochuu()))
+    }
+}
+
+/// Split [`NOTEN_PAYMENT`] between the noten and tenpai seats, nothing if
+/// all or none are tenpai.
+pub fn noten_payments(tenpai: &[bool]) -> Vec<i32> {
+    let ready = tenpai.iter().filter(|&&t| t).count() as i32;
+    let noten = tenpai.len() as i32 - ready;
+    if ready == 0 || noten == 0 {
+        return vec![0; tenpai.len()];
+    }
+    tenpai.iter().map(|&t| if t { NOTEN_PAYMENT / ready } else { -NOTEN_PAYMENT / noten }).collect()
+}
+
+fn add(deltas: &mut [i32], payment: &[i32]) {
+    for (delta, paid) in deltas.iter_mut().zip(payment) {
+        *delta += paid;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_noten_payments() {
+        assert_eq!(noten_payments(&[true, false, false, false]), [3000, -1000, -1000, -1000]);
+        assert_eq!(noten_payments(&[true, true, false, false]), [1500, 1500, -1500, -1500]);
+        assert_eq!(noten_payments(&[false, true, true, true]), [-3000, 1000, 1000, 1000]);
+        assert_eq!(noten_payments(&[true, true, true, true]), [0; 4]);
+        assert_eq!(noten_payments(&[true, false, false]), [3000, -1500, -1500]);
+    }
+}
@@ -0,0 +1,1196 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::hand::{waits, Hand};
+use crate::rules::RuleSet;
+use crate::scoring::{score_hand, seat_wind, WinContext};
+use crate::tile::{AkaDora, BaseTile, Honor, Meld, MeldKind, Relative, Tile};
+
+use super::claims::{resolve_claims, Resolution};
+use super::{Deal, Wall, MAX_KANS, MAX_RINSHAN};
+
+/// Something a seat does, naming tiles by their deck index.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Action {
+    /// Draw from the live wall, or the dead wall after a kan.
+    Tsumo,
+    Discard(usize),
+    /// Declare riichi with this discard.
+    Riichi(usize),
+    /// Call the discard with two tiles from the hand.
+    Chi([usize; 2]),
+    Pon([usize; 2]),
+    Daiminkan([usize; 3]),
+    /// Add a tile to an open pon.
+    Kakan(usize),
+    Ankan([usize; 4]),
+    /// Win on the discard, or on the tile added to a kakan.
+    Ron,
+    /// Win on the tile just drawn.
+    TsumoAgari,
+    /// Decline to claim a discard.
+    Pass,
+    /// Call off the hand on the first turn with nine different terminals
+    /// and honors.
+    KyuushuKyuuhai,
+    /// Set a north aside as a dora in sanma and draw a replacement. Not a
+    /// call, so ippatsu and the first go around carry on.
+    Kita(usize),
+}
+
+impl Action {
+    /// The same action with its tiles in ascending order, as
+    /// [`Round::legal_actions`] lists them.
+    pub fn normalized(mut self) -> Self {
+        match &mut self {
+            Action::Chi(tiles) | Action::Pon(tiles) => tiles.sort(),
+            Action::Daiminkan(tiles) => tiles.sort(),
+            Action::Ankan(tiles) => tiles.sort(),
+            _ => {}
+        }
+        self
+    }
+}
+
+/// Where the tile a seat is about to discard after came from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Source {
+    Wall,
+    /// A replacement draw after a kan.
+    Rinshan,
+    /// A chi or pon, with nothing drawn.
+    Call,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    /// `seat` draws from the live wall.
+    Draw { seat: usize },
+    /// `seat` discards, calls a closed or added kan, or wins.
+    Discard { seat: usize, source: Source },
+    /// Every other seat answers the discard, or the tile added to a kakan,
+    /// of `from` at `index`.
+    Claims { from: usize, index: usize, kakan: bool, answers: Vec<Option<Action>> },
+    /// `seat` draws a replacement from the dead wall.
+    Rinshan { seat: usize },
+    Finished(RoundEnd),
+}
+
+/// A win, with everything needed to score it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Win {
+    pub seat: usize,
+    /// The discarder for a ron.
+    pub from: Option<usize>,
+    pub hand: Hand,
+    pub ctx: WinContext,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoundEnd {
+    /// Won by one seat, or more on the same discard, in turn order from the
+    /// discarder.
+    Win(Vec<usize>),
+    /// The wall ran out.
+    ExhaustiveDraw,
+    Abortive(AbortiveDraw),
+}
+
+/// A hand called off before anyone wins or the wall runs out.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AbortiveDraw {
+    /// Nine different terminals and honors in a starting hand.
+    KyuushuKyuuhai,
+    /// The same wind as every seat's first discard.
+    SuufonRenda,
+    /// A fourth kan, not all of them by the same seat.
+    Suukaikan,
+    /// Every seat in riichi.
+    SuuchaRiichi,
+    /// Three seats ron the same discard, under
+    /// [`MultipleRon::DoubleRon`](crate::rules::MultipleRon::DoubleRon).
+    Sanchahou,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundMeld {
+    pub kind: MeldKind,
+    /// Deck indices, the called tile included.
+    pub tiles: Vec<usize>,
+    pub called: Option<usize>,
+    /// Seat the called tile came from.
+    pub from: Option<usize>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Discard {
+    pub index: usize,
+    /// The tile just drawn.
+    pub tsumogiri: bool,
+    pub riichi: bool,
+    /// Taken by another seat's call.
+    pub called: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeatState {
+    /// Deck indices of the closed tiles, the drawn tile last.
+    pub concealed: Vec<usize>,
+    pub drawn: Option<usize>,
+    pub melds: Vec<RoundMeld>,
+    pub discards: Vec<Discard>,
+    pub riichi: bool,
+    pub double_riichi: bool,
+    /// Norths set aside in sanma.
+    pub kita: Vec<usize>,
+    /// In riichi, with no call since and not yet discarded again.
+    pub ippatsu: bool,
+    /// Tiles let pass since the last discard, or since declaring riichi,
+    /// for furiten.
+    pub missed: Vec<usize>,
+}
+
+impl SeatState {
+    pub fn is_closed(&self) -> bool {
+        self.melds.iter().all(|m| !m.kind.is_open())
+    }
+}
+
+/// Points paid for declaring riichi, won by the next winner.
+pub const RIICHI_DEPOSIT: i32 = 1000;
+
+/// Points and sticks on the table as a hand starts.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Table {
+    pub points: Vec<i32>,
+    pub honba: u32,
+    pub riichi_sticks: u32,
+}
+
+impl Table {
+    /// The start of a match, everyone on the starting points.
+    pub fn new(rules: &RuleSet) -> Self {
+        Self { points: vec![rules.starting_points; rules.players as usize], honba: 0, riichi_sticks: 0 }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RoundError {
+    UnknownSeat(usize),
+    /// Not the seat's turn, or not an action this phase allows it.
+    Illegal { seat: usize, action: Action },
+    /// A tile revealed as two different faces.
+    Mismatch(usize),
+    /// The deal or table is for a different number of players.
+    Players,
+}
+
+impl Display for RoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoundError::UnknownSeat(seat) => write!(f, "unknown seat {seat}"),
+            RoundError::Illegal { seat, action } => write!(f, "{action:?} is not legal for seat {seat}"),
+            RoundError::Mismatch(index) => write!(f, "tile {index} was already revealed as another tile"),
+            RoundError::Players => write!(f, "the deal or table does not match the number of players"),
+        }
+    }
+}
+
+impl std::error::Error for RoundError {}
+
+/// One hand, from the deal to a win or a draw.
+///
+/// Tiles are deck indices into the [`Wall`]. Their faces are only known
+/// once [`Round::reveal`]ed, so each seat keeps its own copy of the round
+/// with its own tiles revealed. A tile must be revealed to every seat before
+/// an action that depends on its face: the discard and the hand's tiles for
+/// a call, all closed tiles of a win.
+///
+/// Checks on tiles a seat keeps hidden, riichi tenpai and furiten, pass
+/// while they are hidden and can be redone by anyone once they are revealed.
+#[derive(Clone, Debug)]
+pub struct Round {
+    rules: RuleSet,
+    wall: Wall,
+    dealer: usize,
+    round_wind: Honor,
+    /// The table as the hand started.
+    start: Table,
+    table: Table,
+    seats: Vec<SeatState>,
+    revealed: HashMap<usize, BaseTile>,
+    phase: Phase,
+    /// A call has been made, ending the first go around.
+    interrupted: bool,
+    wins: Vec<Win>,
+}
+
+impl Round {
+    /// Start from the starting hands of `deal`, with the dealer to draw.
+    pub fn new(
+        rules: RuleSet,
+        wall: Wall,
+        deal: &Deal,
+        table: Table,
+        dealer: usize,
+        round_wind: Honor,
+    ) -> Result<Self, RoundError> {
+        let players = rules.players as usize;
+        if deal.players() != players || table.points.len() != players {
+            return Err(RoundError::Players);
+        }
+        let seats = (0..players)
+            .map(|seat| SeatState { concealed: deal.hand(seat).to_vec(), ..SeatState::default() })
+            .collect();
+        Ok(Self {
+            rules,
+            wall,
+            dealer,
+            round_wind,
+            start: table.clone(),
+            table,
+            seats,
+            revealed: HashMap::new(),
+            phase: Phase::Draw { seat: dealer },
+            interrupted: false,
+            wins: vec![],
+        })
+    }
+
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    pub fn wall(&self) -> &Wall {
+        &self.wall
+    }
+
+    /// Points and sticks, with the riichi deposits of this hand taken.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Points and sticks as the hand started.
+    pub fn start(&self) -> &Table {
+        &self.start
+    }
+
+    pub fn players(&self) -> usize {
+        self.seats.len()
+    }
+
+    pub fn dealer(&self) -> usize {
+        self.dealer
+    }
+
+    pub fn round_wind(&self) -> Honor {
+        self.round_wind
+    }
+
+    pub fn seat(&self, seat: usize) -> &SeatState {
+        &self.seats[seat]
+    }
+
+    pub fn phase(&self) -> &Phase {
+        &self.phase
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self.phase, Phase::Finished(_))
+    }
+
+    /// The wins the round ended with, in the order of [`RoundEnd::Win`].
+    pub fn wins(&self) -> &[Win] {
+        &self.wins
+    }
+
+    /// The face of a tile, once revealed.
+    pub fn face(&self, index: usize) -> Option<BaseTile> {
+        self.revealed.get(&index).copied()
+    }
+
+    /// Record the decoded face of a deck index.
+    pub fn reveal(&mut self, index: usize, tile: BaseTile) -> Result<(), RoundError> {
+        match self.revealed.insert(index, tile) {
+            Some(previous) if previous != tile => {
+                self.revealed.insert(index, previous);
+                Err(RoundError::Mismatch(index))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Everything `seat` may do now, with the tiles revealed so far.
+    pub fn legal_actions(&self, seat: usize) -> Vec<Action> {
+        if seat >= self.players() {
+            return vec![];
+        }
+        match &self.phase {
+            Phase::Draw { seat: s } | Phase::Rinshan { seat: s } if *s == seat => vec![Action::Tsumo],
+            Phase::Discard { seat: s, source } if *s == seat => self.turn_actions(seat, *source),
+            Phase::Claims { from, index, kakan, answers } if *from != seat && answers[seat].is_none() => {
+                self.claim_actions(seat, *from, *index, *kakan)
+            }
+            _ => vec![],
+        }
+    }
+
+    fn turn_actions(&self, seat: usize, source: Source) -> Vec<Action> {
+        let state = &self.seats[seat];
+        // a hand in riichi is locked, only the drawn tile goes
+        let mut actions: Vec<Action> = match state.drawn {
+            Some(drawn) if state.riichi => vec![Action::Discard(drawn)],
+            _ => state.concealed.iter().map(|&i| Action::Discard(i)).collect(),
+        };
+        if source == Source::Call {
+            return actions;
+        }
+        let players = self.players();
+        if state.is_closed()
+            && !state.riichi
+            && self.wall.remaining() >= players
+            && self.table.points[seat] >= RIICHI_DEPOSIT
+        {
+            let tenpai = state.concealed.iter().filter(|&&i| self.tenpai_after(seat, i) != Some(false));
+            actions.extend(tenpai.map(|&i| Action::Riichi(i)));
+        }
+        if self.can_kan() {
+            for (_, indices) in self.by_face(seat) {
+                let Ok(tiles) = <[usize; 4]>::try_from(indices.as_slice()) else { continue };
+                let with_drawn = state.drawn.is_some_and(|d| tiles.contains(&d));
+                let locked = state.riichi && (!with_drawn || self.keeps_waits(seat, &tiles) == Some(false));
+                if !locked {
+                    actions.push(Action::Ankan(tiles));
+                }
+            }
+            for &index in &state.concealed {
+                let Some(face) = self.face(index) else { continue };
+                let pon = state
+                    .melds
+                    .iter()
+                    .any(|m| m.kind == MeldKind::Pon && self.face(m.tiles[0]).map(|t| t.tile()) == Some(face.tile()));
+                if pon {
+                    actions.push(Action::Kakan(index));
+                }
+            }
+        }
+        if self.rules.is_sanma() && self.wall.rinshan_drawn() < MAX_RINSHAN && !self.wall.is_exhausted() {
+            let north = Some(Tile::Honor(Honor::North));
+            // in riichi only the drawn north, which leaves the waits as they were
+            let norths = state
+                .concealed
+                .iter()
+                .filter(|&&i| self.face(i).map(|t| t.tile()) == north)
+                .filter(|&&i| !state.riichi || state.drawn == Some(i));
+            actions.extend(norths.map(|&i| Action::Kita(i)));
+        }
+        if self.win(seat, None, false).is_some() {
+            actions.push(Action::TsumoAgari);
+        }
+        let first_turn = !self.interrupted && state.discards.is_empty() && source == Source::Wall;
+        let yaochuu = self.by_face(seat).keys().filter(|t| t.is_yaochuu()).count();
+        if self.rules.abortive_draws && first_turn && yaochuu >= 9 {
+            actions.push(Action::KyuushuKyuuhai);
+        }
+        actions
+    }
+
+    /// Whether `seat` is tenpai without the tile at `discard`, `None` while
+    /// its closed tiles are not all revealed.
+    fn tenpai_after(&self, seat: usize, discard: usize) -> Option<bool> {
+        let closed: Vec<usize> = self.seats[seat].concealed.iter().copied().filter(|&i| i != discard).collect();
+        self.hand(seat, &closed).map(|hand| waits_on_unheld(&hand))
+    }
+
+    /// Whether an ankan of `tiles` after a riichi leaves the waits as they
+    /// were before the draw, `None` while the closed tiles are not all
+    /// revealed.
+    fn keeps_waits(&self, seat: usize, tiles: &[usize; 4]) -> Option<bool> {
+        let state = &self.seats[seat];
+        let before: Vec<usize> = state.concealed.iter().copied().filter(|&i| Some(i) != state.drawn).collect();
+        let before = waits(&self.hand(seat, &before)?);
+        let mut after = self.hand(seat, &state.concealed)?;
+        after.ankan(self.face(tiles[0])?.tile()).ok()?;
+        Some(waits(&after) == before)
+    }
+
+    fn claim_actions(&self, seat: usize, from: usize, index: usize, kakan: bool) -> Vec<Action> {
+        let mut actions = vec![Action::Pass];
+        if !self.is_furiten(seat) && self.win(seat, Some((from, index)), kakan).is_some() {
+            actions.push(Action::Ron);
+        }
+        // nothing but ron on a kakan or the last discard
+        if kakan || self.wall.is_exhausted() || self.seats[seat].riichi {
+            return actions;
+        }
+        let Some(called) = self.face(index).map(|t| t.tile()) else {
+            return actions;
+        };
+        let same: Vec<usize> = self.by_face(seat).remove(&called).unwrap_or_default();
+        for (a, i) in same.iter().enumerate() {
+            for j in &same[a + 1..] {
+                actions.push(Action::Pon([*i, *j]));
+            }
+        }
+        if same.len() == 3 && self.can_kan() {
+            actions.push(Action::Daiminkan([same[0], same[1], same[2]]));
+        }
+        // no chi in sanma
+        if seat == (from + 1) % self.players() && !self.rules.is_sanma() {
+            let known: Vec<(usize, Tile)> = self.known(seat);
+            for (a, &(i, x)) in known.iter().enumerate() {
+                for &(j, y) in &known[a + 1..] {
+                    if Meld::is_valid_shape(MeldKind::Chi, &[x, y, called]) {
+                        actions.push(Action::Chi([i.min(j), i.max(j)]));
+                    }
+                }
+            }
+        }
+        actions
+    }
+
+    /// A kan can be called while there are replacement tiles and the live
+    /// wall still has a tile to give up for them.
+    fn can_kan(&self) -> bool {
+        self.kans() < MAX_KANS && self.wall.rinshan_drawn() < MAX_RINSHAN && !self.wall.is_exhausted()
+    }
+
+    /// Kans declared by every seat.
+    fn kans(&self) -> usize {
+        self.seats.iter().flat_map(|s| &s.melds).filter(|m| m.kind.is_kan()).count()
+    }
+
+    /// The revealed closed tiles of `seat`, sorted by deck index.
+    fn known(&self, seat: usize) -> Vec<(usize, Tile)> {
+        let mut known: Vec<(usize, Tile)> = self.seats[seat]
+            .concealed
+            .iter()
+            .filter_map(|&i| self.face(i).map(|t| (i, t.tile())))
+            .collect();
+        known.sort();
+        known
+    }
+
+    fn by_face(&self, seat: usize) -> HashMap<Tile, Vec<usize>> {
+        let mut by_face: HashMap<Tile, Vec<usize>> = HashMap::new();
+        for (i, tile) in self.known(seat) {
+            by_face.entry(tile).or_default().push(i);
+        }
+        by_face
+    }
+
+    /// The win of `seat` on its drawn tile, or on the tile at `index`
+    /// discarded by `from`, if every closed tile is revealed and the hand
+    /// is complete with a yaku.
+    fn win(&self, seat: usize, ron: Option<(usize, usize)>, chankan: bool) -> Option<Win> {
+        let state = &self.seats[seat];
+        let players = self.players();
+        let mut closed = state.concealed.clone();
+        closed.extend(ron.map(|(_, index)| index));
+        let hand = self.hand(seat, &closed)?;
+        let win_tile = self.face(*closed.last()?)?.tile();
+        let tsumo = ron.is_none();
+        let mut ctx = WinContext::new(win_tile, tsumo, seat_wind(seat, self.dealer, players), self.round_wind);
+        ctx.riichi = state.riichi;
+        ctx.ippatsu = state.ippatsu && self.rules.ippatsu;
+        ctx.double_riichi = state.double_riichi;
+        ctx.chankan = chankan;
+        let source = match &self.phase {
+            Phase::Discard { source, .. } => Some(*source),
+            _ => None,
+        };
+        ctx.rinshan = tsumo && source == Some(Source::Rinshan);
+        ctx.last_tile = self.wall.is_exhausted() && !ctx.rinshan && !chankan;
+        if tsumo && !self.interrupted && state.discards.is_empty() {
+            ctx.tenhou = seat == self.dealer;
+            ctx.chiihou = seat != self.dealer;
+        }
+        score_hand(&hand, &ctx, 0, &self.rules)?;
+        Some(Win { seat, from: ron.map(|(from, _)| from), hand, ctx })
+    }
+
+    /// The decoded hand of `seat` with `closed` as its closed tiles, `None`
+    /// unless all of them and its melds are revealed.
+    fn hand(&self, seat: usize, closed: &[usize]) -> Option<Hand> {
+        let players = self.players();
+        let mut melds = vec![];
+        let mut aka = AkaDora::NONE;
+        for meld in &self.seats[seat].melds {
+            let tiles = meld.tiles.iter().map(|&i| self.face(i)).collect::<Option<Vec<BaseTile>>>()?;
+            for tile in tiles.iter().filter(|t| t.red) {
+                *aka.count_mut(tile.kind)? += 1;
+            }
+            let mut faces: Vec<Tile> = tiles.iter().map(|t| t.tile()).collect();
+            faces.sort();
+            melds.push(Meld {
+                kind: meld.kind,
+                tiles: faces,
+                called: meld.called.and_then(|i| self.face(i)).map(|t| t.tile()),
+                from: meld.from.and_then(|from| Relative::from_offset((from + players - seat) % players)),
+            });
+        }
+        let mut hand = Hand::from_parts(&[], melds, AkaDora::NONE, aka).ok()?;
+        for &i in closed {
+            hand.draw(&self.face(i)?).ok()?;
+        }
+        Some(hand)
+    }
+
+    /// Whether `seat` may not ron because one of its waits is in its own
+    /// discards, or it let one pass since its last discard, or at any time
+    /// since declaring riichi.
+    ///
+    /// Discards and passes are public, so any seat can check this once the
+    /// closed tiles of `seat` are revealed, as they are for a ron. Until
+    /// then it is `false`.
+    pub fn is_furiten(&self, seat: usize) -> bool {
+        let state = &self.seats[seat];
+        let Some(hand) = self.hand(seat, &state.concealed) else {
+            return false;
+        };
+        let waits = waits(&hand);
+        state
+            .discards
+            .iter()
+            .map(|d| d.index)
+            .chain(state.missed.iter().copied())
+            .filter_map(|i| self.face(i))
+            .any(|tile| waits.contains(&tile.tile()))
+    }
+
+    /// Whether `seat` is one tile from a win without counting on a fifth
+    /// copy of a tile it holds all four of.
+    ///
+    /// A hand is shown to be tenpai at an exhaustive draw, so it is `false`
+    /// until the closed tiles of `seat` are revealed.
+    pub fn is_tenpai(&self, seat: usize) -> bool {
+        let state = &self.seats[seat];
+        self.hand(seat, &state.concealed).is_some_and(|hand| waits_on_unheld(&hand))
+    }
+
+    /// Apply an action of `seat`, if it is one of its
+    /// [`Round::legal_actions`].
+    pub fn apply(&mut self, seat: usize, action: Action) -> Result<(), RoundError> {
+        if seat >= self.players() {
+            return Err(RoundError::UnknownSeat(seat));
+        }
+        let action = action.normalized();
+        if !self.legal_actions(seat).contains(&action) {
+            return Err(RoundError::Illegal { seat, action });
+        }
+        let players = self.players();
+        match action {
+            Action::Tsumo => {
+                let rinshan = matches!(self.phase, Phase::Rinshan { .. });
+                let index = if rinshan { self.wall.draw_rinshan() } else { self.wall.draw() };
+                let index = index.expect("a draw is only legal with tiles left");
+                let state = &mut self.seats[seat];
+                state.concealed.push(index);
+                state.drawn = Some(index);
+                let source = if rinshan { Source::Rinshan } else { Source::Wall };
+                self.phase = Phase::Discard { seat, source };
+            }
+            Action::Discard(index) | Action::Riichi(index) => {
+                let state = &mut self.seats[seat];
+                if !state.riichi {
+                    state.missed.clear();
+                }
+                state.ippatsu = false;
+                let riichi = matches!(action, Action::Riichi(_));
+                if riichi {
+                    state.riichi = true;
+                    state.double_riichi = state.discards.is_empty() && !self.interrupted;
+                }
+                state.concealed.retain(|&i| i != index);
+                let tsumogiri = state.drawn == Some(index);
+                state.drawn = None;
+                state.discards.push(Discard { index, tsumogiri, riichi, called: false });
+                self.phase = Phase::Claims { from: seat, index, kakan: false, answers: vec![None; players] };
+            }
+            Action::Ankan(tiles) => {
+                self.take(seat, &tiles, MeldKind::Ankan, None);
+                self.declare_kan(seat);
+            }
+            Action::Kakan(index) => {
+                let face = self.face(index).map(|t| t.tile());
+                let state = &mut self.seats[seat];
+                state.concealed.retain(|&i| i != index);
+                state.drawn = None;
+                let meld = state
+                    .melds
+                    .iter_mut()
+                    .find(|m| m.kind == MeldKind::Pon && self.revealed.get(&m.tiles[0]).map(|t| t.tile()) == face)
+                    .expect("a kakan is only legal on a pon");
+                meld.kind = MeldKind::Kakan;
+                meld.tiles.push(index);
+                self.phase = Phase::Claims { from: seat, index, kakan: true, answers: vec![None; players] };
+            }
+            Action::TsumoAgari => {
+                let win = self.win(seat, None, false).expect("a win is only legal on a complete hand");
+                self.wins.push(win);
+                self.phase = Phase::Finished(RoundEnd::Win(vec![seat]));
+            }
+            Action::Kita(index) => {
+                let state = &mut self.seats[seat];
+                state.concealed.retain(|&i| i != index);
+                state.drawn = None;
+                state.kita.push(index);
+                self.phase = Phase::Rinshan { seat };
+            }
+            Action::KyuushuKyuuhai => self.phase = Phase::Finished(RoundEnd::Abortive(AbortiveDraw::KyuushuKyuuhai)),
+            Action::Chi(_) | Action::Pon(_) | Action::Daiminkan(_) | Action::Ron | Action::Pass => {
+                if let Phase::Claims { answers, .. } = &mut self.phase {
+                    answers[seat] = Some(action);
+                }
+                self.resolve_claims();
+            }
+        }
+        Ok(())
+    }
+
+    /// Move tiles from the closed hand of `seat` into a meld.
+    fn take(&mut self, seat: usize, own: &[usize], kind: MeldKind, called: Option<(usize, usize)>) {
+        let state = &mut self.seats[seat];
+        state.concealed.retain(|i| !own.contains(i));
+        state.drawn = None;
+        let mut tiles = own.to_vec();
+        tiles.extend(called.map(|(_, index)| index));
+        state.melds.push(RoundMeld { kind, tiles, called: called.map(|(_, i)| i), from: called.map(|(from, _)| from) });
+    }
+
+    fn declare_kan(&mut self, seat: usize) {
+        self.interrupt();
+        if self.rules.kan_dora {
+            self.wall.reveal_kan_dora();
+        }
+        self.phase = Phase::Rinshan { seat };
+    }
+
+    /// A call or kan ends the first go around and every ippatsu.
+    fn interrupt(&mut self) {
+        self.interrupted = true;
+        for state in &mut self.seats {
+            state.ippatsu = false;
+        }
+    }
+
+    /// Once every seat has answered, apply the claim [`resolve_claims`]
+    /// picks.
+    fn resolve_claims(&mut self) {
+        let Phase::Claims { from, index, kakan, answers } = &self.phase else {
+            return;
+        };
+        let (from, index, kakan) = (*from, *index, *kakan);
+        let Some(resolution) = resolve_claims(from, answers, self.rules.multiple_ron) else {
+            return;
+        };
+        for (seat, answer) in answers.clone().into_iter().enumerate() {
+            if answer.is_some_and(|a| a != Action::Ron) {
+                self.seats[seat].missed.push(index);
+            }
+        }
+        // riichi stands once its discard is not ronned
+        let declared = !kakan && self.seats[from].discards.last().is_some_and(|d| d.riichi);
+        if declared && !matches!(resolution, Resolution::Ron(_)) {
+            self.table.points[from] -= RIICHI_DEPOSIT;
+            self.table.riichi_sticks += 1;
+            self.seats[from].ippatsu = true;
+        }
+        if !matches!(resolution, Resolution::Ron(_) | Resolution::TripleRon) {
+            if let Some(draw) = self.abortive_draw(kakan) {
+                self.phase = Phase::Finished(RoundEnd::Abortive(draw));
+                return;
+            }
+        }
+        match resolution {
+            Resolution::Ron(seats) => {
+                for &seat in &seats {
+                    let win = self.win(seat, Some((from, index)), kakan);
+                    self.wins.push(win.expect("a ron is only legal on a complete hand"));
+                }
+                self.phase = Phase::Finished(RoundEnd::Win(seats));
+            }
+            Resolution::TripleRon => self.phase = Phase::Finished(RoundEnd::Abortive(AbortiveDraw::Sanchahou)),
+            Resolution::Call { seat, action } => {
+                self.interrupt();
+                self.seats[from].discards.last_mut().expect("a claim is on a discard").called = true;
+                let called = Some((from, index));
+                match action {
+                    Action::Chi(tiles) => self.take(seat, &tiles, MeldKind::Chi, called),
+                    Action::Pon(tiles) => self.take(seat, &tiles, MeldKind::Pon, called),
+                    Action::Daiminkan(tiles) => self.take(seat, &tiles, MeldKind::Daiminkan, called),
+                    _ => unreachable!("only calls are resolved as one"),
+                }
+                if matches!(action, Action::Daiminkan(_)) {
+                    self.declare_kan(seat);
+                } else {
+                    self.phase = Phase::Discard { seat, source: Source::Call };
+                }
+            }
+            Resolution::Pass if kakan => self.declare_kan(from),
+            Resolution::Pass if self.wall.is_exhausted() => self.phase = Phase::Finished(RoundEnd::ExhaustiveDraw),
+            Resolution::Pass => self.phase = Phase::Draw { seat: (from + 1) % self.players() },
+        }
+    }
+
+    /// The abortive draw, if any, once a discard is not ronned.
+    fn abortive_draw(&self, kakan: bool) -> Option<AbortiveDraw> {
+        if !self.rules.abortive_draws || kakan {
+            return None;
+        }
+        let four = self.players() == 4;
+        let first: Vec<Option<BaseTile>> = self
+            .seats
+            .iter()
+            .map(|s| s.discards.first().filter(|_| s.discards.len() == 1).and_then(|d| self.face(d.index)))
+            .collect();
+        let wind = first[0].map(|t| t.tile()).filter(|t| matches!(t, Tile::Honor(h) if h.is_wind()));
+        if four && !self.interrupted && wind.is_some() && first.iter().all(|t| t.map(|t| t.tile()) == wind) {
+            return Some(AbortiveDraw::SuufonRenda);
+        }
+        if four && self.seats.iter().all(|s| s.riichi) {
+            return Some(AbortiveDraw::SuuchaRiichi);
+        }
+        let kan_seats = self.seats.iter().filter(|s| s.melds.iter().any(|m| m.kind.is_kan())).count();
+        if self.kans() == MAX_KANS && kan_seats > 1 {
+            return Some(AbortiveDraw::Suukaikan);
+        }
+        None
+    }
+}
+
+/// Whether `hand` waits on a tile it does not already hold all four of.
+fn waits_on_unheld(hand: &Hand) -> bool {
+    let all = hand.all_counts();
+    waits(hand).iter().any(|t| t.index().is_some_and(|i| all[i] < 4))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elgamal::MaskedMessage;
+    use crate::scoring::dora_from_indicator;
+    use crate::tile::{parse_hand, AkaDora};
+
+    /// A round over a plaintext deck with every tile revealed, the four
+    /// hands dealt to seats 0 to 3 and `draws` next on the live wall.
+    fn round(hands: [&str; 4], draws: &str) -> Round {
+        round_with(RuleSet::default(), &hands, draws)
+    }
+
+    fn round_with(rules: RuleSet, hands: &[&str], draws: &str) -> Round {
+        let players = hands.len();
+        let hands: Vec<Vec<Tile>> = hands.iter().map(|h| parse_hand(h).unwrap()).collect();
+        let mut stack = vec![];
+        for (take, from) in [(4, 0), (4, 4), (4, 8), (1, 12)] {
+            for hand in &hands {
+                stack.extend_from_slice(&hand[from..from + take]);
+            }
+        }
+        stack.extend(parse_hand(draws).unwrap());
+
+        let mut pool = rules.deck().tiles();
+        let mut order = Wall::new(plaintext(&pool), players, 2).unwrap();
+        let mut tiles = vec![None; pool.len()];
+        for tile in stack {
+            let found = pool.iter().position(|t| t.tile() == tile).unwrap();
+            tiles[order.draw().unwrap()] = Some(pool.remove(found));
+        }
+        let tiles: Vec<BaseTile> = tiles.into_iter().map(|t| t.unwrap_or_else(|| pool.remove(0))).collect();
+
+        let mut wall = Wall::new(plaintext(&tiles), players, 2).unwrap();
+        let deal = Deal::new(&mut wall, players, 0).unwrap();
+        let table = Table::new(&rules);
+        let mut round = Round::new(rules, wall, &deal, table, 0, Honor::East).unwrap();
+        for (index, tile) in tiles.into_iter().enumerate() {
+            round.reveal(index, tile).unwrap();
+        }
+        round
+    }
+
+    fn plaintext(tiles: &[BaseTile]) -> Vec<MaskedMessage> {
+        tiles.iter().map(|t| MaskedMessage::new(t.point)).collect()
+    }
+
+    const HANDS: [&str; 4] = ["123456789m1234p", "55667788p11223z", "234567s99s33567z", "111888s99p4m5567z"];
+
+    fn find(round: &Round, seat: usize, tile: &str) -> usize {
+        let tile: Tile = tile.parse().unwrap();
+        *round.seat(seat).concealed.iter().find(|&&i| round.face(i).unwrap().tile() == tile).unwrap()
+    }
+
+    /// Closed tiles of `seat` with the face `tile`, sorted.
+    fn faces(round: &Round, seat: usize, tile: Tile) -> Vec<usize> {
+        let mut found: Vec<usize> =
+            round.seat(seat).concealed.iter().copied().filter(|&i| round.face(i).unwrap().tile() == tile).collect();
+        found.sort();
+        found
+    }
+
+    fn pass_all(round: &mut Round) {
+        for seat in 0..4 {
+            if round.legal_actions(seat).contains(&Action::Pass) {
+                round.apply(seat, Action::Pass).unwrap();
+            }
+        }
+    }
+
+    /// Draw and discard `tile`, then pass.
+    fn turn(round: &mut Round, seat: usize, tile: &str) {
+        round.apply(seat, Action::Tsumo).unwrap();
+        round.apply(seat, Action::Discard(find(round, seat, tile))).unwrap();
+        pass_all(round);
+    }
+
+    #[test]
+    fn test_turns() {
+        let mut round = round(HANDS, "4m");
+        assert_eq!(round.phase(), &Phase::Draw { seat: 0 });
+        assert_eq!(round.legal_actions(1), vec![]);
+        assert_eq!(round.apply(1, Action::Tsumo), Err(RoundError::Illegal { seat: 1, action: Action::Tsumo }));
+
+        round.apply(0, Action::Tsumo).unwrap();
+        assert_eq!(round.seat(0).concealed.len(), 14);
+        let drawn = round.seat(0).drawn.unwrap();
+        let actions = round.legal_actions(0);
+        assert_eq!(actions.iter().filter(|a| matches!(a, Action::Discard(_))).count(), 14);
+        assert!(actions.contains(&Action::Riichi(drawn)));
+        assert!(!actions.contains(&Action::TsumoAgari));
+
+        round.apply(0, Action::Discard(drawn)).unwrap();
+        assert!(round.seat(0).discards[0].tsumogiri);
+        assert!(round.legal_actions(0).is_empty());
+        assert_eq!(round.legal_actions(2), vec![Action::Pass]);
+        round.apply(2, Action::Pass).unwrap();
+        assert_eq!(round.apply(2, Action::Pass), Err(RoundError::Illegal { seat: 2, action: Action::Pass }));
+        pass_all(&mut round);
+        assert_eq!(round.phase(), &Phase::Draw { seat: 1 });
+    }
+
+    #[test]
+    fn test_exhaustive_draw() {
+        let mut round = round(HANDS, "");
+        let mut draws = 0;
+        while !round.is_finished() {
+            match *round.phase() {
+                Phase::Draw { seat } => {
+                    round.apply(seat, Action::Tsumo).unwrap();
+                    draws += 1;
+                }
+                Phase::Discard { seat, .. } => {
+                    round.apply(seat, Action::Discard(round.seat(seat).drawn.unwrap())).unwrap()
+                }
+                _ => pass_all(&mut round),
+            }
+        }
+        assert_eq!(draws, 136 - 14 - 52);
+        assert_eq!(round.phase(), &Phase::Finished(RoundEnd::ExhaustiveDraw));
+        // seat 0 waits on 1p and 4p, seat 1 on 3z for chiitoitsu
+        let result = round.result().unwrap();
+        assert_eq!(result.tenpai, [true, true, false, false]);
+        assert_eq!(result.deltas, [1500, 1500, -1500, -1500]);
+        assert!(result.renchan);
+    }
+
+    #[test]
+    fn test_calls() {
+        let mut round = round(HANDS, "9p 7z 8p 2m 1s 3m 5m 6m 9p");
+        round.apply(0, Action::Tsumo).unwrap();
+        let nine = round.seat(0).drawn.unwrap();
+        round.apply(0, Action::Discard(nine)).unwrap();
+
+        // seat 1 can chi 78p in four ways, seat 3 can pon and wins over it
+        let chi = round.legal_actions(1).into_iter().filter(|a| matches!(a, Action::Chi(_))).count();
+        assert_eq!(chi, 4);
+        let sevens = [find(&round, 1, "7p"), find(&round, 1, "8p")];
+        round.apply(1, Action::Chi(sevens)).unwrap();
+        round.apply(2, Action::Pass).unwrap();
+        let mut pon = [0; 2];
+        pon.copy_from_slice(&faces(&round, 3, Tile::Pin(9)));
+        assert!(round.legal_actions(3).contains(&Action::Pon(pon)));
+        round.apply(3, Action::Pon(pon)).unwrap();
+        assert_eq!(round.phase(), &Phase::Discard { seat: 3, source: Source::Call });
+        assert!(round.seat(0).discards[0].called);
+        assert_eq!(round.seat(3).melds[0].tiles.len(), 3);
+        assert_eq!(round.seat(3).melds[0].from, Some(0));
+        // nothing but a discard after a call
+        assert!(round.legal_actions(3).iter().all(|a| matches!(a, Action::Discard(_))));
+
+        round.apply(3, Action::Discard(find(&round, 3, "7z"))).unwrap();
+        pass_all(&mut round);
+        assert_eq!(round.phase(), &Phase::Draw { seat: 0 });
+        turn(&mut round, 0, "7z");
+        round.apply(1, Action::Tsumo).unwrap();
+        round.apply(1, Action::Discard(find(&round, 1, "8p"))).unwrap();
+        // only the next seat may chi
+        assert!(!round.legal_actions(3).iter().any(|a| matches!(a, Action::Chi(_))));
+        pass_all(&mut round);
+
+        // ankan from the hand and kakan onto the pon each draw a replacement
+        turn(&mut round, 2, "5z");
+        round.apply(3, Action::Tsumo).unwrap();
+        let ones = faces(&round, 3, Tile::Sou(1));
+        assert_eq!(ones.len(), 4);
+        round.apply(3, Action::Ankan(ones.try_into().unwrap())).unwrap();
+        assert_eq!(round.phase(), &Phase::Rinshan { seat: 3 });
+        assert_eq!(round.wall().dora_indicators().len(), 2);
+        round.apply(3, Action::Tsumo).unwrap();
+        assert_eq!(round.phase(), &Phase::Discard { seat: 3, source: Source::Rinshan });
+        round.apply(3, Action::Discard(round.seat(3).drawn.unwrap())).unwrap();
+        pass_all(&mut round);
+        turn(&mut round, 0, "4m");
+        turn(&mut round, 1, "1z");
+        turn(&mut round, 2, "6z");
+        round.apply(3, Action::Tsumo).unwrap();
+        let kakan = round.seat(3).drawn.unwrap();
+        assert_eq!(round.face(kakan).unwrap().tile(), Tile::Pin(9));
+        round.apply(3, Action::Kakan(kakan)).unwrap();
+        assert!(matches!(round.phase(), Phase::Claims { kakan: true, .. }));
+        assert_eq!(round.legal_actions(0), vec![Action::Pass]);
+        pass_all(&mut round);
+        assert_eq!(round.phase(), &Phase::Rinshan { seat: 3 });
+        assert_eq!(round.seat(3).melds[0].kind, MeldKind::Kakan);
+    }
+
+    #[test]
+    fn test_wins() {
+        // tenhou
+        let mut round = round(HANDS, "4p");
+        round.apply(0, Action::Tsumo).unwrap();
+        assert!(round.legal_actions(0).contains(&Action::TsumoAgari));
+        round.apply(0, Action::TsumoAgari).unwrap();
+        assert_eq!(round.phase(), &Phase::Finished(RoundEnd::Win(vec![0])));
+        assert!(round.wins()[0].ctx.tenhou);
+        let result = round.result().unwrap();
+        assert_eq!(result.deltas, [48000, -16000, -16000, -16000]);
+        assert!(result.renchan);
+
+        let mut round = self::round(HANDS, "4m 4p");
+        turn(&mut round, 0, "4m");
+        round.apply(1, Action::Tsumo).unwrap();
+        assert!(!round.legal_actions(1).contains(&Action::TsumoAgari));
+        round.apply(1, Action::Discard(round.seat(1).drawn.unwrap())).unwrap();
+        assert_eq!(round.legal_actions(0), vec![Action::Pass, Action::Ron]);
+        round.apply(0, Action::Ron).unwrap();
+        pass_all(&mut round);
+        assert_eq!(round.phase(), &Phase::Finished(RoundEnd::Win(vec![0])));
+        let win = &round.wins()[0];
+        assert_eq!((win.seat, win.from), (0, Some(1)));
+        assert_eq!(win.ctx.win_tile, Tile::Pin(4));
+        assert!(!win.ctx.tsumo);
+
+        // seats 0 and 2 both wait on 4p, in turn order from seat 1 seat 2
+        // comes first
+        let hands = ["123456789m1234p", "55667788p11223z", "123456789s23p55z", "111888s99p4m5567z"];
+        let mut round = self::round(hands, "4m 4p");
+        turn(&mut round, 0, "4m");
+        round.apply(1, Action::Tsumo).unwrap();
+        round.apply(1, Action::Discard(round.seat(1).drawn.unwrap())).unwrap();
+        round.apply(0, Action::Ron).unwrap();
+        round.apply(2, Action::Ron).unwrap();
+        round.apply(3, Action::Pass).unwrap();
+        assert_eq!(round.phase(), &Phase::Finished(RoundEnd::Win(vec![2, 0])));
+        assert_eq!(round.wins().iter().map(|w| w.seat).collect::<Vec<_>>(), [2, 0]);
+    }
+
+    #[test]
+    fn test_furiten() {
+        // 4p drawn and let go is in seat 0's own discards
+        let mut round = round(HANDS, "4p 2m 3m 1p");
+        turn(&mut round, 0, "4p");
+        assert!(round.is_furiten(0));
+        turn(&mut round, 1, "2m");
+        turn(&mut round, 2, "3m");
+        round.apply(3, Action::Tsumo).unwrap();
+        round.apply(3, Action::Discard(round.seat(3).drawn.unwrap())).unwrap();
+        assert!(!round.legal_actions(0).contains(&Action::Ron));
+
+        // passing on 4p lasts until seat 0 discards again
+        let mut round = self::round(HANDS, "4m 4p 1p 2m 9s 1p");
+        turn(&mut round, 0, "4m");
+        round.apply(1, Action::Tsumo).unwrap();
+        round.apply(1, Action::Discard(round.seat(1).drawn.unwrap())).unwrap();
+        assert!(!round.is_furiten(0));
+        assert!(round.legal_actions(0).contains(&Action::Ron));
+        pass_all(&mut round);
+        assert!(round.is_furiten(0));
+        round.apply(2, Action::Tsumo).unwrap();
+        round.apply(2, Action::Discard(round.seat(2).drawn.unwrap())).unwrap();
+        assert_eq!(round.legal_actions(0), vec![Action::Pass]);
+        pass_all(&mut round);
+        turn(&mut round, 3, "4m");
+        turn(&mut round, 0, "9s");
+        assert!(!round.is_furiten(0));
+        round.apply(1, Action::Tsumo).unwrap();
+        round.apply(1, Action::Discard(round.seat(1).drawn.unwrap())).unwrap();
+        assert!(round.legal_actions(0).contains(&Action::Ron));
+
+        // after riichi a missed win lasts for the rest of the hand
+        let mut round = self::round(HANDS, "4m 4p 2m 3m 9s");
+        round.apply(0, Action::Tsumo).unwrap();
+        round.apply(0, Action::Riichi(round.seat(0).drawn.unwrap())).unwrap();
+        pass_all(&mut round);
+        round.apply(1, Action::Tsumo).unwrap();
+        round.apply(1, Action::Discard(round.seat(1).drawn.unwrap())).unwrap();
+        pass_all(&mut round);
+        turn(&mut round, 2, "2m");
+        turn(&mut round, 3, "3m");
+        turn(&mut round, 0, "9s");
+        assert!(round.is_furiten(0));
+    }
+
+    #[test]
+    fn test_riichi() {
+        let mut round = round(HANDS, "4m 4p");
+        round.apply(0, Action::Tsumo).unwrap();
+        let drawn = round.seat(0).drawn.unwrap();
+        // only discards that keep the hand tenpai
+        assert!(!round.legal_actions(0).contains(&Action::Riichi(find(&round, 0, "2p"))));
+        round.apply(0, Action::Riichi(drawn)).unwrap();
+        assert_eq!(round.table().riichi_sticks, 0);
+        pass_all(&mut round);
+        assert_eq!(round.table().points[0], 24000);
+        assert_eq!(round.table().riichi_sticks, 1);
+        assert!(round.seat(0).double_riichi && round.seat(0).ippatsu);
+
+        round.apply(1, Action::Tsumo).unwrap();
+        round.apply(1, Action::Discard(round.seat(1).drawn.unwrap())).unwrap();
+        round.apply(0, Action::Ron).unwrap();
+        pass_all(&mut round);
+        let ctx = round.wins()[0].ctx;
+        assert!(ctx.riichi && ctx.double_riichi && ctx.ippatsu);
+        let result = round.result().unwrap();
+        assert_eq!(result.deltas.iter().sum::<i32>(), 1000);
+        assert_eq!(result.riichi_sticks, 0);
+
+        // a call ends ippatsu
+        let mut round = self::round(HANDS, "4m 3z 4p");
+        turn(&mut round, 0, "4m");
+        round.apply(1, Action::Tsumo).unwrap();
+        round.apply(1, Action::Riichi(find(&round, 1, "3z"))).unwrap();
+        round.apply(0, Action::Pass).unwrap();
+        round.apply(3, Action::Pass).unwrap();
+        let mut pon = [0; 2];
+        pon.copy_from_slice(&faces(&round, 2, Tile::Honor(Honor::West)));
+        round.apply(2, Action::Pon(pon)).unwrap();
+        assert_eq!(round.table().points[1], 24000);
+        assert!(round.seat(1).double_riichi && !round.seat(1).ippatsu);
+
+        // 1111m234p567s789s only waits on a fifth 1m, so no riichi on
+        // the drawn tile, but one on a 1m waiting on 5z
+        let hands = ["1111m234p567s789s", HANDS[1], HANDS[2], HANDS[3]];
+        let mut round = self::round(hands, "5z");
+        round.apply(0, Action::Tsumo).unwrap();
+        let actions = round.legal_actions(0);
+        assert!(!actions.contains(&Action::Riichi(round.seat(0).drawn.unwrap())));
+        assert!(actions.contains(&Action::Riichi(find(&round, 0, "1m"))));
+    }
+
+    #[test]
+    fn test_riichi_lock() {
+        let hands = ["111m456m789m1234p", HANDS[1], HANDS[2], HANDS[3]];
+        let mut round = round(hands, "7z 2m 3m 5m 1m");
+        round.apply(0, Action::Tsumo).unwrap();
+        round.apply(0, Action::Riichi(round.seat(0).drawn.unwrap())).unwrap();
+        pass_all(&mut round);
+        turn(&mut round, 1, "2m");
+        turn(&mut round, 2, "3m");
+        turn(&mut round, 3, "5m");
+        round.apply(0, Action::Tsumo).unwrap();
+        let drawn = round.seat(0).drawn.unwrap();
+        let ones = faces(&round, 0, Tile::Man(1));
+        assert_eq!(round.legal_actions(0), [Action::Discard(drawn), Action::Ankan(ones.try_into().unwrap())]);
+
+        // 11123m reads as 111m 23m or 11m 123m, a kan of 1m loses the wait
+        // on 9s
+        let hands = ["11123m456p789p99s", HANDS[1], HANDS[2], HANDS[3]];
+        let mut round = self::round(hands, "7z 2m 3m 5m 1m");
+        round.apply(0, Action::Tsumo).unwrap();
+        round.apply(0, Action::Riichi(round.seat(0).drawn.unwrap())).unwrap();
+        pass_all(&mut round);
+        turn(&mut round, 1, "2m");
+        turn(&mut round, 2, "3m");
+        turn(&mut round, 3, "5m");
+        round.apply(0, Action::Tsumo).unwrap();
+        let drawn = round.seat(0).drawn.unwrap();
+        assert_eq!(round.legal_actions(0), [Action::Discard(drawn), Action::TsumoAgari]);
+        assert!(round.apply(0, Action::Discard(find(&round, 0, "2m"))).is_err());
+    }
+
+    #[test]
+    fn test_abortive_draws() {
+        let kyuushu = ["19m19p19s1234z567m", HANDS[1], HANDS[2], HANDS[3]];
+        let mut round = round(kyuushu, "2m 4z 4z 4z");
+        round.apply(0, Action::Tsumo).unwrap();
+        assert!(round.legal_actions(0).contains(&Action::KyuushuKyuuhai));
+        let mut called_off = round.clone();
+        called_off.apply(0, Action::KyuushuKyuuhai).unwrap();
+        assert_eq!(called_off.phase(), &Phase::Finished(RoundEnd::Abortive(AbortiveDraw::KyuushuKyuuhai)));
+        let result = called_off.result().unwrap();
+        assert_eq!(result.deltas, [0; 4]);
+        assert!(result.renchan);
+
+        // suufon renda, 4z from every seat in the first go around
+        round.apply(0, Action::Discard(find(&round, 0, "4z"))).unwrap();
+        pass_all(&mut round);
+        for seat in 1..4 {
+            assert!(!round.is_finished());
+            round.apply(seat, Action::Tsumo).unwrap();
+            round.apply(seat, Action::Discard(round.seat(seat).drawn.unwrap())).unwrap();
+            pass_all(&mut round);
+        }
+        assert_eq!(round.phase(), &Phase::Finished(RoundEnd::Abortive(AbortiveDraw::SuufonRenda)));
+
+        // suucha riichi, the fourth deposit stays on the table
+        let tenpai = ["123456789m1234p", "55667788p11223z", "234567s99s33z567p", "111888s99p789m55z"];
+        let mut round = self::round(tenpai, "2m 3m 4m 6m");
+        for seat in 0..4 {
+            round.apply(seat, Action::Tsumo).unwrap();
+            round.apply(seat, Action::Riichi(round.seat(seat).drawn.unwrap())).unwrap();
+            pass_all(&mut round);
+        }
+        assert_eq!(round.phase(), &Phase::Finished(RoundEnd::Abortive(AbortiveDraw::SuuchaRiichi)));
+        assert_eq!(round.table().points, [24000; 4]);
+        assert_eq!(round.result().unwrap().riichi_sticks, 4);
+    }
+
+    #[test]
+    fn test_sanma() {
+        let rules = RuleSet { aka: AkaDora::NONE, ..RuleSet::sanma() };
+        let hands = ["123456789p1234s", "19m19p19s1235z677z", "11999m67p666s555z"];
+        let mut round = round_with(rules, &hands, "4z 8p 4s");
+        round.apply(0, Action::Tsumo).unwrap();
+        let north = round.seat(0).drawn.unwrap();
+        assert!(round.legal_actions(0).contains(&Action::Kita(north)));
+        round.apply(0, Action::Kita(north)).unwrap();
+        assert_eq!(round.phase(), &Phase::Rinshan { seat: 0 });
+        assert_eq!(round.seat(0).kita, [north]);
+        round.apply(0, Action::Tsumo).unwrap();
+        assert_eq!(round.wall().rinshan_drawn(), 1);
+        assert_eq!(round.wall().dora_indicators().len(), 1);
+        round.apply(0, Action::Discard(round.seat(0).drawn.unwrap())).unwrap();
+        pass_all(&mut round);
+
+        // 8p, and no chi for the next seat
+        round.apply(1, Action::Tsumo).unwrap();
+        round.apply(1, Action::Discard(round.seat(1).drawn.unwrap())).unwrap();
+        assert!(!round.legal_actions(2).iter().any(|a| matches!(a, Action::Chi(_))));
+        pass_all(&mut round);
+
+        round.apply(2, Action::Tsumo).unwrap();
+        round.apply(2, Action::Discard(round.seat(2).drawn.unwrap())).unwrap();
+        round.apply(0, Action::Ron).unwrap();
+        pass_all(&mut round);
+        let result = round.result().unwrap();
+        // the set aside north counts once, and again if it is the dora
+        let indicator = round.face(round.wall().dora_indicators()[0]).unwrap().tile();
+        let dora = dora_from_indicator(indicator, true).unwrap();
+        let kita = if dora == Tile::Honor(Honor::North) { 2 } else { 1 };
+        let in_hand = round.wins()[0].hand.all_counts()[dora.index().unwrap()] as u32;
+        assert_eq!(result.scores[0].dora, in_hand + kita);
+        assert_eq!(result.deltas.iter().sum::<i32>(), 0);
+        assert_eq!(result.deltas[2], -result.deltas[0]);
+    }
+}
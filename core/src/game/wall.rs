@@ -0,0 +1,260 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::elgamal::MaskedMessage;
+
+/// Tiles set aside for kan replacements and dora indicators.
+pub const DEAD_WALL: usize = 14;
+/// Kans per hand, and so kan dora.
+pub const MAX_KANS: usize = 4;
+/// Replacement draws per hand, for four kans and in sanma four norths set
+/// aside.
+pub const MAX_RINSHAN: usize = 2 * MAX_KANS;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WallError {
+    /// Not a whole number of two tile stacks per player.
+    DeckSize(usize),
+    /// Two dice sum to 2 up to 12.
+    Dice(u8),
+}
+
+impl Display for WallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WallError::DeckSize(len) => write!(f, "a deck of {len} tiles can't be built into a wall"),
+            WallError::Dice(dice) => write!(f, "invalid dice roll {dice}"),
+        }
+    }
+}
+
+impl std::error::Error for WallError {}
+
+/// The shuffled deck laid out as a wall, handing out deck indices in the
+/// order tiles are drawn.
+///
+/// The deck is read as stacks of two, top tile first, in draw order around
+/// the table, one side of `len / 2 / players` stacks in front of each seat
+/// starting with the dealer. The dice pick the side, counting the dealer as
+/// 1, and the stack on it where the wall is broken. The live wall runs on
+/// from the break and the dead wall is the 7 stacks before it:
+///
+/// ```text
+///  stack   b-7  b-6  b-5  b-4  b-3  b-2  b-1 | b   b+1 ...
+///  top     d5   d4   d3   d2   d1   r3   r1  | 0   2
+///  bottom  u5   u4   u3   u2   u1   r4   r2  | 1   3
+/// ```
+///
+/// with `d` the dora indicators, `u` the ura dora indicators under them and
+/// `r` the rinshan draws. Every rinshan draw moves the last live tile into
+/// the dead wall, so the live wall ends one tile earlier. Once the four
+/// rinshan tiles are gone, the tiles moved in this way are the next
+/// replacement draws, in the order they moved.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Wall {
+    tiles: Vec<MaskedMessage>,
+    /// Stack the live wall starts at.
+    break_stack: usize,
+    drawn: usize,
+    rinshan: usize,
+    /// Dora indicators revealed, the first one included.
+    revealed: usize,
+}
+
+impl Wall {
+    /// Lay out `tiles`, the final shuffled deck, for `players` and break it
+    /// where the sum of two dice says.
+    pub fn new(tiles: Vec<MaskedMessage>, players: usize, dice: u8) -> Result<Self, WallError> {
+        let len = tiles.len();
+        if players == 0 || !len.is_multiple_of(2 * players) || len < 2 * DEAD_WALL {
+            return Err(WallError::DeckSize(len));
+        }
+        if !(2..=12).contains(&dice) {
+            return Err(WallError::Dice(dice));
+        }
+        let side = (dice as usize - 1) % players;
+        let break_stack = side * len / 2 / players + dice as usize;
+        Ok(Self { tiles, break_stack, drawn: 0, rinshan: 0, revealed: 1 })
+    }
+
+    pub fn tiles(&self) -> &[MaskedMessage] {
+        &self.tiles
+    }
+
+    pub fn tile(&self, index: usize) -> &MaskedMessage {
+        &self.tiles[index]
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn break_stack(&self) -> usize {
+        self.break_stack
+    }
+
+    /// Deck index of the tile `offset` tiles after the break.
+    fn at(&self, offset: isize) -> usize {
+        let len = self.len() as isize;
+        (self.break_stack as isize * 2 + offset).rem_euclid(len) as usize
+    }
+
+    /// Tiles in the live wall, drawn or not, which shrinks with every
+    /// rinshan draw.
+    pub fn live_len(&self) -> usize {
+        self.len() - DEAD_WALL - self.rinshan
+    }
+
+    /// Live tiles left to draw.
+    pub fn remaining(&self) -> usize {
+        self.live_len() - self.drawn
+    }
+
+    /// The last live tile has been drawn, so the next discard is the last
+    /// of the hand.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Index of the next live tile, `None` once the wall is exhausted.
+    pub fn peek(&self) -> Option<usize> {
+        (!self.is_exhausted()).then(|| self.at(self.drawn as isize))
+    }
+
+    /// Draw the next live tile. The draw that exhausts the wall is haitei.
+    pub fn draw(&mut self) -> Option<usize> {
+        let index = self.peek()?;
+        self.drawn += 1;
+        Some(index)
+    }
+
+    /// Replacement draws taken so far.
+    pub fn rinshan_drawn(&self) -> usize {
+        self.rinshan
+    }
+
+    /// Draw a replacement tile after a kan or a north set aside. `None`
+    /// after [`MAX_RINSHAN`] or once the live wall is empty, when there is
+    /// nothing to replace.
+    pub fn draw_rinshan(&mut self) -> Option<usize> {
+        if self.rinshan >= MAX_RINSHAN || self.is_exhausted() {
+            return None;
+        }
+        let k = self.rinshan as isize;
+        self.rinshan += 1;
+        let offset = match k - MAX_KANS as isize {
+            moved @ 0.. => -(DEAD_WALL as isize) - 1 - moved,
+            _ => -2 - 2 * (k / 2) + k % 2,
+        };
+        Some(self.at(offset))
+    }
+
+    /// Reveal the next kan dora indicator, when the rules say so after a
+    /// kan. `None` once all of them are.
+    pub fn reveal_kan_dora(&mut self) -> Option<usize> {
+        if self.revealed > MAX_KANS {
+            return None;
+        }
+        self.revealed += 1;
+        Some(self.dora_indicator(self.revealed - 1))
+    }
+
+    fn dora_indicator(&self, i: usize) -> usize {
+        self.at(-6 - 2 * i as isize)
+    }
+
+    /// Indices of the revealed dora indicators, kan dora included.
+    pub fn dora_indicators(&self) -> Vec<usize> {
+        (0..self.revealed).map(|i| self.dora_indicator(i)).collect()
+    }
+
+    /// Indices of the ura dora indicators under the revealed dora
+    /// indicators, only opened for a riichi win.
+    pub fn ura_indicators(&self) -> Vec<usize> {
+        (0..self.revealed).map(|i| self.at(-5 - 2 * i as isize)).collect()
+    }
+
+    /// Indices of the 14 dead wall tiles as laid out at the break.
+    pub fn dead_wall(&self) -> Vec<usize> {
+        (-(DEAD_WALL as isize)..0).map(|offset| self.at(offset)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tile::get_richi_tiles;
+
+    fn wall(dice: u8) -> Wall {
+        Wall::new(get_richi_tiles(), 4, dice).unwrap()
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(Wall::new(get_richi_tiles(), 4, 13), Err(WallError::Dice(13)));
+        assert_eq!(Wall::new(get_richi_tiles()[..130].to_vec(), 4, 7), Err(WallError::DeckSize(130)));
+
+        // 5 breaks the dealer's own side, 5 stacks in
+        let mut wall = wall(5);
+        assert_eq!(wall.break_stack(), 5);
+        assert_eq!(wall.peek(), Some(10));
+        assert_eq!(wall.dead_wall(), [132, 133, 134, 135, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(wall.dora_indicators(), vec![4]);
+        assert_eq!(wall.ura_indicators(), vec![5]);
+
+        // 6 breaks the next side, 17 stacks on
+        assert_eq!(self::wall(6).break_stack(), 23);
+        // 12 breaks the last side
+        let twelve = self::wall(12);
+        assert_eq!(twelve.break_stack(), 63);
+        assert_eq!(twelve.dead_wall().last(), Some(&125));
+
+        assert_eq!(wall.draw_rinshan(), Some(8));
+        assert_eq!(wall.draw_rinshan(), Some(9));
+        assert_eq!(wall.draw_rinshan(), Some(6));
+        assert_eq!(wall.reveal_kan_dora(), Some(2));
+        assert_eq!(wall.dora_indicators(), vec![4, 2]);
+        assert_eq!(wall.ura_indicators(), vec![5, 3]);
+    }
+
+    #[test]
+    fn test_draws() {
+        let mut wall = wall(7);
+        assert_eq!(wall.live_len(), 122);
+        let first = wall.draw().unwrap();
+        assert_eq!(first, wall.break_stack() * 2);
+        assert_eq!(wall.draw(), Some(first + 1));
+
+        for _ in 0..MAX_KANS {
+            assert!(wall.draw_rinshan().is_some());
+            assert!(wall.reveal_kan_dora().is_some());
+        }
+        assert_eq!(wall.reveal_kan_dora(), None);
+        assert_eq!(wall.dora_indicators().len(), 5);
+
+        // the kans took 4 tiles off the end of the live wall
+        assert_eq!(wall.remaining(), 122 - 4 - 2);
+        // and the next replacements draw them, first moved first
+        let moved: Vec<usize> = (0..4).map(|k| wall.at(wall.live_len() as isize + 3 - k)).collect();
+        let mut seen: Vec<usize> = vec![first, first + 1];
+        for index in moved {
+            assert_eq!(wall.draw_rinshan(), Some(index));
+            seen.push(index);
+        }
+        assert_eq!(wall.draw_rinshan(), None);
+        while let Some(index) = wall.draw() {
+            seen.push(index);
+        }
+        assert!(wall.is_exhausted());
+        seen.extend(wall.dead_wall());
+        seen.sort();
+        seen.dedup();
+        // the 4 tiles moved by the later replacements were never drawn
+        assert_eq!(seen.len(), 136 - 4);
+    }
+}
@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+
+use crate::tile::{MeldKind, Tile};
+
+use super::Hand;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum GroupKind {
+    /// Shuntsu, three in a row.
+    Sequence,
+    /// Koutsu, three of a kind.
+    Triplet,
+    /// Kantsu, four of a kind.
+    Kan,
+}
+
+/// One set of a standard winning hand.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Group {
+    pub kind: GroupKind,
+    /// Lowest tile of the group.
+    pub tile: Tile,
+    /// Called from another player. An ankan is closed, and so is every group
+    /// of the closed tiles, even one completed by ron.
+    pub open: bool,
+}
+
+impl Group {
+    pub fn contains(&self, tile: Tile) -> bool {
+        match self.kind {
+            GroupKind::Sequence => {
+                tile.kind() == self.tile.kind() && (self.tile.ord()..self.tile.ord() + 3).contains(&tile.ord())
+            }
+            _ => tile == self.tile,
+        }
+    }
+
+    pub fn is_yaochuu(&self) -> bool {
+        match self.kind {
+            GroupKind::Sequence => matches!(self.tile.number(), Some(1 | 7)),
+            _ => self.tile.is_yaochuu(),
+        }
+    }
+
+    fn from_meld(meld: &crate::tile::Meld) -> Self {
+        let kind = match meld.kind {
+            MeldKind::Chi => GroupKind::Sequence,
+            MeldKind::Pon => GroupKind::Triplet,
+            _ => GroupKind::Kan,
+        };
+        Group { kind, tile: meld.first(), open: meld.is_open() }
+    }
+}
+
+/// One way to read a complete hand.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Agari {
+    /// Four groups and a pair. Called melds come first in call order,
+    /// followed by the closed groups sorted.
+    Standard { pair: Tile, groups: Vec<Group> },
+    /// Seven distinct pairs, sorted.
+    Chiitoitsu { pairs: Vec<Tile> },
+    /// One of each terminal and honor, `pair` doubled.
+    Kokushi { pair: Tile },
+}
+
+/// Every reading of a 14 tile hand, empty if it is not complete.
+///
+/// A hand can have several, e.g. `111222333m` is three triplets or three
+/// identical sequences, and `112233m` also counts as pairs for chiitoitsu.
+pub fn decompose(hand: &Hand) -> Vec<Agari> {
+    if hand.len() != 14 {
+        return vec![];
+    }
+    let mut found = vec![];
+    let melds: Vec<Group> = hand.melds().iter().map(Group::from_meld).collect();
+    let mut counts = *hand.closed();
+    for pair in 0..34 {
+        if counts[pair] < 2 {
+            continue;
+        }
+        counts[pair] -= 2;
+        let mut closed = vec![];
+        decompose_groups(&mut counts, 0, &mut closed, &mut |closed| {
+            let mut groups = melds.clone();
+            let mut closed = closed.to_vec();
+            closed.sort();
+            groups.extend(closed);
+            // a triplet and a sequence off the same tile are reached in
+            // either order
+            let agari = Agari::Standard { pair: Tile::from_index(pair).unwrap(), groups };
+            if !found.contains(&agari) {
+                found.push(agari);
+            }
+        });
+        counts[pair] += 2;
+    }
+    if hand.melds().is_empty() {
+        found.extend(chiitoitsu(&counts));
+        found.extend(kokushi(&counts));
+    }
+    found
+}
+
+pub fn is_agari(hand: &Hand) -> bool {
+    !decompose(hand).is_empty()
+}
+
+/// Split `counts` into groups, lowest tile first, calling `done` for each
+/// complete split.
+fn decompose_groups(
+    counts: &mut [u8; 34],
+    from: usize,
+    groups: &mut Vec<Group>,
+    done: &mut dyn FnMut(&[Group]),
+) {
+    let Some(i) = (from..34).find(|&i| counts[i] > 0) else {
+        done(groups);
+        return;
+    };
+    let tile = Tile::from_index(i).unwrap();
+    if counts[i] >= 3 {
+        counts[i] -= 3;
+        groups.push(Group { kind: GroupKind::Triplet, tile, open: false });
+        decompose_groups(counts, i, groups, done);
+        groups.pop();
+        counts[i] += 3;
+    }
+    // sequences stay within a suit, `i % 9 < 7` keeps them off the 8 and 9
+    if i < 27 && i % 9 < 7 && counts[i + 1] > 0 && counts[i + 2] > 0 {
+        counts[i..i + 3].iter_mut().for_each(|n| *n -= 1);
+        groups.push(Group { kind: GroupKind::Sequence, tile, open: false });
+        decompose_groups(counts, i, groups, done);
+        groups.pop();
+        counts[i..i + 3].iter_mut().for_each(|n| *n += 1);
+    }
+}
+
+fn chiitoitsu(counts: &[u8; 34]) -> Option<Agari> {
+    let pairs: Vec<Tile> = (0..34).filter(|&i| counts[i] == 2).filter_map(Tile::from_index).collect();
+    (pairs.len() == 7).then_some(Agari::Chiitoitsu { pairs })
+}
+
+fn kokushi(counts: &[u8; 34]) -> Option<Agari> {
+    let yaochuu: Vec<usize> = (0..34).filter(|&i| Tile::from_index(i).unwrap().is_yaochuu()).collect();
+    if yaochuu.iter().any(|&i| counts[i] == 0) || yaochuu.iter().map(|&i| counts[i]).sum::<u8>() != 14 {
+        return None;
+    }
+    let pair = yaochuu.into_iter().find(|&i| counts[i] == 2)?;
+    Some(Agari::Kokushi { pair: Tile::from_index(pair).unwrap() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tile::Honor;
+
+    fn agari(s: &str) -> Vec<Agari> {
+        decompose(&s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_standard() {
+        let found = agari("123m456p789s11122z");
+        assert_eq!(found.len(), 1);
+        let Agari::Standard { pair, groups } = &found[0] else {
+            panic!("expected a standard hand")
+        };
+        assert_eq!(*pair, Tile::Honor(Honor::South));
+        assert_eq!(groups.len(), 4);
+        assert_eq!(groups[3], Group { kind: GroupKind::Triplet, tile: Tile::Honor(Honor::East), open: false });
+
+        // three triplets or three sequences
+        assert_eq!(agari("111222333m789p55s").len(), 2);
+        assert_eq!(agari("11123456789999m").len(), 1);
+        // four of a tile read as a triplet and a sequence once
+        assert_eq!(agari("111123m456p789s11z").len(), 1);
+        let found = agari("111122223333m11z");
+        assert_eq!(found.len(), 2);
+        assert_ne!(found[0], found[1]);
+        assert!(agari("123m456p789s1122z3z").is_empty());
+        assert!(agari("123m456p").is_empty());
+        // no wrap-around sequences
+        assert!(agari("891m123p456p789p11z").is_empty());
+    }
+
+    #[test]
+    fn test_melds() {
+        let found = agari("234m55p [c123s] [p777z] [a1111m]");
+        assert_eq!(found.len(), 1);
+        let Agari::Standard { groups, .. } = &found[0] else {
+            panic!("expected a standard hand")
+        };
+        assert_eq!(groups[0], Group { kind: GroupKind::Sequence, tile: Tile::Sou(1), open: true });
+        assert_eq!(groups[1].kind, GroupKind::Triplet);
+        assert_eq!((groups[2].kind, groups[2].open), (GroupKind::Kan, false));
+        assert_eq!(groups[3].tile, Tile::Man(2));
+        assert!(groups[0].contains(Tile::Sou(3)) && groups[0].is_yaochuu());
+
+        // melds rule out chiitoitsu and kokushi
+        assert!(agari("1122m3344p55s [p777z]").is_empty());
+    }
+
+    #[test]
+    fn test_special() {
+        let found = agari("1122m3344p5566s77z");
+        assert_eq!(found.len(), 1);
+        assert!(matches!(&found[0], Agari::Chiitoitsu { pairs } if pairs.len() == 7));
+        // ryanpeikou reads both ways
+        let found = agari("112233m445566p11z");
+        assert_eq!(found.len(), 2);
+        // four of a kind is not two pairs
+        assert!(agari("1111m3344p5566s77z").is_empty());
+
+        let found = agari("19m19p19s12345677z");
+        assert_eq!(found, vec![Agari::Kokushi { pair: Tile::Honor(Honor::Red) }]);
+        assert!(agari("19m19p19s12345666z").is_empty());
+    }
+}
@@ -0,0 +1,413 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::tile::{parse_hand_with_melds, AkaDora, BaseTile, Meld, MeldKind, ParseHandError, Relative, Tile};
+
+pub mod agari;
+pub mod shanten;
+
+pub use agari::{decompose, is_agari, Agari, Group, GroupKind};
+pub use shanten::{shanten, standard_shanten, ukeire, waits};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HandError {
+    /// The tile is not in the closed part of the hand.
+    Missing(Tile),
+    /// A fifth copy of the tile.
+    TooMany(Tile),
+    /// Bonus tiles and jokers are not part of the closed hand.
+    NotPlayable(Tile),
+    /// The tiles do not form the meld.
+    InvalidMeld(MeldKind),
+    /// Kakan without a pon of the tile.
+    NoPon(Tile),
+    /// A red copy of the tile when the hand holds no red five.
+    NoRed(Tile),
+}
+
+impl Display for HandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HandError::Missing(tile) => write!(f, "no {tile} in the hand"),
+            HandError::TooMany(tile) => write!(f, "more than four {tile}"),
+            HandError::NotPlayable(tile) => write!(f, "{tile} cannot be held in the hand"),
+            HandError::InvalidMeld(kind) => write!(f, "tiles do not form a {kind:?}"),
+            HandError::NoPon(tile) => write!(f, "no pon of {tile} to add a kan to"),
+            HandError::NoRed(tile) => write!(f, "no red {tile} in the hand"),
+        }
+    }
+}
+
+impl std::error::Error for HandError {}
+
+/// The tiles of one player, decoded.
+///
+/// Closed tiles, the drawn one included, are kept as counts in the usual 34
+/// tile order of [`Tile::index`]. Red fives are only counted, which copy is
+/// red does not matter once it is in the hand.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hand {
+    closed: [u8; 34],
+    melds: Vec<Meld>,
+    drawn: Option<Tile>,
+    /// Red fives in the closed tiles, by suit.
+    aka: AkaDora,
+    /// Red fives in the melds, by suit.
+    melded_aka: AkaDora,
+    /// Flowers and seasons set aside.
+    bonus: Vec<Tile>,
+}
+
+impl Default for Hand {
+    fn default() -> Self {
+        Self {
+            closed: [0; 34],
+            melds: vec![],
+            drawn: None,
+            aka: AkaDora::NONE,
+            melded_aka: AkaDora::NONE,
+            bonus: vec![],
+        }
+    }
+}
+
+impl Hand {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A hand from its dealt tiles, bonus tiles are set aside.
+    pub fn from_tiles(tiles: &[BaseTile]) -> Result<Self, HandError> {
+        let mut hand = Self::new();
+        for tile in tiles {
+            hand.insert(tile)?;
+        }
+        Ok(hand)
+    }
+
+    /// A hand from plain faces and melds, e.g. from [`parse_hand_with_melds`],
+    /// with `aka` of the closed fives and `melded_aka` of the melded ones red.
+    pub fn from_parts(tiles: &[Tile], melds: Vec<Meld>, aka: AkaDora, melded_aka: AkaDora) -> Result<Self, HandError> {
+        let mut hand = Self { melds, aka, melded_aka, ..Self::new() };
+        for &tile in tiles {
+            hand.add(tile)?;
+        }
+        for five in [Tile::Man(5), Tile::Pin(5), Tile::Sou(5)] {
+            if hand.aka.count(five.kind()) > hand.count(five) {
+                return Err(HandError::NoRed(five));
+            }
+        }
+        Ok(hand)
+    }
+
+    pub fn closed(&self) -> &[u8; 34] {
+        &self.closed
+    }
+
+    pub fn melds(&self) -> &[Meld] {
+        &self.melds
+    }
+
+    /// The tile drawn this turn, still counted in [`Hand::closed`].
+    pub fn drawn(&self) -> Option<Tile> {
+        self.drawn
+    }
+
+    /// Red fives in the closed tiles and melds.
+    pub fn aka(&self) -> u8 {
+        self.aka.total() + self.melded_aka.total()
+    }
+
+    pub fn bonus(&self) -> &[Tile] {
+        &self.bonus
+    }
+
+    pub fn count(&self, tile: Tile) -> u8 {
+        tile.index().map_or(0, |i| self.closed[i])
+    }
+
+    /// Closed tiles in 34 tile order.
+    pub fn closed_tiles(&self) -> Vec<Tile> {
+        self.closed
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &n)| std::iter::repeat_n(Tile::from_index(i).unwrap(), n as usize))
+            .collect()
+    }
+
+    /// Counts of every tile, closed and melded.
+    pub fn all_counts(&self) -> [u8; 34] {
+        let mut all = self.closed;
+        for tile in self.melds.iter().flat_map(|m| &m.tiles) {
+            all[tile.index().unwrap()] += 1;
+        }
+        all
+    }
+
+    pub fn closed_len(&self) -> usize {
+        self.closed.iter().map(|&n| n as usize).sum()
+    }
+
+    /// Tiles counted towards the 13 + 1, a kan counting as three.
+    pub fn len(&self) -> usize {
+        self.closed_len() + 3 * self.melds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// No open melds, an ankan keeps the hand closed.
+    pub fn is_closed(&self) -> bool {
+        self.melds.iter().all(|m| !m.is_open())
+    }
+
+    /// Take a tile from the wall, a bonus tile is set aside instead.
+    pub fn draw(&mut self, tile: &BaseTile) -> Result<(), HandError> {
+        self.insert(tile)?;
+        if !tile.tile().is_bonus() {
+            self.drawn = Some(tile.tile());
+        }
+        Ok(())
+    }
+
+    /// Discard `tile`, a red five only if the hand holds one of its suit and
+    /// a plain five only if it holds one besides the red copies.
+    pub fn discard(&mut self, tile: &BaseTile) -> Result<(), HandError> {
+        let face = tile.tile();
+        let red = self.aka.count(face.kind());
+        if tile.red && red == 0 {
+            return Err(HandError::NoRed(face));
+        }
+        if !tile.red && face.number() == Some(5) && self.count(face) <= red {
+            return Err(HandError::Missing(face));
+        }
+        if tile.red {
+            *self.aka.count_mut(face.kind()).expect("red fives are suited") -= 1;
+        }
+        self.remove(face, 1)?;
+        self.drawn = None;
+        Ok(())
+    }
+
+    /// Chi, pon or daiminkan on a discard. `own` are the tiles taken from
+    /// the closed hand.
+    pub fn call(
+        &mut self,
+        kind: MeldKind,
+        called: &BaseTile,
+        from: Relative,
+        own: &[Tile],
+    ) -> Result<(), HandError> {
+        let mut tiles = own.to_vec();
+        tiles.push(called.tile());
+        let valid = match kind {
+            MeldKind::Chi => from == Relative::Kamicha,
+            MeldKind::Pon | MeldKind::Daiminkan => true,
+            MeldKind::Kakan | MeldKind::Ankan => false,
+        };
+        if !valid || !Meld::is_valid_shape(kind, &tiles) {
+            return Err(HandError::InvalidMeld(kind));
+        }
+        self.remove_all(own)?;
+        tiles.sort();
+        self.melds.push(Meld { kind, tiles, called: Some(called.tile()), from: Some(from) });
+        if let Some(n) = self.melded_aka.count_mut(called.tile().kind()).filter(|_| called.red) {
+            *n += 1;
+        }
+        self.drawn = None;
+        Ok(())
+    }
+
+    /// Add the fourth tile to an open pon.
+    pub fn kakan(&mut self, tile: Tile) -> Result<(), HandError> {
+        let meld = self
+            .melds
+            .iter_mut()
+            .find(|m| m.kind == MeldKind::Pon && m.first() == tile)
+            .ok_or(HandError::NoPon(tile))?;
+        if self.closed[tile.index().unwrap()] == 0 {
+            return Err(HandError::Missing(tile));
+        }
+        meld.kind = MeldKind::Kakan;
+        meld.tiles.push(tile);
+        self.remove(tile, 1)?;
+        self.drawn = None;
+        Ok(())
+    }
+
+    pub fn ankan(&mut self, tile: Tile) -> Result<(), HandError> {
+        if tile.index().is_none() {
+            return Err(HandError::InvalidMeld(MeldKind::Ankan));
+        }
+        self.remove(tile, 4)?;
+        self.melds.push(Meld { kind: MeldKind::Ankan, tiles: vec![tile; 4], called: None, from: None });
+        self.drawn = None;
+        Ok(())
+    }
+
+    fn insert(&mut self, tile: &BaseTile) -> Result<(), HandError> {
+        let face = tile.tile();
+        if face.is_bonus() {
+            self.bonus.push(face);
+            return Ok(());
+        }
+        self.add(face)?;
+        if let Some(n) = self.aka.count_mut(face.kind()).filter(|_| tile.red) {
+            *n += 1;
+        }
+        Ok(())
+    }
+
+    fn add(&mut self, tile: Tile) -> Result<(), HandError> {
+        let i = tile.index().ok_or(HandError::NotPlayable(tile))?;
+        if self.closed[i] >= 4 {
+            return Err(HandError::TooMany(tile));
+        }
+        self.closed[i] += 1;
+        Ok(())
+    }
+
+    fn remove(&mut self, tile: Tile, n: u8) -> Result<(), HandError> {
+        let i = tile.index().ok_or(HandError::NotPlayable(tile))?;
+        if self.closed[i] < n {
+            return Err(HandError::Missing(tile));
+        }
+        self.closed[i] -= n;
+        self.meld_red(tile);
+        Ok(())
+    }
+
+    fn remove_all(&mut self, tiles: &[Tile]) -> Result<(), HandError> {
+        let mut closed = self.closed;
+        for &tile in tiles {
+            let i = tile.index().ok_or(HandError::NotPlayable(tile))?;
+            closed[i] = closed[i].checked_sub(1).ok_or(HandError::Missing(tile))?;
+        }
+        self.closed = closed;
+        for &tile in tiles {
+            self.meld_red(tile);
+        }
+        Ok(())
+    }
+
+    /// Red fives of `tile`'s suit the closed tiles can no longer hold went
+    /// into a meld, plain copies being taken first.
+    fn meld_red(&mut self, tile: Tile) {
+        let left = self.count(tile);
+        if let Some(red) = self.aka.count_mut(tile.kind()).filter(|n| tile.number() == Some(5) && **n > left) {
+            let moved = *red - left;
+            *red = left;
+            *self.melded_aka.count_mut(tile.kind()).expect("red fives are suited") += moved;
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseHandModelError {
+    Notation(ParseHandError),
+    Hand(HandError),
+}
+
+impl Display for ParseHandModelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseHandModelError::Notation(e) => e.fmt(f),
+            ParseHandModelError::Hand(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseHandModelError {}
+
+/// MPSZ notation with melds, see [`crate::tile::notation`].
+impl FromStr for Hand {
+    type Err = ParseHandModelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = parse_hand_with_melds(s).map_err(ParseHandModelError::Notation)?;
+        let melded_aka = parsed.melded_aka();
+        Hand::from_parts(&parsed.tiles, parsed.melds, parsed.aka, melded_aka).map_err(ParseHandModelError::Hand)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tile::map::TILES;
+
+    #[test]
+    fn test_from_tiles() {
+        // 1m x4, red 5m, 5p, one flower
+        let tiles = [TILES[0], TILES[1], TILES[2], TILES[3], TILES[16], TILES[52], TILES[136]];
+        let hand = Hand::from_tiles(&tiles).unwrap();
+        assert_eq!(hand.closed_len(), 6);
+        assert_eq!(hand.count(Tile::Man(1)), 4);
+        assert_eq!(hand.aka(), 2);
+        assert_eq!(hand.bonus().len(), 1);
+        assert_eq!(hand.drawn(), None);
+        assert!(hand.is_closed());
+
+        let mut hand = hand;
+        assert_eq!(hand.draw(&TILES[0]), Err(HandError::TooMany(Tile::Man(1))));
+        hand.draw(&TILES[17]).unwrap();
+        assert_eq!(hand.drawn(), Some(Tile::Man(5)));
+        hand.discard(&TILES[16]).unwrap();
+        assert_eq!((hand.aka(), hand.drawn(), hand.count(Tile::Man(5))), (1, None, 1));
+        assert_eq!(hand.discard(&TILES[20]), Err(HandError::Missing(Tile::Man(6))));
+
+        let mut hand: Hand = "55m".parse().unwrap();
+        assert_eq!(hand.discard(&TILES[16]), Err(HandError::NoRed(Tile::Man(5))));
+        assert_eq!(hand.count(Tile::Man(5)), 2);
+
+        // red fives are held by suit, a red 5m is no red 5p
+        let mut hand: Hand = "05m5p".parse().unwrap();
+        assert_eq!(hand.discard(&TILES[52]), Err(HandError::NoRed(Tile::Pin(5))));
+        assert_eq!(hand.aka(), 1);
+        // the plain 5m goes, then only the red one is left
+        hand.discard(&TILES[17]).unwrap();
+        assert_eq!(hand.discard(&TILES[18]), Err(HandError::Missing(Tile::Man(5))));
+        assert_eq!((hand.count(Tile::Man(5)), hand.aka()), (1, 1));
+        hand.discard(&TILES[16]).unwrap();
+        assert_eq!((hand.count(Tile::Man(5)), hand.aka()), (0, 0));
+        assert!("0m".parse::<Hand>().is_ok());
+    }
+
+    #[test]
+    fn test_calls() {
+        let mut hand: Hand = "1155m234p".parse().unwrap();
+        assert_eq!(hand.len(), 7);
+
+        assert_eq!(
+            hand.call(MeldKind::Chi, &TILES[36], Relative::Toimen, &[Tile::Pin(2), Tile::Pin(3)]),
+            Err(HandError::InvalidMeld(MeldKind::Chi))
+        );
+        hand.call(MeldKind::Pon, &TILES[16], Relative::Toimen, &[Tile::Man(5), Tile::Man(5)]).unwrap();
+        assert_eq!(hand.aka(), 1);
+        assert_eq!(hand.len(), 8);
+        assert!(!hand.is_closed());
+
+        assert_eq!(hand.kakan(Tile::Man(5)), Err(HandError::Missing(Tile::Man(5))));
+        hand.draw(&TILES[17]).unwrap();
+        hand.kakan(Tile::Man(5)).unwrap();
+        assert_eq!(hand.melds()[0].kind, MeldKind::Kakan);
+        assert_eq!(hand.melds()[0].tiles, vec![Tile::Man(5); 4]);
+
+        assert_eq!(hand.ankan(Tile::Man(1)), Err(HandError::Missing(Tile::Man(1))));
+        // calling with the closed fives keeps the red one closed while a plain
+        // copy is left
+        let mut hand: Hand = "055m".parse().unwrap();
+        hand.call(MeldKind::Pon, &TILES[18], Relative::Toimen, &[Tile::Man(5), Tile::Man(5)]).unwrap();
+        assert_eq!((hand.aka(), hand.count(Tile::Man(5))), (1, 1));
+        assert_eq!(hand.discard(&TILES[19]), Err(HandError::Missing(Tile::Man(5))));
+        hand.discard(&TILES[16]).unwrap();
+        assert_eq!(hand.aka(), 0);
+
+        let mut hand: Hand = "1111m [p055s]".parse().unwrap();
+        assert_eq!(hand.aka(), 1);
+        hand.ankan(Tile::Man(1)).unwrap();
+        assert_eq!(hand.closed_len(), 0);
+        assert_eq!(hand.len(), 6);
+        assert_eq!(hand.closed_tiles(), vec![]);
+    }
+}
@@ -0,0 +1,308 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::tile::Tile;
+
+use super::Hand;
+
+/// Best number of partial sets for a suit, by `[pair taken][complete sets]`,
+/// `-1` where that many sets cannot be made.
+type SuitValue = [[i8; 5]; 2];
+
+thread_local! {
+    /// Suit values worked out so far, memoized per thread. Keyed by the
+    /// counts of one suit in base 5, with a flag for honors, so no count may
+    /// be over 4.
+    static SUIT_CACHE: RefCell<HashMap<u32, SuitValue>> = RefCell::new(HashMap::new());
+}
+
+/// Tiles away from tenpai, `0` for tenpai and `-1` for a complete hand. The
+/// lowest of the standard, chiitoitsu and kokushi shapes.
+///
+/// A hand only waiting on a tile it holds all four of, melds included, is
+/// not tenpai.
+pub fn shanten(hand: &Hand) -> i8 {
+    shanten_of(hand.closed(), &melded(hand), hand.melds().len())
+}
+
+/// Shanten of a standard four sets and a pair hand only.
+pub fn standard_shanten(hand: &Hand) -> i8 {
+    standard(hand.closed(), &melded(hand), hand.melds().len())
+}
+
+/// Counts of the tiles in melds.
+fn melded(hand: &Hand) -> [u8; 34] {
+    let mut melded = hand.all_counts();
+    for (n, closed) in melded.iter_mut().zip(hand.closed()) {
+        *n -= closed;
+    }
+    melded
+}
+
+fn shanten_of(counts: &[u8; 34], melded: &[u8; 34], melds: usize) -> i8 {
+    let standard = standard(counts, melded, melds);
+    if melds > 0 {
+        return standard;
+    }
+    standard.min(chiitoitsu(counts)).min(kokushi(counts))
+}
+
+/// Standard shanten, one more than [`standard_sets`] says when tenpai
+/// would take a fifth copy of a tile.
+fn standard(counts: &[u8; 34], melded: &[u8; 34], melds: usize) -> i8 {
+    let best = standard_sets(counts, melds);
+    if best == 0 && !has_wait(&mut counts.clone(), melded, melds) {
+        return 1;
+    }
+    best
+}
+
+/// Whether a hand of 3n + 1 tiles has a wait not held four times, or a hand
+/// of 3n + 2 tiles has a discard leaving one.
+fn has_wait(counts: &mut [u8; 34], melded: &[u8; 34], melds: usize) -> bool {
+    let len: u8 = counts.iter().sum();
+    (0..34).any(|i| {
+        if len % 3 == 2 {
+            if counts[i] == 0 {
+                return false;
+            }
+            counts[i] -= 1;
+            let found = has_wait(counts, melded, melds);
+            counts[i] += 1;
+            found
+        } else {
+            if counts[i] + melded[i] >= 4 {
+                return false;
+            }
+            counts[i] += 1;
+            let complete = standard_sets(counts, melds) == -1;
+            counts[i] -= 1;
+            complete
+        }
+    })
+}
+
+/// Standard shanten from the best split into sets, pairs and partial sets.
+fn standard_sets(counts: &[u8; 34], melds: usize) -> i8 {
+    let need = 4 - melds.min(4) as i8;
+    // combine the suits one by one, keeping the best partial sets per
+    // (pair taken, complete sets)
+    let mut total: SuitValue = [[-1; 5]; 2];
+    total[0][0] = 0;
+    for (range, honors) in [(0..9, false), (9..18, false), (18..27, false), (27..34, true)] {
+        let suit = suit_value(&counts[range], honors);
+        let mut next = [[-1; 5]; 2];
+        for (h1, m1) in (0..2).flat_map(|h| (0..5).map(move |m| (h, m))) {
+            if total[h1][m1] < 0 {
+                continue;
+            }
+            for (h2, m2) in (0..2 - h1).flat_map(|h| (0..5).map(move |m| (h, m))) {
+                if suit[h2][m2] < 0 {
+                    continue;
+                }
+                let slot = &mut next[h1 + h2][(m1 + m2).min(4)];
+                *slot = (*slot).max(total[h1][m1] + suit[h2][m2]);
+            }
+        }
+        total = next;
+    }
+
+    let mut best = 2 * need;
+    for (head, by_sets) in total.iter().enumerate() {
+        for (sets, &partial) in by_sets.iter().enumerate() {
+            if partial < 0 {
+                continue;
+            }
+            let sets = (sets as i8).min(need);
+            let partial = partial.min(need - sets);
+            best = best.min(2 * need - 2 * sets - partial - head as i8);
+        }
+    }
+    best
+}
+
+fn suit_value(counts: &[u8], honors: bool) -> SuitValue {
+    debug_assert!(counts.iter().all(|&n| n <= 4));
+    let key = counts.iter().fold(0, |key, &n| key * 5 + n as u32) | (honors as u32) << 31;
+    if let Some(value) = SUIT_CACHE.with(|cache| cache.borrow().get(&key).copied()) {
+        return value;
+    }
+    let value = compute_suit_value(counts, honors);
+    SUIT_CACHE.with(|cache| cache.borrow_mut().insert(key, value));
+    value
+}
+
+/// Try every way to take a set, pair or partial set off the lowest tile.
+fn compute_suit_value(counts: &[u8], honors: bool) -> SuitValue {
+    let Some(i) = counts.iter().position(|&n| n > 0) else {
+        let mut value = [[-1; 5]; 2];
+        value[0][0] = 0;
+        return value;
+    };
+    let mut best = [[-1; 5]; 2];
+    let mut take = |tiles: &[usize], sets: usize, partial: i8, head: usize| {
+        let mut taken = counts.to_vec();
+        for &j in tiles {
+            match taken.get_mut(j) {
+                Some(n) if *n > 0 => *n -= 1,
+                _ => return,
+            }
+        }
+        let rest = suit_value(&taken, honors);
+        for h in 0..2 - head {
+            for m in 0..5 {
+                if rest[h][m] >= 0 {
+                    let slot = &mut best[h + head][(m + sets).min(4)];
+                    *slot = (*slot).max(rest[h][m] + partial);
+                }
+            }
+        }
+    };
+    take(&[i, i, i], 1, 0, 0);
+    take(&[i, i], 0, 0, 1);
+    take(&[i, i], 0, 1, 0);
+    if !honors {
+        take(&[i, i + 1, i + 2], 1, 0, 0);
+        take(&[i, i + 1], 0, 1, 0);
+        take(&[i, i + 2], 0, 1, 0);
+    }
+    // or leave the tile isolated
+    take(&[i], 0, 0, 0);
+    best
+}
+
+fn chiitoitsu(counts: &[u8; 34]) -> i8 {
+    let pairs = counts.iter().filter(|&&n| n >= 2).count() as i8;
+    let kinds = counts.iter().filter(|&&n| n > 0).count() as i8;
+    6 - pairs + (7 - kinds).max(0)
+}
+
+fn kokushi(counts: &[u8; 34]) -> i8 {
+    let yaochuu: Vec<u8> = (0..34)
+        .filter(|&i| Tile::from_index(i).unwrap().is_yaochuu())
+        .map(|i| counts[i])
+        .collect();
+    let kinds = yaochuu.iter().filter(|&&n| n > 0).count() as i8;
+    let pair = yaochuu.iter().any(|&n| n >= 2) as i8;
+    13 - kinds - pair
+}
+
+/// Tiles that complete a hand of 13 (or 3n + 1) tiles, empty unless it is
+/// tenpai. A tile the hand holds all four of, melds included, is never a
+/// wait.
+pub fn waits(hand: &Hand) -> Vec<Tile> {
+    let mut counts = *hand.closed();
+    let melded = melded(hand);
+    let melds = hand.melds().len();
+    (0..34)
+        .filter(|&i| {
+            if counts[i] + melded[i] >= 4 {
+                return false;
+            }
+            counts[i] += 1;
+            let complete = shanten_of(&counts, &melded, melds) == -1;
+            counts[i] -= 1;
+            complete
+        })
+        .filter_map(Tile::from_index)
+        .collect()
+}
+
+/// Tiles that bring a hand of 13 (or 3n + 1) tiles closer to winning, with
+/// how many of each are left unseen. `visible` counts the tiles seen
+/// outside the hand, e.g. discards, other players' melds and dora
+/// indicators.
+pub fn ukeire(hand: &Hand, visible: &[u8; 34]) -> Vec<(Tile, u8)> {
+    let mut counts = *hand.closed();
+    let melded = melded(hand);
+    let melds = hand.melds().len();
+    let current = shanten_of(&counts, &melded, melds);
+    let own = hand.all_counts();
+    let better: Vec<usize> = (0..34)
+        .filter(|&i| {
+            if own[i] >= 4 {
+                return false;
+            }
+            counts[i] += 1;
+            let better = shanten_of(&counts, &melded, melds) < current;
+            counts[i] -= 1;
+            better
+        })
+        .collect();
+    better
+        .into_iter()
+        .map(|i| {
+            let seen = own[i] + visible[i];
+            (Tile::from_index(i).unwrap(), 4u8.saturating_sub(seen))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(s: &str) -> Hand {
+        s.parse().unwrap()
+    }
+
+    fn tiles(s: &str) -> Vec<Tile> {
+        crate::tile::parse_hand(s).unwrap()
+    }
+
+    #[test]
+    fn test_shanten() {
+        assert_eq!(shanten(&hand("123m456p789s11122z")), -1);
+        assert_eq!(shanten(&hand("123m456p789s1122z")), 0);
+        assert_eq!(shanten(&hand("123m456p789s1123z")), 1);
+        assert_eq!(shanten(&hand("147m258p369s1234z")), 6);
+        assert_eq!(shanten(&hand("1122m3344p5566s7z")), 0);
+        assert_eq!(standard_shanten(&hand("1122m3344p5566s7z")), 3);
+        assert_eq!(shanten(&hand("19m19p19s1234567z")), 0);
+        assert_eq!(shanten(&hand("19m19p19s1234566z")), 0);
+        assert_eq!(shanten(&hand("19m19p19s1234555z")), 1);
+        assert_eq!(shanten(&hand("23m55p [c123s] [p777z] [a1111m]")), 0);
+        assert_eq!(shanten(&hand("2m5p79s [c123s] [p777z] [a1111m]")), 1);
+        // only waiting on a fifth 1m is not tenpai, before or after the
+        // draw, with the four 1m closed or one of them in a meld
+        assert_eq!(shanten(&hand("1111m234p567s789s")), 1);
+        assert_eq!(standard_shanten(&hand("1111m234p567s789s")), 1);
+        assert_eq!(shanten(&hand("1111m234p567s789s5z")), 0);
+        assert_eq!(shanten(&hand("1111m234p567s789s1z")), 0);
+        assert_eq!(shanten(&hand("1m234p567s789s [p111m]")), 1);
+    }
+
+    #[test]
+    fn test_waits() {
+        assert_eq!(waits(&hand("1112345678999m")), tiles("123456789m"));
+        assert_eq!(waits(&hand("123m456p789s1122z")), tiles("12z"));
+        assert_eq!(waits(&hand("19m19p19s1234567z")), tiles("19m19p19s1234567z"));
+        assert_eq!(waits(&hand("123m456p789s1123z")), vec![]);
+
+        // every wait agrees with the decomposition
+        for s in ["1112345678999m", "2345678p11s [p777z]", "1122m3344p5566s7z", "13m456p789s111z22z"] {
+            let hand = hand(s);
+            for tile in waits(&hand) {
+                let complete: Hand = format!("{s} {tile}").parse().unwrap();
+                assert!(crate::hand::is_agari(&complete), "{s} + {tile}");
+            }
+        }
+
+        // no fifth 2m, which would also have left a count of 5 in the suit
+        // cache and read back as another hand's suit
+        assert_eq!(waits(&hand("2222m123p456p789p5s")), tiles("5s"));
+        assert_eq!(waits(&hand("1111m234p567s789s")), vec![]);
+        assert_eq!(shanten(&hand("1m123456789p115s")), 1);
+    }
+
+    #[test]
+    fn test_ukeire() {
+        let mut visible = [0; 34];
+        visible[Tile::Honor(crate::tile::Honor::East).index().unwrap()] = 2;
+        let found = ukeire(&hand("123m456p789s1122z"), &visible);
+        assert_eq!(found, vec![(tiles("1z")[0], 0), (tiles("2z")[0], 2)]);
+
+        let found = ukeire(&hand("123m456p789s1123z"), &[0; 34]);
+        assert_eq!(found.iter().map(|(_, n)| *n as usize).sum::<usize>(), 3 + 2 + 3);
+    }
+}
@@ -3,5 +3,11 @@
 pub mod babyjubjub;
 pub mod bn128;
 pub mod elgamal;
+pub mod game;
+pub mod hand;
+pub mod pedersen;
 pub mod poseidon;
+pub mod rules;
+pub mod scoring;
+pub mod shuffle;
 pub mod tile;
@@ -0,0 +1,108 @@
+use std::sync::Mutex;
+
+use ff::PrimeField;
+
+use crate::babyjubjub::{Fs, Point, PointProjective};
+use crate::bn128::Fr;
+use crate::poseidon::poseidon_hash;
+
+/// Domain separator for generator derivation, "zkmj-pedersen" as an integer.
+const GENERATOR_DOMAIN: u128 = 0x7a6b6d6a2d706564657273656e;
+
+/// `H` followed by every `G[i]` derived so far. Generator `i` does not
+/// depend on `n`, so a longer key only extends a shorter one.
+static GENERATORS: Mutex<Vec<Point>> = Mutex::new(Vec::new());
+
+/// Vector Pedersen commitment key `com(v; r) = r * H + sum(v[i] * G[i])`.
+///
+/// The generators are derived by hashing to the curve, so nobody knows a
+/// discrete log relation between them.
+#[derive(Clone, Debug)]
+pub struct PedersenParams {
+    pub h: Point,
+    pub g: Vec<Point>,
+}
+
+impl PedersenParams {
+    /// Commitment key for vectors of up to `n` elements, hashed to the
+    /// curve once per process.
+    pub fn new(n: usize) -> Self {
+        let mut generators = GENERATORS.lock().unwrap_or_else(|e| e.into_inner());
+        let derived = generators.len() as u64;
+        generators.extend((derived..=n as u64).map(hash_to_point));
+        Self { h: generators[0], g: generators[1..=n].to_vec() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.g.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.g.is_empty()
+    }
+
+    pub fn commit(&self, v: &[Fs], r: &Fs) -> Point {
+        assert!(v.len() <= self.g.len());
+        (msm(&self.g, v) + self.h.mul_scalar(&r.to_fr())).affine()
+    }
+}
+
+/// Sum of `scalars[i] * points[i]`.
+pub(crate) fn msm(points: &[Point], scalars: &[Fs]) -> PointProjective {
+    points
+        .iter()
+        .zip(scalars)
+        .fold(Point::ZERO.projective(), |acc, (p, s)| acc + p.mul_scalar(&s.to_fr()))
+}
+
+/// Try-and-increment: hash `(domain, index, counter)` to a `y` coordinate
+/// until it lies on the curve, then clear the cofactor.
+fn hash_to_point(index: u64) -> Point {
+    let domain = Fr::from_u128(GENERATOR_DOMAIN);
+    let eight = Fr::from(8);
+    (0u64..)
+        .find_map(|counter| {
+            let y = poseidon_hash(&[domain, Fr::from(index), Fr::from(counter)]);
+            Option::<Point>::from(Point::decompress(y.to_repr().0))
+                .map(|p| p.mul_scalar(&eight))
+                .filter(|p| *p != Point::ZERO)
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::babyjubjub::constants::SUBORDER;
+    use ff::Field;
+
+    #[test]
+    fn test_generators_in_subgroup() {
+        let params = PedersenParams::new(4);
+        assert_eq!(params.len(), 4);
+        for p in params.g.iter().chain([&params.h]) {
+            assert_eq!(p.mul_scalar(&SUBORDER), Point::ZERO);
+        }
+        assert_ne!(params.g[0], params.g[1]);
+
+        // a shorter key is a prefix of a longer one, whichever comes first
+        let short = PedersenParams::new(2);
+        assert_eq!((short.h, &short.g[..]), (params.h, &params.g[..2]));
+        assert_eq!(PedersenParams::new(5).g[..4], params.g[..]);
+        assert_eq!(params.g[3], hash_to_point(4));
+    }
+
+    #[test]
+    fn test_commit_homomorphic() {
+        let mut rng = rand::thread_rng();
+        let params = PedersenParams::new(3);
+        let a: Vec<Fs> = (0..3).map(|_| Fs::random(&mut rng)).collect();
+        let b: Vec<Fs> = (0..3).map(|_| Fs::random(&mut rng)).collect();
+        let (ra, rb) = (Fs::random(&mut rng), Fs::random(&mut rng));
+        let sum: Vec<Fs> = a.iter().zip(&b).map(|(x, y)| *x + y).collect();
+        assert_eq!(
+            (params.commit(&a, &ra) + params.commit(&b, &rb)).affine(),
+            params.commit(&sum, &(ra + rb)),
+        );
+    }
+}
@@ -16,11 +16,11 @@ fn sbox(n_rounds_p: usize, state: &mut [Fr], i: usize) {
     if i < POSEIDON_CONSTANTS_N_ROUNDS_FULL / 2
         || i >= POSEIDON_CONSTANTS_N_ROUNDS_FULL / 2 + n_rounds_p
     {
-        for j in 0..state.len() {
-            let aux = state[j];
-            state[j] = state[j].square();
-            state[j] = state[j].square();
-            state[j] *= aux;
+        for s in state.iter_mut() {
+            let aux = *s;
+            *s = s.square();
+            *s = s.square();
+            *s *= aux;
         }
     } else {
         let aux = state[0];
@@ -30,7 +30,7 @@ fn sbox(n_rounds_p: usize, state: &mut [Fr], i: usize) {
     }
 }
 
-pub fn mix(state: &Vec<Fr>, m: &[&[Fr]]) -> Vec<Fr> {
+pub fn mix(state: &[Fr], m: &[&[Fr]]) -> Vec<Fr> {
     let mut new_state: Vec<Fr> = Vec::new();
     for i in 0..state.len() {
         new_state.push(Fr::ZERO);
@@ -43,13 +43,13 @@ pub fn mix(state: &Vec<Fr>, m: &[&[Fr]]) -> Vec<Fr> {
     new_state.clone()
 }
 
-fn poseidon_hash(inp: &[Fr]) -> Fr {
+pub fn poseidon_hash(inp: &[Fr]) -> Fr {
     let t = inp.len() + 1;
     // if inp.len() == 0 || inp.len() >= self.constants.n_rounds_p.len() - 1 {
     // if inp.is_empty() || inp.len() > POSEIDON_CONSTANTS_N_ROUNDS_PARTIAL.len() {
     //     return Err("Wrong inputs length".to_string());
     // }
-    debug_assert!(inp.len() > 0 && inp.len() <= POSEIDON_CONSTANTS_N_ROUNDS_PARTIAL.len());
+    debug_assert!(!inp.is_empty() && inp.len() <= POSEIDON_CONSTANTS_N_ROUNDS_PARTIAL.len());
 
     let n_rounds_p = POSEIDON_CONSTANTS_N_ROUNDS_PARTIAL[t - 2];
 
@@ -57,7 +57,7 @@ fn poseidon_hash(inp: &[Fr]) -> Fr {
     state[1..].copy_from_slice(inp);
 
     for i in 0..(POSEIDON_CONSTANTS_N_ROUNDS_FULL + n_rounds_p) {
-        ark(&mut state, &POSEIDON_CONSTANTS_C[t - 2], i * t);
+        ark(&mut state, POSEIDON_CONSTANTS_C[t - 2], i * t);
         sbox(n_rounds_p, &mut state, i);
         state = mix(&state, POSEIDON_CONSTANTS_M[t - 2]);
     }
@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+
+use crate::scoring::{DoraRules, FlowerDora, SanmaTsumo, ScoringRules, YakuRules};
+use crate::tile::{AkaDora, DeckSpec};
+
+/// What happens when more than one player can ron the same discard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MultipleRon {
+    /// Only the first player after the discarder in turn order wins.
+    Atamahane,
+    /// Up to two players win, three is an abortive draw.
+    DoubleRon,
+    /// Everyone who can wins.
+    TripleRon,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchLength {
+    /// East round only.
+    Tonpuusen,
+    /// East and South rounds.
+    Hanchan,
+}
+
+/// Every rule choice a table agrees on before the key ceremony.
+///
+/// Serialized as is, so every player can check they are playing the same
+/// rules.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleSet {
+    /// 4, or 3 for sanma.
+    pub players: u8,
+    pub aka: AkaDora,
+    pub yaku: YakuRules,
+    pub scoring: ScoringRules,
+    pub dora: DoraRules,
+    /// Reveal a new dora indicator for every kan.
+    pub kan_dora: bool,
+    /// Reveal ura dora for a riichi win.
+    pub ura_dora: bool,
+    pub ippatsu: bool,
+    pub multiple_ron: MultipleRon,
+    /// Kyuushu kyuuhai, suufon renda, suukaikan and suucha riichi end the
+    /// hand.
+    pub abortive_draws: bool,
+    /// A player whose discards are all terminals and honors, none of them
+    /// called, is paid a mangan at an exhaustive draw.
+    pub nagashi_mangan: bool,
+    pub length: MatchLength,
+    pub starting_points: i32,
+    /// Points to finish above, the difference to the starting points of
+    /// every player goes to first place as oka.
+    pub return_points: i32,
+    /// Added to the final points by placement, first place first.
+    pub uma: Vec<i32>,
+    /// A player below zero points ends the match.
+    pub tobi: bool,
+    /// The dealer may end the match by winning or being tenpai in the last
+    /// hand while in first place.
+    pub agari_yame: bool,
+    /// The match goes into the West round while nobody has reached
+    /// `return_points`.
+    pub west_extension: bool,
+}
+
+impl RuleSet {
+    pub fn tenhou() -> Self {
+        Self {
+            players: 4,
+            aka: AkaDora::STANDARD,
+            yaku: YakuRules { open_tanyao: true, double_yakuman: false },
+            scoring: ScoringRules {
+                kazoe_yakuman: true,
+                kiriage_mangan: false,
+                double_wind_fu: 4,
+                sanma_tsumo: SanmaTsumo::TsumoLoss,
+            },
+            dora: DoraRules::default(),
+            kan_dora: true,
+            ura_dora: true,
+            ippatsu: true,
+            multiple_ron: MultipleRon::DoubleRon,
+            abortive_draws: true,
+            nagashi_mangan: true,
+            length: MatchLength::Hanchan,
+            starting_points: 25000,
+            return_points: 30000,
+            uma: vec![20000, 10000, -10000, -20000],
+            tobi: true,
+            agari_yame: true,
+            west_extension: true,
+        }
+    }
+
+    pub fn mahjong_soul() -> Self {
+        Self {
+            yaku: YakuRules { open_tanyao: true, double_yakuman: true },
+            return_points: 25000,
+            uma: vec![15000, 5000, -5000, -15000],
+            ..Self::tenhou()
+        }
+    }
+
+    /// World Riichi Championship rules, no red fives, double yakuman,
+    /// abortive draws or nagashi mangan and no ending a match early.
+    pub fn wrc() -> Self {
+        Self {
+            players: 4,
+            aka: AkaDora::NONE,
+            yaku: YakuRules { open_tanyao: true, double_yakuman: false },
+            scoring: ScoringRules {
+                kazoe_yakuman: false,
+                kiriage_mangan: true,
+                double_wind_fu: 2,
+                sanma_tsumo: SanmaTsumo::TsumoLoss,
+            },
+            dora: DoraRules::default(),
+            kan_dora: true,
+            ura_dora: true,
+            ippatsu: true,
+            multiple_ron: MultipleRon::Atamahane,
+            abortive_draws: false,
+            nagashi_mangan: false,
+            length: MatchLength::Hanchan,
+            starting_points: 30000,
+            return_points: 30000,
+            uma: vec![15000, 5000, -5000, -15000],
+            tobi: false,
+            agari_yame: false,
+            west_extension: false,
+        }
+    }
+
+    /// European Mahjong Association rules, WRC with 4 fu for a double wind
+    /// pair.
+    pub fn ema() -> Self {
+        Self { scoring: ScoringRules { double_wind_fu: 4, ..Self::wrc().scoring }, ..Self::wrc() }
+    }
+
+    /// Tenhou's three player rules, one red five in pins and sous.
+    pub fn sanma() -> Self {
+        Self {
+            players: 3,
+            aka: AkaDora { man: 0, pin: 1, sou: 1 },
+            dora: DoraRules { sanma: true, ..DoraRules::default() },
+            starting_points: 35000,
+            return_points: 40000,
+            uma: vec![15000, 0, -15000],
+            ..Self::tenhou()
+        }
+    }
+
+    pub fn is_sanma(&self) -> bool {
+        self.players == 3
+    }
+
+    /// The tiles these rules play with.
+    pub fn deck(&self) -> DeckSpec {
+        let spec = if self.is_sanma() { DeckSpec::sanma() } else { DeckSpec::riichi() };
+        DeckSpec { aka: self.aka, flowers: self.dora.flowers != FlowerDora::None, ..spec }
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::tenhou()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(RuleSet::default().deck().len(), 136);
+        assert_eq!(RuleSet::wrc().deck().aka.total(), 0);
+        let sanma = RuleSet::sanma();
+        assert!(sanma.is_sanma());
+        assert_eq!(sanma.deck().len(), 108);
+        assert_eq!(sanma.uma.len(), 3);
+        let dora = DoraRules { flowers: FlowerDora::Each, ..DoraRules::default() };
+        let flowers = RuleSet { dora, ..RuleSet::tenhou() };
+        assert_eq!(flowers.deck().len(), 144);
+
+        let ema = RuleSet::ema();
+        assert!(ema.ura_dora && ema.ippatsu);
+        assert!(!ema.abortive_draws && !ema.nagashi_mangan);
+        assert_eq!(ema.scoring.double_wind_fu, 4);
+        assert_eq!(ema.multiple_ron, MultipleRon::Atamahane);
+    }
+}
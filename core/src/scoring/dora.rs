@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+
+use crate::hand::Hand;
+use crate::tile::{BaseTile, Bonus, Honor, Tile};
+
+/// How flowers and seasons count as dora.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlowerDora {
+    /// Not at all, e.g. a deck without them.
+    None,
+    /// One dora each.
+    Each,
+    /// Only the flower and season numbered after the seat wind, East 1.
+    OwnSeat,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DoraRules {
+    /// Three player indicators, 1m indicates 9m and 9m indicates 1m.
+    pub sanma: bool,
+    pub flowers: FlowerDora,
+}
+
+impl Default for DoraRules {
+    fn default() -> Self {
+        Self { sanma: false, flowers: FlowerDora::None }
+    }
+}
+
+/// The dora a revealed indicator points at, the next tile of its suit,
+/// winds and dragons cycling separately. `None` for bonus tiles and jokers.
+pub fn dora_from_indicator(indicator: Tile, sanma: bool) -> Option<Tile> {
+    let next = |n: u8| n % 9 + 1;
+    match indicator {
+        Tile::Man(1) if sanma => Some(Tile::Man(9)),
+        Tile::Man(n) => Some(Tile::Man(next(n))),
+        Tile::Pin(n) => Some(Tile::Pin(next(n))),
+        Tile::Sou(n) => Some(Tile::Sou(next(n))),
+        Tile::Honor(h) if h.is_wind() => Some(Tile::Honor(Honor::ALL[(h as usize + 1) % 4])),
+        Tile::Honor(h) => Some(Tile::Honor(Honor::ALL[4 + (h as usize - 3) % 3])),
+        Tile::Bonus(_) | Tile::Joker => None,
+    }
+}
+
+/// Dora in a hand, by where they come from.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DoraCount {
+    /// From the dora and kan dora indicators.
+    pub dora: u32,
+    pub ura: u32,
+    pub aka: u32,
+    /// Flowers and seasons.
+    pub bonus: u32,
+}
+
+impl DoraCount {
+    pub fn total(&self) -> u32 {
+        self.dora + self.ura + self.aka + self.bonus
+    }
+}
+
+/// Count the dora of a hand, melds included. `ura_indicators` should be
+/// empty unless the hand won in riichi. A tile is counted once for every
+/// indicator pointing at it.
+pub fn count_dora(
+    hand: &Hand,
+    indicators: &[BaseTile],
+    ura_indicators: &[BaseTile],
+    seat_wind: Honor,
+    rules: &DoraRules,
+) -> DoraCount {
+    let all = hand.all_counts();
+    let count = |indicators: &[BaseTile]| -> u32 {
+        indicators
+            .iter()
+            .filter_map(|i| dora_from_indicator(i.tile(), rules.sanma))
+            .filter_map(|dora| dora.index())
+            .map(|i| all[i] as u32)
+            .sum()
+    };
+    let own_seat = seat_wind.ord();
+    let bonus = hand
+        .bonus()
+        .iter()
+        .filter(|tile| match (rules.flowers, tile) {
+            (FlowerDora::None, _) => false,
+            (FlowerDora::Each, _) => true,
+            (FlowerDora::OwnSeat, Tile::Bonus(Bonus::Flower(n) | Bonus::Season(n))) => *n == own_seat,
+            (FlowerDora::OwnSeat, _) => false,
+        })
+        .count() as u32;
+    DoraCount {
+        dora: count(indicators),
+        ura: count(ura_indicators),
+        aka: hand.aka() as u32,
+        bonus,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tile::map::TILES;
+
+    fn tile(s: &str) -> Tile {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_indicator() {
+        let cases = [
+            ("1m", "2m"),
+            ("9m", "1m"),
+            ("9p", "1p"),
+            ("5s", "6s"),
+            ("4z", "1z"),
+            ("2z", "3z"),
+            ("7z", "5z"),
+            ("5z", "6z"),
+        ];
+        for (indicator, dora) in cases {
+            assert_eq!(dora_from_indicator(tile(indicator), false), Some(tile(dora)), "{indicator}");
+        }
+        assert_eq!(dora_from_indicator(tile("1m"), true), Some(tile("9m")));
+        assert_eq!(dora_from_indicator(tile("9m"), true), Some(tile("1m")));
+        assert_eq!(dora_from_indicator(tile("9p"), true), Some(tile("1p")));
+        assert_eq!(dora_from_indicator(tile("1f"), false), None);
+    }
+
+    #[test]
+    fn test_count_dora() {
+        // 1m x2 and a red 5p in the hand, East flower and North season aside
+        let hand = Hand::from_tiles(&[TILES[0], TILES[1], TILES[52], TILES[53], TILES[136], TILES[143]]).unwrap();
+        // 9m and 9m again (kan dora) point at 1m, 4p at 5p
+        let indicators = [TILES[32], TILES[33], TILES[48]];
+        let ura = [TILES[35]];
+        let rules = DoraRules::default();
+        let count = count_dora(&hand, &indicators, &ura, Honor::East, &rules);
+        assert_eq!(count, DoraCount { dora: 6, ura: 2, aka: 1, bonus: 0 });
+        assert_eq!(count.total(), 9);
+
+        let each = DoraRules { flowers: FlowerDora::Each, ..rules };
+        assert_eq!(count_dora(&hand, &[], &[], Honor::East, &each).bonus, 2);
+        let own = DoraRules { flowers: FlowerDora::OwnSeat, ..rules };
+        assert_eq!(count_dora(&hand, &[], &[], Honor::East, &own).bonus, 1);
+        assert_eq!(count_dora(&hand, &[], &[], Honor::North, &own).bonus, 1);
+        assert_eq!(count_dora(&hand, &[], &[], Honor::South, &own).bonus, 0);
+
+        let hand: Hand = "99m [p111m]".parse().unwrap();
+        let sanma = DoraRules { sanma: true, ..rules };
+        assert_eq!(count_dora(&hand, &[TILES[0]], &[], Honor::East, &sanma).dora, 2);
+        assert_eq!(count_dora(&hand, &[TILES[32]], &[], Honor::East, &sanma).dora, 3);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod dora;
+pub mod points;
+pub mod yaku;
+
+pub use dora::{count_dora, dora_from_indicator, DoraCount, DoraRules, FlowerDora};
+pub use points::{fu, score_hand, seat_wind, Limit, Payment, SanmaTsumo, Score, ScoredHand, ScoringRules};
+pub use yaku::{best_yaku, evaluate, Yaku, YakuRules, WinContext};
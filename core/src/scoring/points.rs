@@ -0,0 +1,340 @@
+use serde::{Deserialize, Serialize};
+
+use crate::hand::{decompose, Agari, Group, GroupKind, Hand};
+use crate::rules::RuleSet;
+use crate::tile::{Honor, Tile};
+
+use super::yaku::{evaluate, Yaku, WinContext, YAKUMAN_HAN};
+
+/// Rule options for turning han and fu into points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoringRules {
+    /// 13 han or more without a yakuman is a yakuman, otherwise sanbaiman.
+    pub kazoe_yakuman: bool,
+    /// 4 han 30 fu and 3 han 60 fu are rounded up to mangan.
+    pub kiriage_mangan: bool,
+    /// Fu for a pair of the wind that is both seat and round wind.
+    pub double_wind_fu: u8,
+    pub sanma_tsumo: SanmaTsumo,
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self { kazoe_yakuman: true, kiriage_mangan: false, double_wind_fu: 4, sanma_tsumo: SanmaTsumo::TsumoLoss }
+    }
+}
+
+/// How a tsumo is paid at a three player table, without a North seat.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SanmaTsumo {
+    /// The winner goes without North's share.
+    TsumoLoss,
+    /// North's share is split between the two payers.
+    Bisection,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Limit {
+    Mangan,
+    Haneman,
+    Baiman,
+    Sanbaiman,
+    KazoeYakuman,
+    /// Number of yakuman.
+    Yakuman(u8),
+}
+
+/// Value of a hand before it is split into payments.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Score {
+    /// Yaku and dora, `0` for a yakuman.
+    pub han: u32,
+    pub fu: u8,
+    pub limit: Option<Limit>,
+    /// Basic points, what a non-dealer ron pays a quarter of.
+    pub base: u32,
+}
+
+impl Score {
+    pub fn new(han: u32, fu: u8, yakuman: u8, rules: &ScoringRules) -> Self {
+        let (limit, base) = match han {
+            _ if yakuman > 0 => (Some(Limit::Yakuman(yakuman)), 8000 * yakuman as u32),
+            13.. if rules.kazoe_yakuman => (Some(Limit::KazoeYakuman), 8000),
+            11.. => (Some(Limit::Sanbaiman), 6000),
+            8..=10 => (Some(Limit::Baiman), 4000),
+            6..=7 => (Some(Limit::Haneman), 3000),
+            5 => (Some(Limit::Mangan), 2000),
+            _ => {
+                let base = fu as u32 * (1 << (han + 2));
+                if base >= 2000 || (rules.kiriage_mangan && base >= 1920) {
+                    (Some(Limit::Mangan), 2000)
+                } else {
+                    (None, base)
+                }
+            }
+        };
+        let han = if yakuman > 0 { 0 } else { han };
+        Self { han, fu, limit, base }
+    }
+}
+
+/// Points moved by a win, by seat. The winner gains what the others lose,
+/// plus the riichi sticks on the table.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Payment {
+    pub deltas: Vec<i32>,
+}
+
+impl Payment {
+    /// `from` is the seat that dealt in, `None` for tsumo. Honba add 300 to
+    /// a ron and 100 per payer to a tsumo, each riichi stick is worth 1000.
+    pub fn new(
+        score: &Score,
+        players: usize,
+        winner: usize,
+        dealer: usize,
+        from: Option<usize>,
+        honba: u32,
+        riichi_sticks: u32,
+    ) -> Self {
+        let mut deltas = vec![0i32; players];
+        let mut pay = |seat: usize, points: u32| {
+            deltas[seat] -= points as i32;
+            deltas[winner] += points as i32;
+        };
+        match from {
+            Some(from) => {
+                let multiple = if winner == dealer { 6 } else { 4 };
+                pay(from, round_up(score.base * multiple) + 300 * honba);
+            }
+            None => {
+                for seat in (0..players).filter(|&s| s != winner) {
+                    let multiple = if winner == dealer || seat == dealer { 2 } else { 1 };
+                    pay(seat, round_up(score.base * multiple) + 100 * honba);
+                }
+            }
+        }
+        deltas[winner] += 1000 * riichi_sticks as i32;
+        Self { deltas }
+    }
+
+    /// Add what a North seat would have paid for a tsumo, split between
+    /// the payers of a three player table and rounded up to 100 each.
+    pub fn bisect_north(&mut self, score: &Score, winner: usize, dealer: usize) {
+        let multiple = if winner == dealer { 2 } else { 1 };
+        let share = round_up(score.base * multiple);
+        let payers = self.deltas.len() as u32 - 1;
+        let each = round_up(share.div_ceil(payers)) as i32;
+        for (seat, delta) in self.deltas.iter_mut().enumerate() {
+            *delta += if seat == winner { each * payers as i32 } else { -each };
+        }
+    }
+
+    /// What the winner receives, sticks included.
+    pub fn total(&self) -> i32 {
+        self.deltas.iter().copied().max().unwrap_or(0)
+    }
+}
+
+fn round_up(points: u32) -> u32 {
+    points.div_ceil(100) * 100
+}
+
+/// Fu of one reading of a winning hand, rounded up to 10 except for
+/// chiitoitsu's 25.
+pub fn fu(hand: &Hand, agari: &Agari, ctx: &WinContext, yaku: &[(Yaku, u8)], rules: &ScoringRules) -> u8 {
+    let (pair, groups) = match agari {
+        Agari::Chiitoitsu { .. } => return 25,
+        Agari::Kokushi { .. } => return 30,
+        Agari::Standard { pair, groups } => (*pair, groups),
+    };
+    if yaku.iter().any(|(y, _)| *y == Yaku::Pinfu) {
+        return if ctx.tsumo { 20 } else { 30 };
+    }
+    let closed = hand.is_closed();
+    let mut fu = 20;
+    if closed && !ctx.tsumo {
+        fu += 10;
+    }
+    if ctx.tsumo {
+        fu += 2;
+    }
+    fu += pair_fu(pair, ctx, rules);
+
+    // the winning tile is read wherever it gives the most fu
+    let win = ctx.win_tile;
+    let mut best = None;
+    let mut consider = |wait: u8, shanpon: Option<usize>| {
+        let sets: u8 = groups
+            .iter()
+            .enumerate()
+            .map(|(i, g)| group_fu(g, shanpon == Some(i) && !ctx.tsumo))
+            .sum();
+        best = best.max(Some(wait + sets));
+    };
+    if pair == win {
+        consider(2, None);
+    }
+    for (i, group) in groups.iter().enumerate().filter(|(_, g)| !g.open && g.contains(win)) {
+        match group.kind {
+            GroupKind::Sequence => {
+                let kanchan = win.ord() == group.tile.ord() + 1;
+                let penchan = (group.tile.ord() == 1 && win.ord() == 3) || (group.tile.ord() == 7 && win.ord() == 7);
+                consider(if kanchan || penchan { 2 } else { 0 }, None);
+            }
+            _ => consider(0, Some(i)),
+        }
+    }
+    fu += best.unwrap_or(0);
+
+    let fu = fu.div_ceil(10) * 10;
+    // an open hand without any fu still scores 30
+    fu.max(30)
+}
+
+fn pair_fu(pair: Tile, ctx: &WinContext, rules: &ScoringRules) -> u8 {
+    match pair {
+        Tile::Honor(h) if h.is_dragon() => 2,
+        Tile::Honor(h) if h == ctx.seat_wind && h == ctx.round_wind => rules.double_wind_fu,
+        Tile::Honor(h) if h == ctx.seat_wind || h == ctx.round_wind => 2,
+        _ => 0,
+    }
+}
+
+/// `ron_completed` marks a closed triplet finished by ron, scored as open.
+fn group_fu(group: &Group, ron_completed: bool) -> u8 {
+    let base = match group.kind {
+        GroupKind::Sequence => return 0,
+        GroupKind::Triplet => 2,
+        GroupKind::Kan => 8,
+    };
+    let closed = if !group.open && !ron_completed { 2 } else { 1 };
+    let yaochuu = if group.tile.is_yaochuu() { 2 } else { 1 };
+    base * closed * yaochuu
+}
+
+/// A scored win: the reading used, its yaku and the value.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoredHand {
+    pub agari: Agari,
+    pub yaku: Vec<(Yaku, u8)>,
+    pub dora: u32,
+    pub score: Score,
+}
+
+/// Score the most valuable reading of a winning hand. `dora` counts dora,
+/// ura dora, aka dora and any bonus tiles. `None` if the hand is not
+/// complete or has no yaku.
+pub fn score_hand(
+    hand: &Hand,
+    ctx: &WinContext,
+    dora: u32,
+    rules: &RuleSet,
+) -> Option<ScoredHand> {
+    decompose(hand)
+        .into_iter()
+        .filter_map(|agari| {
+            let yaku = evaluate(hand, &agari, ctx, &rules.yaku);
+            if yaku.is_empty() {
+                return None;
+            }
+            let yakuman: u32 = yaku.iter().filter(|(y, _)| y.is_yakuman()).map(|(_, han)| *han as u32).sum();
+            let fu = fu(hand, &agari, ctx, &yaku, &rules.scoring);
+            let han = yaku.iter().map(|(_, han)| *han as u32).sum::<u32>() + dora;
+            let score = Score::new(han, fu, (yakuman / YAKUMAN_HAN as u32) as u8, &rules.scoring);
+            Some(ScoredHand { agari, yaku, dora, score })
+        })
+        .max_by_key(|s| (s.score.base, s.score.han, s.score.fu))
+}
+
+/// Seat wind of `seat` with `dealer` sitting East.
+pub fn seat_wind(seat: usize, dealer: usize, players: usize) -> Honor {
+    Honor::ALL[(seat + players - dealer) % players]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scored(s: &str, win: &str, tsumo: bool, seat: Honor) -> ScoredHand {
+        let hand: Hand = s.parse().unwrap();
+        let ctx = WinContext::new(win.parse().unwrap(), tsumo, seat, Honor::East);
+        score_hand(&hand, &ctx, 0, &RuleSet::default()).unwrap()
+    }
+
+    #[test]
+    fn test_fu() {
+        // pinfu tsumo is always 20, pinfu ron 30
+        assert_eq!(scored("234m456p678s22345s", "5s", true, Honor::South).score.fu, 20);
+        assert_eq!(scored("234m456p678s22345s", "5s", false, Honor::South).score.fu, 30);
+        // closed ron 30 + kanchan 2 = 32 -> 40
+        assert_eq!(scored("234m456p678s22345s", "4s", false, Honor::South).score.fu, 40);
+        assert_eq!(scored("1155m2299p6677s11z", "1z", false, Honor::South).score.fu, 25);
+        // 20 + closed ron 10 + closed 222m 4 + closed 111z 8 + tanki 2 = 44 -> 50
+        assert_eq!(scored("222m456p678s111z99s", "9s", false, Honor::East).score.fu, 50);
+        // shanpon ron: the 1z triplet counts as open
+        assert_eq!(scored("222m456p678s111z99s", "1z", false, Honor::East).score.fu, 40);
+        // open hand, 20 + open 555z 4 = 24 -> 30
+        assert_eq!(scored("234m456p11s [c678s] [p555z]", "1s", false, Honor::South).score.fu, 30);
+        // ankan of terminals 32 + tsumo 2
+        assert_eq!(scored("234m456p11s678s [a9999m]", "1s", true, Honor::South).score.fu, 60);
+    }
+
+    #[test]
+    fn test_score() {
+        let rules = ScoringRules::default();
+        assert_eq!(Score::new(1, 30, 0, &rules).base, 240);
+        assert_eq!(Score::new(3, 70, 0, &rules).limit, Some(Limit::Mangan));
+        assert_eq!(Score::new(4, 30, 0, &rules).base, 1920);
+        let kiriage = ScoringRules { kiriage_mangan: true, ..rules };
+        assert_eq!(Score::new(4, 30, 0, &kiriage).base, 2000);
+        assert_eq!(Score::new(7, 30, 0, &rules).limit, Some(Limit::Haneman));
+        assert_eq!(Score::new(10, 30, 0, &rules).base, 4000);
+        assert_eq!(Score::new(12, 30, 0, &rules).base, 6000);
+        assert_eq!(Score::new(14, 30, 0, &rules).limit, Some(Limit::KazoeYakuman));
+        let no_kazoe = ScoringRules { kazoe_yakuman: false, ..rules };
+        assert_eq!(Score::new(14, 30, 0, &no_kazoe).limit, Some(Limit::Sanbaiman));
+        assert_eq!(Score::new(0, 30, 2, &rules).base, 16000);
+    }
+
+    #[test]
+    fn test_payment() {
+        let rules = ScoringRules::default();
+        // 1 han 30 fu: non-dealer ron 1000, dealer ron 1500
+        let score = Score::new(1, 30, 0, &rules);
+        assert_eq!(Payment::new(&score, 4, 1, 0, Some(2), 0, 0).deltas, [0, 1000, -1000, 0]);
+        assert_eq!(Payment::new(&score, 4, 0, 0, Some(2), 0, 0).deltas, [1500, 0, -1500, 0]);
+        // non-dealer tsumo 300/500, with two honba and a riichi stick
+        let payment = Payment::new(&score, 4, 1, 0, None, 2, 1);
+        assert_eq!(payment.deltas, [-700, 2700, -500, -500]);
+        assert_eq!(payment.total(), 2700);
+        // dealer mangan tsumo 4000 all
+        let mangan = Score::new(5, 30, 0, &rules);
+        assert_eq!(Payment::new(&mangan, 4, 2, 2, None, 0, 0).deltas, [-4000, -4000, 12000, -4000]);
+        // 3 han 40 fu ron with a honba: 5200 + 300
+        let score = Score::new(3, 40, 0, &rules);
+        assert_eq!(Payment::new(&score, 4, 3, 0, Some(0), 1, 0).deltas, [-5500, 0, 0, 5500]);
+
+        // sanma non-dealer 1000/2000: tsumo loss, or North's 1000 split
+        let score = Score::new(3, 30, 0, &rules);
+        let mut payment = Payment::new(&score, 3, 1, 0, None, 0, 0);
+        assert_eq!(payment.deltas, [-2000, 3000, -1000]);
+        payment.bisect_north(&score, 1, 0);
+        assert_eq!(payment.deltas, [-2500, 4000, -1500]);
+        // dealer 500 all: North's 500 split as 300 each
+        let score = Score::new(1, 30, 0, &rules);
+        let mut payment = Payment::new(&score, 3, 0, 0, None, 0, 0);
+        payment.bisect_north(&score, 0, 0);
+        assert_eq!(payment.deltas, [1600, -800, -800]);
+    }
+
+    #[test]
+    fn test_score_hand() {
+        // the ryanpeikou reading beats chiitoitsu
+        let scored = scored("223344m556677p99s", "7p", false, Honor::South);
+        assert!(matches!(scored.agari, Agari::Standard { .. }));
+        assert_eq!(scored.score.han, 4);
+        assert_eq!(seat_wind(2, 1, 4), Honor::South);
+        assert_eq!(seat_wind(0, 1, 3), Honor::West);
+    }
+}
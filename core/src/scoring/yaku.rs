@@ -0,0 +1,547 @@
+use serde::{Deserialize, Serialize};
+
+use crate::hand::{decompose, Agari, Group, GroupKind, Hand};
+use crate::tile::{Honor, Tile};
+
+/// How the hand was won, everything the tiles alone do not tell.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WinContext {
+    /// The tile won on, also counted in the hand.
+    pub win_tile: Tile,
+    pub tsumo: bool,
+    pub seat_wind: Honor,
+    pub round_wind: Honor,
+    pub riichi: bool,
+    pub double_riichi: bool,
+    pub ippatsu: bool,
+    /// Won on the last tile of the wall, haitei by tsumo or houtei by ron.
+    pub last_tile: bool,
+    pub rinshan: bool,
+    pub chankan: bool,
+    pub tenhou: bool,
+    pub chiihou: bool,
+}
+
+impl WinContext {
+    /// A plain win with nothing special about it.
+    pub fn new(win_tile: Tile, tsumo: bool, seat_wind: Honor, round_wind: Honor) -> Self {
+        Self {
+            win_tile,
+            tsumo,
+            seat_wind,
+            round_wind,
+            riichi: false,
+            double_riichi: false,
+            ippatsu: false,
+            last_tile: false,
+            rinshan: false,
+            chankan: false,
+            tenhou: false,
+            chiihou: false,
+        }
+    }
+}
+
+/// Rule options that change which yaku count.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct YakuRules {
+    /// Kuitan, tanyao with open melds.
+    pub open_tanyao: bool,
+    /// Kokushi on a 13 sided wait, suuankou tanki, junsei chuuren and
+    /// daisuushii count as two yakuman.
+    pub double_yakuman: bool,
+}
+
+impl Default for YakuRules {
+    fn default() -> Self {
+        Self { open_tanyao: true, double_yakuman: true }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Yaku {
+    Riichi,
+    DoubleRiichi,
+    Ippatsu,
+    MenzenTsumo,
+    Pinfu,
+    Iipeikou,
+    Tanyao,
+    SeatWind,
+    RoundWind,
+    Haku,
+    Hatsu,
+    Chun,
+    Haitei,
+    Houtei,
+    Rinshan,
+    Chankan,
+    Chiitoitsu,
+    Sanshoku,
+    SanshokuDoukou,
+    Ittsu,
+    Chanta,
+    Toitoi,
+    Sanankou,
+    Sankantsu,
+    Shousangen,
+    Honroutou,
+    Ryanpeikou,
+    Junchan,
+    Honitsu,
+    Chinitsu,
+    // yakuman
+    Tenhou,
+    Chiihou,
+    Kokushi,
+    Kokushi13,
+    Suuankou,
+    SuuankouTanki,
+    Daisangen,
+    Shousuushii,
+    Daisuushii,
+    Tsuuiisou,
+    Chinroutou,
+    Ryuuiisou,
+    Chuuren,
+    JunseiChuuren,
+    Suukantsu,
+}
+
+/// Han of one yakuman.
+pub const YAKUMAN_HAN: u8 = 13;
+
+impl Yaku {
+    pub fn is_yakuman(self) -> bool {
+        self >= Yaku::Tenhou
+    }
+
+    /// Han for the yaku, with kuisagari for an open hand. A yakuman is
+    /// [`YAKUMAN_HAN`] per multiple.
+    pub fn han(self, open: bool, rules: &YakuRules) -> u8 {
+        let open = open as u8;
+        match self {
+            Yaku::DoubleRiichi | Yaku::Chiitoitsu | Yaku::Toitoi | Yaku::SanshokuDoukou => 2,
+            Yaku::Sanankou | Yaku::Sankantsu | Yaku::Shousangen | Yaku::Honroutou => 2,
+            Yaku::Sanshoku | Yaku::Ittsu | Yaku::Chanta => 2 - open,
+            Yaku::Ryanpeikou => 3,
+            Yaku::Junchan | Yaku::Honitsu => 3 - open,
+            Yaku::Chinitsu => 6 - open,
+            Yaku::Kokushi13 | Yaku::SuuankouTanki | Yaku::Daisuushii | Yaku::JunseiChuuren
+                if rules.double_yakuman =>
+            {
+                2 * YAKUMAN_HAN
+            }
+            y if y.is_yakuman() => YAKUMAN_HAN,
+            _ => 1,
+        }
+    }
+}
+
+/// Yaku of every reading of a winning hand, the one with the most han.
+pub fn best_yaku(hand: &Hand, ctx: &WinContext, rules: &YakuRules) -> Option<(Agari, Vec<(Yaku, u8)>)> {
+    decompose(hand)
+        .into_iter()
+        .map(|agari| {
+            let yaku = evaluate(hand, &agari, ctx, rules);
+            (agari, yaku)
+        })
+        .filter(|(_, yaku)| !yaku.is_empty())
+        .max_by_key(|(_, yaku)| total_han(yaku))
+}
+
+pub fn total_han(yaku: &[(Yaku, u8)]) -> u32 {
+    yaku.iter().map(|(_, han)| *han as u32).sum()
+}
+
+/// Yaku of one reading of a winning hand with their han. Only yakuman are
+/// listed if there are any, empty if the hand has no yaku. Dora are not
+/// yaku and are left out.
+pub fn evaluate(hand: &Hand, agari: &Agari, ctx: &WinContext, rules: &YakuRules) -> Vec<(Yaku, u8)> {
+    let open = !hand.is_closed();
+    let found = Evaluator::new(hand, agari, ctx).yaku(rules);
+    let yakuman: Vec<Yaku> = found.iter().copied().filter(|y| y.is_yakuman()).collect();
+    let found = if yakuman.is_empty() { found } else { yakuman };
+    found.into_iter().map(|y| (y, y.han(open, rules))).collect()
+}
+
+struct Evaluator<'a> {
+    hand: &'a Hand,
+    agari: &'a Agari,
+    ctx: &'a WinContext,
+    /// Every tile of the hand, melds included.
+    all: [u8; 34],
+}
+
+impl<'a> Evaluator<'a> {
+    fn new(hand: &'a Hand, agari: &'a Agari, ctx: &'a WinContext) -> Self {
+        Self { hand, agari, ctx, all: hand.all_counts() }
+    }
+
+    fn tiles(&self) -> impl Iterator<Item = Tile> + '_ {
+        (0..34).filter(|&i| self.all[i] > 0).filter_map(Tile::from_index)
+    }
+
+    fn yaku(&self, rules: &YakuRules) -> Vec<Yaku> {
+        let ctx = self.ctx;
+        let closed = self.hand.is_closed();
+        let mut yaku = vec![];
+
+        if ctx.tenhou {
+            yaku.push(Yaku::Tenhou);
+        }
+        if ctx.chiihou {
+            yaku.push(Yaku::Chiihou);
+        }
+        if ctx.double_riichi {
+            yaku.push(Yaku::DoubleRiichi);
+        } else if ctx.riichi {
+            yaku.push(Yaku::Riichi);
+        }
+        if ctx.ippatsu {
+            yaku.push(Yaku::Ippatsu);
+        }
+        if closed && ctx.tsumo {
+            yaku.push(Yaku::MenzenTsumo);
+        }
+        if ctx.last_tile {
+            yaku.push(if ctx.tsumo { Yaku::Haitei } else { Yaku::Houtei });
+        }
+        if ctx.rinshan {
+            yaku.push(Yaku::Rinshan);
+        }
+        if ctx.chankan {
+            yaku.push(Yaku::Chankan);
+        }
+
+        // yaku that only look at the tiles
+        if self.tiles().all(|t| !t.is_yaochuu()) && (closed || rules.open_tanyao) {
+            yaku.push(Yaku::Tanyao);
+        }
+        if self.tiles().all(|t| t.is_honor()) {
+            yaku.push(Yaku::Tsuuiisou);
+        } else if self.tiles().all(|t| t.is_terminal()) {
+            yaku.push(Yaku::Chinroutou);
+        } else if self.tiles().all(|t| t.is_yaochuu()) {
+            yaku.push(Yaku::Honroutou);
+        }
+        if self.tiles().all(is_green) {
+            yaku.push(Yaku::Ryuuiisou);
+        }
+        let suits: Vec<_> = self.tiles().filter(|t| t.is_suited()).map(|t| t.kind()).collect();
+        if !suits.is_empty() && suits.iter().all(|&k| k == suits[0]) {
+            if self.tiles().any(|t| t.is_honor()) {
+                yaku.push(Yaku::Honitsu);
+            } else {
+                yaku.push(Yaku::Chinitsu);
+                if let Some(chuuren) = self.chuuren() {
+                    yaku.push(chuuren);
+                }
+            }
+        }
+
+        match self.agari {
+            Agari::Kokushi { .. } => {
+                let mut without = *self.hand.closed();
+                without[ctx.win_tile.index().unwrap()] -= 1;
+                let thirteen_sided = without.iter().all(|&n| n <= 1);
+                yaku.push(if thirteen_sided { Yaku::Kokushi13 } else { Yaku::Kokushi });
+            }
+            Agari::Chiitoitsu { .. } => yaku.push(Yaku::Chiitoitsu),
+            Agari::Standard { pair, groups } => self.standard(*pair, groups, &mut yaku),
+        }
+        yaku
+    }
+
+    fn standard(&self, pair: Tile, groups: &[Group], yaku: &mut Vec<Yaku>) {
+        let ctx = self.ctx;
+        let closed = self.hand.is_closed();
+        let sequences: Vec<&Group> = groups.iter().filter(|g| g.kind == GroupKind::Sequence).collect();
+        let triplets: Vec<&Group> = groups.iter().filter(|g| g.kind != GroupKind::Sequence).collect();
+        let kans = groups.iter().filter(|g| g.kind == GroupKind::Kan).count();
+        let dragons = triplets.iter().filter(|g| matches!(g.tile, Tile::Honor(h) if h.is_dragon())).count();
+        let winds = triplets.iter().filter(|g| matches!(g.tile, Tile::Honor(h) if h.is_wind())).count();
+        let concealed = self.concealed_triplets(pair, groups);
+
+        // yakuman
+        if concealed == 4 {
+            yaku.push(if pair == ctx.win_tile { Yaku::SuuankouTanki } else { Yaku::Suuankou });
+        }
+        if dragons == 3 {
+            yaku.push(Yaku::Daisangen);
+        }
+        if winds == 4 {
+            yaku.push(Yaku::Daisuushii);
+        } else if winds == 3 && matches!(pair, Tile::Honor(h) if h.is_wind()) {
+            yaku.push(Yaku::Shousuushii);
+        }
+        if kans == 4 {
+            yaku.push(Yaku::Suukantsu);
+        }
+
+        for group in &triplets {
+            match group.tile {
+                Tile::Honor(Honor::White) => yaku.push(Yaku::Haku),
+                Tile::Honor(Honor::Green) => yaku.push(Yaku::Hatsu),
+                Tile::Honor(Honor::Red) => yaku.push(Yaku::Chun),
+                Tile::Honor(wind) => {
+                    if wind == ctx.seat_wind {
+                        yaku.push(Yaku::SeatWind);
+                    }
+                    if wind == ctx.round_wind {
+                        yaku.push(Yaku::RoundWind);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let yakuhai_pair = matches!(pair, Tile::Honor(h) if h.is_dragon() || h == ctx.seat_wind || h == ctx.round_wind);
+        if closed && sequences.len() == 4 && !yakuhai_pair && self.ryanmen(groups) {
+            yaku.push(Yaku::Pinfu);
+        }
+
+        if closed {
+            let mut tiles: Vec<Tile> = sequences.iter().map(|g| g.tile).collect();
+            tiles.sort();
+            let mut peikou = 0;
+            let mut i = 0;
+            while i + 1 < tiles.len() {
+                if tiles[i] == tiles[i + 1] {
+                    peikou += 1;
+                    i += 2;
+                } else {
+                    i += 1;
+                }
+            }
+            match peikou {
+                1 => yaku.push(Yaku::Iipeikou),
+                2 => yaku.push(Yaku::Ryanpeikou),
+                _ => {}
+            }
+        }
+
+        let in_all_suits = |groups: &[&Group]| {
+            groups.iter().any(|g| {
+                g.tile.is_suited()
+                    && [Tile::Man, Tile::Pin, Tile::Sou]
+                        .iter()
+                        .all(|suit| groups.iter().any(|o| o.tile == suit(g.tile.ord())))
+            })
+        };
+        if in_all_suits(&sequences) {
+            yaku.push(Yaku::Sanshoku);
+        }
+        if in_all_suits(&triplets) {
+            yaku.push(Yaku::SanshokuDoukou);
+        }
+        if sequences.iter().any(|g| {
+            g.tile.ord() == 1
+                && [4, 7].iter().all(|&ord| {
+                    sequences.iter().any(|o| o.tile.kind() == g.tile.kind() && o.tile.ord() == ord)
+                })
+        }) {
+            yaku.push(Yaku::Ittsu);
+        }
+
+        if !sequences.is_empty() && pair.is_yaochuu() && groups.iter().all(|g| g.is_yaochuu()) {
+            let honors = pair.is_honor() || groups.iter().any(|g| g.tile.is_honor());
+            yaku.push(if honors { Yaku::Chanta } else { Yaku::Junchan });
+        }
+        if sequences.is_empty() {
+            yaku.push(Yaku::Toitoi);
+        }
+        if concealed == 3 {
+            yaku.push(Yaku::Sanankou);
+        }
+        if kans == 3 {
+            yaku.push(Yaku::Sankantsu);
+        }
+        if dragons == 2 && matches!(pair, Tile::Honor(h) if h.is_dragon()) {
+            yaku.push(Yaku::Shousangen);
+        }
+    }
+
+    /// Closed triplets and kans. A triplet completed by ron counts as open,
+    /// unless the winning tile can be read as part of the pair or a closed
+    /// sequence instead.
+    fn concealed_triplets(&self, pair: Tile, groups: &[Group]) -> usize {
+        let win = self.ctx.win_tile;
+        let closed: Vec<&Group> = groups.iter().filter(|g| !g.open).collect();
+        let mut count = closed.iter().filter(|g| g.kind != GroupKind::Sequence).count();
+        let elsewhere = pair == win
+            || closed.iter().any(|g| g.kind == GroupKind::Sequence && g.contains(win));
+        if !self.ctx.tsumo
+            && !elsewhere
+            && closed.iter().any(|g| g.kind == GroupKind::Triplet && g.tile == win)
+        {
+            count -= 1;
+        }
+        count
+    }
+
+    /// Whether the winning tile can be read as a two sided wait.
+    fn ryanmen(&self, groups: &[Group]) -> bool {
+        let win = self.ctx.win_tile;
+        groups.iter().any(|g| {
+            !g.open
+                && g.kind == GroupKind::Sequence
+                && ((g.tile == win && win.ord() <= 6) || (g.contains(win) && win.ord() == g.tile.ord() + 2 && g.tile.ord() >= 2))
+        })
+    }
+
+    /// Chuuren poutou: closed 1112345678999 plus any tile of the suit.
+    fn chuuren(&self) -> Option<Yaku> {
+        if !self.hand.melds().is_empty() {
+            return None;
+        }
+        let first = self.tiles().next()?.index()? / 9 * 9;
+        let suit = &self.all[first..first + 9];
+        let base = [3, 1, 1, 1, 1, 1, 1, 1, 3];
+        if suit.iter().zip(base).any(|(&n, b)| n < b) {
+            return None;
+        }
+        let extra = self.ctx.win_tile.index()? - first;
+        let junsei = suit.iter().enumerate().all(|(i, &n)| n - (i == extra) as u8 == base[i]);
+        Some(if junsei { Yaku::JunseiChuuren } else { Yaku::Chuuren })
+    }
+}
+
+fn is_green(tile: Tile) -> bool {
+    matches!(tile, Tile::Sou(2 | 3 | 4 | 6 | 8) | Tile::Honor(Honor::Green))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tile(s: &str) -> Tile {
+        s.parse().unwrap()
+    }
+
+    /// Yaku of the best reading, `s` including the winning tile `win`.
+    fn yaku_of(s: &str, win: &str, tsumo: bool) -> Vec<(Yaku, u8)> {
+        let ctx = WinContext::new(tile(win), tsumo, Honor::South, Honor::East);
+        yaku_with(s, &ctx)
+    }
+
+    fn yaku_with(s: &str, ctx: &WinContext) -> Vec<(Yaku, u8)> {
+        let hand: Hand = s.parse().unwrap();
+        best_yaku(&hand, ctx, &YakuRules::default()).map_or(vec![], |(_, yaku)| yaku)
+    }
+
+    fn names(yaku: &[(Yaku, u8)]) -> Vec<Yaku> {
+        let mut names: Vec<Yaku> = yaku.iter().map(|(y, _)| *y).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_basic_yaku() {
+        assert_eq!(
+            names(&yaku_of("234m456p678s22345s", "5s", true)),
+            [Yaku::MenzenTsumo, Yaku::Pinfu, Yaku::Tanyao]
+        );
+        // kanchan is not pinfu
+        assert_eq!(names(&yaku_of("234m456p678s22345s", "4s", false)), [Yaku::Tanyao]);
+        assert_eq!(names(&yaku_of("234m456p11s [c678s] [p555z]", "1s", false)), [Yaku::Haku]);
+        assert_eq!(yaku_of("234m456p11s [c678s] [p999m]", "1s", false), vec![]);
+        // double east for the dealer
+        let ctx = WinContext::new(tile("9m"), false, Honor::East, Honor::East);
+        assert_eq!(
+            names(&yaku_with("123m456p789s99m [p111z]", &ctx)),
+            [Yaku::SeatWind, Yaku::RoundWind]
+        );
+    }
+
+    #[test]
+    fn test_closed_and_open_values() {
+        let closed = yaku_of("123456789m12399p", "9p", false);
+        assert_eq!(closed, vec![(Yaku::Ittsu, 2)]);
+        let open = yaku_of("456789m12399p [c123m]", "9p", false);
+        assert_eq!(open, vec![(Yaku::Ittsu, 1)]);
+
+        assert_eq!(
+            names(&yaku_of("11223344556677m", "7m", false)),
+            [Yaku::Pinfu, Yaku::Ryanpeikou, Yaku::Chinitsu]
+        );
+        assert_eq!(
+            names(&yaku_of("123m123p123s789s11z", "1z", false)),
+            [Yaku::Sanshoku, Yaku::Chanta]
+        );
+        assert_eq!(
+            names(&yaku_of("123m123p123s789s99m", "9m", false)),
+            [Yaku::Sanshoku, Yaku::Junchan]
+        );
+        assert_eq!(names(&yaku_of("1155m2299p6677s11z", "1z", false)), [Yaku::Chiitoitsu]);
+    }
+
+    #[test]
+    fn test_triplets() {
+        // ron on the shanpon wait makes that triplet open
+        assert_eq!(
+            names(&yaku_of("111m333p555s77799p", "7p", false)),
+            [Yaku::Toitoi, Yaku::Sanankou]
+        );
+        assert_eq!(names(&yaku_of("111m333p555s77799p", "7p", true)), [Yaku::Suuankou]);
+        assert_eq!(
+            yaku_of("111m333p555s77799p", "9p", false),
+            vec![(Yaku::SuuankouTanki, 26)]
+        );
+        assert_eq!(
+            names(&yaku_of("55z666p234m [p777z] [a6666z]", "2m", false)),
+            [Yaku::Hatsu, Yaku::Chun, Yaku::Shousangen]
+        );
+        assert_eq!(names(&yaku_of("11m [p555z] [p666z] [p777z] 234m", "1m", false)), [Yaku::Daisangen]);
+        assert_eq!(
+            names(&yaku_of("111m111p111s234s99s", "4s", false)),
+            [Yaku::SanshokuDoukou, Yaku::Sanankou]
+        );
+    }
+
+    #[test]
+    fn test_yakuman() {
+        assert_eq!(yaku_of("19m19p19s1234567z1m", "1m", false), vec![(Yaku::Kokushi13, 26)]);
+        assert_eq!(yaku_of("119m19p19s123456z7z", "7z", false), vec![(Yaku::Kokushi, 13)]);
+        assert_eq!(yaku_of("11123456789999m", "1m", false), vec![(Yaku::Chuuren, 13)]);
+        assert_eq!(yaku_of("11123456789995m", "5m", false), vec![(Yaku::JunseiChuuren, 26)]);
+        assert_eq!(yaku_of("223344666888s66z", "6z", false), vec![(Yaku::Ryuuiisou, 13)]);
+        assert_eq!(
+            names(&yaku_of("11122233344555z", "5z", true)),
+            [Yaku::Suuankou, Yaku::Shousuushii, Yaku::Tsuuiisou]
+        );
+        assert_eq!(
+            yaku_of("111999m111999p11s", "1s", false),
+            vec![(Yaku::Chinroutou, 13), (Yaku::SuuankouTanki, 26)]
+        );
+
+        let mut ctx = WinContext::new(tile("5s"), true, Honor::East, Honor::East);
+        ctx.tenhou = true;
+        assert_eq!(names(&yaku_with("234m456p678s22345s", &ctx)), [Yaku::Tenhou]);
+    }
+
+    #[test]
+    fn test_situational() {
+        let mut ctx = WinContext::new(tile("2p"), false, Honor::West, Honor::East);
+        ctx.riichi = true;
+        ctx.ippatsu = true;
+        ctx.last_tile = true;
+        assert_eq!(
+            names(&yaku_with("123m456m789s1p33z 2p 3p", &ctx)),
+            [Yaku::Riichi, Yaku::Ippatsu, Yaku::Houtei]
+        );
+        ctx.double_riichi = true;
+        ctx.tsumo = true;
+        ctx.rinshan = true;
+        assert_eq!(
+            yaku_with("123m456m789s1p33z 2p 3p", &ctx),
+            vec![(Yaku::DoubleRiichi, 2), (Yaku::Ippatsu, 1), (Yaku::MenzenTsumo, 1), (Yaku::Haitei, 1), (Yaku::Rinshan, 1)]
+        );
+
+        let rules = YakuRules { open_tanyao: false, double_yakuman: false };
+        let hand: Hand = "234m456p66s [c678s] [p555m]".parse().unwrap();
+        let ctx = WinContext::new(tile("6s"), false, Honor::South, Honor::East);
+        assert!(best_yaku(&hand, &ctx, &rules).is_none());
+        assert!(best_yaku(&hand, &ctx, &YakuRules::default()).is_some());
+    }
+}
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+
+use crate::babyjubjub::PublicKey;
+use crate::bn128::Fr;
+use crate::elgamal::MaskedMessage;
+use crate::tile::{replay_shuffle, PermutationMatrix, ShuffleEncryptResult};
+
+use super::session::{ShuffleEvidence, ShuffleSession};
+
+/// The secrets a seat reveals after the hand for its shuffle step.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShuffleSecrets {
+    pub randomness: Vec<Fr>,
+    pub permutation: PermutationMatrix,
+}
+
+impl From<&ShuffleEncryptResult> for ShuffleSecrets {
+    fn from(result: &ShuffleEncryptResult) -> Self {
+        Self {
+            randomness: result.randomness.clone(),
+            permutation: result.permutation.clone(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FaultReason {
+    /// No secrets were revealed for this step.
+    Missing,
+    /// The randomness or permutation has the wrong shape.
+    Malformed,
+    /// The secrets do not open the commitment published with the deck.
+    CommitmentMismatch,
+    /// Replaying the secrets does not give the published deck.
+    DeckMismatch,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShuffleFault {
+    pub seat: usize,
+    pub reason: FaultReason,
+}
+
+/// Replay every shuffle step from its revealed secrets.
+///
+/// `decks[k]` is the deck published by seat `k` and `secrets[k]` what it
+/// revealed. Each step is replayed from the previous *published* deck, so a
+/// faulty step is blamed on its own seat only. Returns every faulty seat,
+/// empty if the whole chain checks out.
+pub fn audit(
+    agg_pk: &PublicKey,
+    initial: &[MaskedMessage],
+    decks: &[Vec<MaskedMessage>],
+    secrets: &[ShuffleSecrets],
+) -> Vec<ShuffleFault> {
+    let mut faults = vec![];
+    let mut previous = initial;
+    for (seat, deck) in decks.iter().enumerate() {
+        if let Some(reason) = check_step(agg_pk, previous, deck, secrets.get(seat)) {
+            warn!("shuffle audit: seat {} {:?}", seat, reason);
+            faults.push(ShuffleFault { seat, reason });
+        }
+        previous = deck;
+    }
+    faults
+}
+
+fn check_step(
+    agg_pk: &PublicKey,
+    input: &[MaskedMessage],
+    output: &[MaskedMessage],
+    secrets: Option<&ShuffleSecrets>,
+) -> Option<FaultReason> {
+    let Some(secrets) = secrets else {
+        return Some(FaultReason::Missing);
+    };
+    let n = input.len();
+    if secrets.randomness.len() != n || output.len() != n || !secrets.permutation.is_valid(n) {
+        return Some(FaultReason::Malformed);
+    }
+    (replay_shuffle(agg_pk, input, &secrets.randomness, &secrets.permutation) != output)
+        .then_some(FaultReason::DeckMismatch)
+}
+
+impl ShuffleSession {
+    /// Audit every contribution, also checking that committed steps open
+    /// their commitment. `secrets` is indexed by seat.
+    pub fn audit(&self, secrets: &[ShuffleSecrets]) -> Vec<ShuffleFault> {
+        let decks: Vec<Vec<MaskedMessage>> =
+            self.contributions().iter().map(|c| c.deck.clone()).collect();
+        let mut faults = audit(self.agg_pk(), self.initial_deck(), &decks, secrets);
+        for contribution in self.contributions() {
+            let seat = contribution.seat;
+            let ShuffleEvidence::Commitment(commitment) = &contribution.evidence else {
+                continue;
+            };
+            let Some(secrets) = secrets.get(seat) else {
+                continue;
+            };
+            if faults.iter().all(|f| f.seat != seat)
+                && !commitment.opens(&secrets.randomness, &secrets.permutation)
+            {
+                faults.push(ShuffleFault { seat, reason: FaultReason::CommitmentMismatch });
+            }
+        }
+        faults.sort_by_key(|f| f.seat);
+        faults
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::babyjubjub::SecretKey;
+    use crate::shuffle::session::ShuffleCommitment;
+    use crate::tile::{get_richi_tiles, shuffle_encrypt_deck};
+    use ff::Field;
+
+    #[test]
+    fn test_audit() {
+        let agg_pk = PublicKey::aggregate((0..4).map(|_| SecretKey::random().public_key()).collect());
+        let initial = get_richi_tiles();
+        let mut decks: Vec<Vec<MaskedMessage>> = vec![];
+        let mut secrets = vec![];
+        for _ in 0..4 {
+            let result = shuffle_encrypt_deck(&agg_pk, decks.last().unwrap_or(&initial));
+            secrets.push(ShuffleSecrets::from(&result));
+            decks.push(result.tiles);
+        }
+        assert!(audit(&agg_pk, &initial, &decks, &secrets).is_empty());
+
+        // seat 1 published a deck it cannot explain, seat 2 reveals garbage
+        // and seat 3 reveals nothing
+        decks[1].swap(3, 7);
+        secrets[2].randomness.clear();
+        secrets.truncate(3);
+        let faults = audit(&agg_pk, &initial, &decks, &secrets);
+        assert_eq!(
+            faults,
+            vec![
+                ShuffleFault { seat: 1, reason: FaultReason::DeckMismatch },
+                ShuffleFault { seat: 2, reason: FaultReason::Malformed },
+                ShuffleFault { seat: 3, reason: FaultReason::Missing },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_session_commitment() {
+        let agg_pk = PublicKey::aggregate((0..4).map(|_| SecretKey::random().public_key()).collect());
+        let mut session = ShuffleSession::new(agg_pk, get_richi_tiles(), 2);
+        let mut secrets = vec![];
+        for seat in 0..2 {
+            let result = shuffle_encrypt_deck(&agg_pk, session.current_deck());
+            // seat 1 commits to something other than what it shuffled with
+            let commitment = match seat {
+                0 => ShuffleCommitment::from_result(&result),
+                _ => ShuffleCommitment(Fr::ONE),
+            };
+            session
+                .contribute(seat, result.tiles.clone(), ShuffleEvidence::Commitment(commitment))
+                .unwrap();
+            secrets.push(ShuffleSecrets::from(&result));
+        }
+        assert_eq!(
+            session.audit(&secrets),
+            vec![ShuffleFault { seat: 1, reason: FaultReason::CommitmentMismatch }]
+        );
+    }
+}
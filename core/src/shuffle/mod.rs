@@ -0,0 +1,8 @@
+pub mod audit;
+pub mod proof;
+pub mod session;
+mod transcript;
+
+pub use audit::{audit, FaultReason, ShuffleFault, ShuffleSecrets};
+pub use proof::ShuffleProof;
+pub use session::{ShuffleError, ShuffleEvidence, ShuffleSession};
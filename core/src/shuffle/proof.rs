@@ -0,0 +1,374 @@
+//! Bayer-Groth shuffle argument for ElGamal ciphertexts over BabyJubJub.
+//!
+//! This is the `m = 1` instance of Bayer and Groth, "Efficient Zero-Knowledge
+//! Argument for Correctness of a Shuffle" (EUROCRYPT 2012): the prover
+//! commits to the permutation `a`, then to `b = x^a` for a challenge `x`, and
+//! shows with a product argument that `y * a + b - z` is a permutation of
+//! `y * i + x^i - z`, and with a multi-exponentiation argument that the
+//! output deck raised to `b` equals the input deck raised to `x^i` up to a
+//! re-encryption. Challenges are derived with a Poseidon transcript.
+//!
+//! The verifier rejects an output deck or proof holding a point off the
+//! curve or outside the prime order subgroup, so a small-order component
+//! cannot be slipped past the challenges. The input deck is the previous
+//! output, already checked when its own proof was verified.
+
+use ff::Field;
+use serde::{Deserialize, Serialize};
+
+use crate::babyjubjub::{Fs, Point, PointProjective, PublicKey, BASE_POINT};
+use crate::elgamal::MaskedMessage;
+use crate::pedersen::{msm, PedersenParams};
+use crate::tile::ShuffleEncryptResult;
+
+use super::transcript::Transcript;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShuffleProof {
+    /// Commitment to the permutation.
+    pub c_a: Point,
+    /// Commitment to the permuted challenge powers.
+    pub c_b: Point,
+    pub product: ProductArgument,
+    pub multi_exp: MultiExpArgument,
+}
+
+/// Argument that the committed vector has a given product.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductArgument {
+    pub c_d: Point,
+    pub c_lower_delta: Point,
+    pub c_upper_delta: Point,
+    pub a_tilde: Vec<Fs>,
+    pub b_tilde: Vec<Fs>,
+    pub r_tilde: Fs,
+    pub s_tilde: Fs,
+}
+
+/// Argument that the output deck raised to the committed exponents equals
+/// a known ciphertext up to a re-encryption.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiExpArgument {
+    pub c_b0: Point,
+    pub e0: MaskedMessage,
+    pub b: Vec<Fs>,
+    pub s: Fs,
+    pub tau: Fs,
+}
+
+impl ShuffleProof {
+    /// Prove that `result.tiles` is a re-encrypted permutation of `input`.
+    pub fn prove(
+        agg_pk: &PublicKey,
+        input: &[MaskedMessage],
+        result: &ShuffleEncryptResult,
+    ) -> ShuffleProof {
+        let n = input.len();
+        assert!(n >= 2);
+        assert_eq!(result.tiles.len(), n);
+        assert_eq!(result.randomness.len(), n);
+        let mut rng = rand::thread_rng();
+        let params = PedersenParams::new(n);
+
+        // output[i] = input[pi[i]] re-encrypted with randomness[pi[i]]
+        let pi = inverse(&result.permutation.to_permutation());
+        let rerandomness: Vec<Fs> = pi.iter().map(|&j| Fs::from_fr(&result.randomness[j])).collect();
+
+        let mut transcript = statement(agg_pk, input, &result.tiles);
+
+        let a: Vec<Fs> = pi.iter().map(|&j| Fs::from(j as u64)).collect();
+        let r_a = Fs::random(&mut rng);
+        let c_a = params.commit(&a, &r_a);
+        transcript.append_point(&c_a);
+        let x = transcript.challenge();
+
+        let powers = powers(&x, n);
+        let b: Vec<Fs> = pi.iter().map(|&j| powers[j]).collect();
+        let r_b = Fs::random(&mut rng);
+        let c_b = params.commit(&b, &r_b);
+        transcript.append_point(&c_b);
+        let y = transcript.challenge();
+        let z = transcript.challenge();
+
+        let d: Vec<Fs> = a.iter().zip(&b).map(|(a, b)| y * a + b - z).collect();
+        let r_d = y * r_a + r_b;
+        let product = ProductArgument::prove(&params, &mut transcript, &d, &r_d);
+
+        let rho = b.iter().zip(&rerandomness).fold(Fs::ZERO, |acc, (b, r)| acc + *b * r);
+        let multi_exp = MultiExpArgument::prove(
+            &params, &mut transcript, agg_pk, &result.tiles, &b, &r_b, &rho,
+        );
+
+        ShuffleProof { c_a, c_b, product, multi_exp }
+    }
+
+    /// Check that `output` is a re-encrypted permutation of `input`.
+    pub fn verify(
+        &self,
+        agg_pk: &PublicKey,
+        input: &[MaskedMessage],
+        output: &[MaskedMessage],
+    ) -> bool {
+        let n = input.len();
+        if n < 2 || output.len() != n {
+            return false;
+        }
+        if !self.points_in_subgroup() || !output.iter().all(|m| m.c0.in_subgroup() && m.c1.in_subgroup()) {
+            return false;
+        }
+        let params = PedersenParams::new(n);
+
+        let mut transcript = statement(agg_pk, input, output);
+        transcript.append_point(&self.c_a);
+        let x = transcript.challenge();
+        transcript.append_point(&self.c_b);
+        let y = transcript.challenge();
+        let z = transcript.challenge();
+
+        let powers = powers(&x, n);
+        let zs = vec![z; n];
+        let c_d = (self.c_a.mul_scalar(&y.to_fr()) + self.c_b + (-params.commit(&zs, &Fs::ZERO))).affine();
+        let target = powers
+            .iter()
+            .enumerate()
+            .fold(Fs::ONE, |acc, (i, p)| acc * (y * Fs::from(i as u64) + p - z));
+        if !self.product.verify(&params, &mut transcript, &c_d, &target) {
+            return false;
+        }
+
+        let c = combine(input, &powers);
+        self.multi_exp.verify(&params, &mut transcript, agg_pk, output, &self.c_b, &c)
+    }
+
+    fn points_in_subgroup(&self) -> bool {
+        let product = &self.product;
+        let e0 = &self.multi_exp.e0;
+        [
+            self.c_a,
+            self.c_b,
+            product.c_d,
+            product.c_lower_delta,
+            product.c_upper_delta,
+            self.multi_exp.c_b0,
+            e0.c0,
+            e0.c1,
+        ]
+        .iter()
+        .all(Point::in_subgroup)
+    }
+}
+
+impl ProductArgument {
+    fn prove(params: &PedersenParams, transcript: &mut Transcript, a: &[Fs], r: &Fs) -> Self {
+        let n = a.len();
+        let mut rng = rand::thread_rng();
+
+        let mut b = Vec::with_capacity(n);
+        b.push(a[0]);
+        for k in 1..n {
+            b.push(b[k - 1] * a[k]);
+        }
+
+        let d: Vec<Fs> = (0..n).map(|_| Fs::random(&mut rng)).collect();
+        let r_d = Fs::random(&mut rng);
+        let mut delta = vec![d[0]];
+        delta.extend((1..n - 1).map(|_| Fs::random(&mut rng)));
+        delta.push(Fs::ZERO);
+        let (s_1, s_x) = (Fs::random(&mut rng), Fs::random(&mut rng));
+
+        let lower: Vec<Fs> = (0..n - 1).map(|k| -delta[k] * d[k + 1]).collect();
+        let upper: Vec<Fs> = (0..n - 1)
+            .map(|k| delta[k + 1] - a[k + 1] * delta[k] - b[k] * d[k + 1])
+            .collect();
+        let c_d = params.commit(&d, &r_d);
+        let c_lower_delta = params.commit(&lower, &s_1);
+        let c_upper_delta = params.commit(&upper, &s_x);
+        transcript.append_point(&c_d);
+        transcript.append_point(&c_lower_delta);
+        transcript.append_point(&c_upper_delta);
+        let x = transcript.challenge();
+
+        ProductArgument {
+            c_d,
+            c_lower_delta,
+            c_upper_delta,
+            a_tilde: a.iter().zip(&d).map(|(a, d)| x * a + d).collect(),
+            b_tilde: b.iter().zip(&delta).map(|(b, delta)| x * b + delta).collect(),
+            r_tilde: x * r + r_d,
+            s_tilde: x * s_x + s_1,
+        }
+    }
+
+    fn verify(&self, params: &PedersenParams, transcript: &mut Transcript, c_a: &Point, product: &Fs) -> bool {
+        let n = params.len();
+        if self.a_tilde.len() != n || self.b_tilde.len() != n {
+            return false;
+        }
+        transcript.append_point(&self.c_d);
+        transcript.append_point(&self.c_lower_delta);
+        transcript.append_point(&self.c_upper_delta);
+        let x = transcript.challenge();
+        let xr = x.to_fr();
+
+        let (a, b) = (&self.a_tilde, &self.b_tilde);
+        let chain: Vec<Fs> = (0..n - 1).map(|k| x * b[k + 1] - b[k] * a[k + 1]).collect();
+        b[0] == a[0]
+            && b[n - 1] == x * product
+            && (c_a.mul_scalar(&xr) + self.c_d).affine() == params.commit(a, &self.r_tilde)
+            && (self.c_upper_delta.mul_scalar(&xr) + self.c_lower_delta).affine()
+                == params.commit(&chain, &self.s_tilde)
+    }
+}
+
+impl MultiExpArgument {
+    fn prove(
+        params: &PedersenParams,
+        transcript: &mut Transcript,
+        agg_pk: &PublicKey,
+        output: &[MaskedMessage],
+        b: &[Fs],
+        r_b: &Fs,
+        rho: &Fs,
+    ) -> Self {
+        let mut rng = rand::thread_rng();
+        let b0: Vec<Fs> = (0..b.len()).map(|_| Fs::random(&mut rng)).collect();
+        let (s0, tau0) = (Fs::random(&mut rng), Fs::random(&mut rng));
+
+        let c_b0 = params.commit(&b0, &s0);
+        let e0 = reencrypt(&combine(output, &b0), agg_pk, &-tau0);
+        transcript.append_point(&c_b0);
+        transcript.append_messages(&[e0]);
+        let e = transcript.challenge();
+
+        MultiExpArgument {
+            c_b0,
+            e0,
+            b: b0.iter().zip(b).map(|(b0, b)| *b0 + e * b).collect(),
+            s: s0 + e * r_b,
+            tau: tau0 + e * rho,
+        }
+    }
+
+    fn verify(
+        &self,
+        params: &PedersenParams,
+        transcript: &mut Transcript,
+        agg_pk: &PublicKey,
+        output: &[MaskedMessage],
+        c_b: &Point,
+        c: &MaskedMessage,
+    ) -> bool {
+        if self.b.len() != output.len() {
+            return false;
+        }
+        transcript.append_point(&self.c_b0);
+        transcript.append_messages(&[self.e0]);
+        let e = transcript.challenge();
+        let er = e.to_fr();
+
+        let lhs = reencrypt(&combine(output, &self.b), agg_pk, &-self.tau);
+        let rhs = MaskedMessage {
+            c0: (self.e0.c0 + c.c0.mul_scalar(&er)).affine(),
+            c1: (self.e0.c1 + c.c1.mul_scalar(&er)).affine(),
+        };
+        (c_b.mul_scalar(&er) + self.c_b0).affine() == params.commit(&self.b, &self.s) && lhs == rhs
+    }
+}
+
+fn statement(agg_pk: &PublicKey, input: &[MaskedMessage], output: &[MaskedMessage]) -> Transcript {
+    let mut transcript = Transcript::new("zk-mahjong shuffle");
+    transcript.append_point(agg_pk);
+    transcript.append_messages(input);
+    transcript.append_messages(output);
+    transcript
+}
+
+fn inverse(permutation: &[usize]) -> Vec<usize> {
+    let mut inverse = vec![0; permutation.len()];
+    for (i, &j) in permutation.iter().enumerate() {
+        inverse[j] = i;
+    }
+    inverse
+}
+
+fn powers(x: &Fs, n: usize) -> Vec<Fs> {
+    std::iter::successors(Some(Fs::ONE), |p| Some(*p * x)).take(n).collect()
+}
+
+/// Component-wise `sum(e[i] * m[i])`.
+fn combine(messages: &[MaskedMessage], exponents: &[Fs]) -> MaskedMessage {
+    let c0: Vec<Point> = messages.iter().map(|m| m.c0).collect();
+    let c1: Vec<Point> = messages.iter().map(|m| m.c1).collect();
+    MaskedMessage {
+        c0: msm(&c0, exponents).affine(),
+        c1: msm(&c1, exponents).affine(),
+    }
+}
+
+fn reencrypt(m: &MaskedMessage, agg_pk: &PublicKey, r: &Fs) -> MaskedMessage {
+    let r = r.to_fr();
+    let c0: PointProjective = m.c0 + BASE_POINT.mul_scalar(&r);
+    let c1: PointProjective = m.c1 + agg_pk.mul_scalar(&r);
+    MaskedMessage { c0: c0.affine(), c1: c1.affine() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::babyjubjub::SecretKey;
+    use crate::tile::{get_richi_tiles, shuffle_encrypt_deck};
+
+    fn random_agg_pk() -> PublicKey {
+        PublicKey::aggregate((0..4).map(|_| SecretKey::random().public_key()).collect())
+    }
+
+    #[test]
+    fn test_prove_verify() {
+        let agg_pk = random_agg_pk();
+        let deck = get_richi_tiles();
+        let result = shuffle_encrypt_deck(&agg_pk, &deck);
+        let proof = ShuffleProof::prove(&agg_pk, &deck, &result);
+        assert!(proof.verify(&agg_pk, &deck, &result.tiles));
+
+        // chained shuffles verify against the previous output
+        let next = shuffle_encrypt_deck(&agg_pk, &result.tiles);
+        let next_proof = ShuffleProof::prove(&agg_pk, &result.tiles, &next);
+        assert!(next_proof.verify(&agg_pk, &result.tiles, &next.tiles));
+        assert!(!next_proof.verify(&agg_pk, &deck, &next.tiles));
+    }
+
+    #[test]
+    fn test_reject_tampered_deck() {
+        let agg_pk = random_agg_pk();
+        let deck = get_richi_tiles();
+        let result = shuffle_encrypt_deck(&agg_pk, &deck);
+        let proof = ShuffleProof::prove(&agg_pk, &deck, &result);
+
+        // replacing a tile with a copy of another one is not a permutation
+        let mut forged = result.tiles.clone();
+        forged[0] = forged[1].remask(&agg_pk, &crate::bn128::Fr::ONE);
+        assert!(!proof.verify(&agg_pk, &deck, &forged));
+
+        let mut swapped = result.tiles.clone();
+        swapped.swap(0, 1);
+        assert!(!proof.verify(&agg_pk, &deck, &swapped));
+        assert!(!proof.verify(&random_agg_pk(), &deck, &result.tiles));
+    }
+
+    #[test]
+    fn test_reject_small_order_points() {
+        let agg_pk = random_agg_pk();
+        let deck = get_richi_tiles();
+        let result = shuffle_encrypt_deck(&agg_pk, &deck);
+        let proof = ShuffleProof::prove(&agg_pk, &deck, &result);
+        let two_torsion = Point::new(crate::bn128::Fr::ZERO, -crate::bn128::Fr::ONE);
+
+        let mut forged = result.tiles.clone();
+        forged[0].c1 = (forged[0].c1 + two_torsion).affine();
+        assert!(!proof.verify(&agg_pk, &deck, &forged));
+
+        let mut forged = proof.clone();
+        forged.multi_exp.e0.c0 = (forged.multi_exp.e0.c0 + two_torsion).affine();
+        assert!(!forged.verify(&agg_pk, &deck, &result.tiles));
+    }
+}
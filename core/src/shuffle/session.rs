@@ -0,0 +1,244 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::babyjubjub::PublicKey;
+use crate::bn128::Fr;
+use crate::elgamal::MaskedMessage;
+use crate::tile::{get_richi_tiles, get_sanma_tiles, PermutationMatrix, ShuffleEncryptResult};
+
+use super::transcript::Transcript;
+use super::ShuffleProof;
+
+/// Binding commitment to the secrets of one shuffle step, opened after the
+/// hand for an audit instead of proving the step up front.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShuffleCommitment(pub Fr);
+
+impl ShuffleCommitment {
+    pub fn new(randomness: &[Fr], permutation: &PermutationMatrix) -> Self {
+        let mut transcript = Transcript::new("zk-mahjong shuffle commitment");
+        for r in randomness {
+            transcript.append_fr(r);
+        }
+        for i in permutation.to_permutation() {
+            transcript.append_fr(&Fr::from(i as u64));
+        }
+        Self(transcript.digest())
+    }
+
+    pub fn from_result(result: &ShuffleEncryptResult) -> Self {
+        Self::new(&result.randomness, &result.permutation)
+    }
+
+    pub fn opens(&self, randomness: &[Fr], permutation: &PermutationMatrix) -> bool {
+        *self == Self::new(randomness, permutation)
+    }
+}
+
+/// What a seat publishes alongside its shuffled deck.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShuffleEvidence {
+    /// Checked immediately against the previous deck.
+    Proof(Box<ShuffleProof>),
+    /// Checked once the secrets are revealed, see [`ShuffleSession::audit`].
+    Commitment(ShuffleCommitment),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Contribution {
+    pub seat: usize,
+    pub deck: Vec<MaskedMessage>,
+    pub evidence: ShuffleEvidence,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShuffleError {
+    /// Every seat has already shuffled.
+    Complete,
+    OutOfTurn { expected: usize, seat: usize },
+    DeckSize { expected: usize, actual: usize },
+    InvalidProof { seat: usize },
+}
+
+impl Display for ShuffleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShuffleError::Complete => write!(f, "every seat has already shuffled"),
+            ShuffleError::OutOfTurn { expected, seat } => {
+                write!(f, "seat {seat} shuffled out of turn, expected seat {expected}")
+            }
+            ShuffleError::DeckSize { expected, actual } => {
+                write!(f, "expected a deck of {expected} tiles, got {actual}")
+            }
+            ShuffleError::InvalidProof { seat } => write!(f, "invalid shuffle proof from seat {seat}"),
+        }
+    }
+}
+
+impl std::error::Error for ShuffleError {}
+
+/// Chains the shuffle of every seat, in seat order, starting from a
+/// plaintext deck.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ShuffleSession {
+    agg_pk: PublicKey,
+    players: usize,
+    initial: Vec<MaskedMessage>,
+    contributions: Vec<Contribution>,
+}
+
+impl ShuffleSession {
+    pub fn new(agg_pk: PublicKey, initial: Vec<MaskedMessage>, players: usize) -> Self {
+        Self { agg_pk, players, initial, contributions: vec![] }
+    }
+
+    /// Four player session over the 136 tile riichi deck.
+    pub fn riichi(agg_pk: PublicKey) -> Self {
+        Self::new(agg_pk, get_richi_tiles(), 4)
+    }
+
+    /// Three player session over the 108 tile sanma deck.
+    pub fn sanma(agg_pk: PublicKey) -> Self {
+        Self::new(agg_pk, get_sanma_tiles(), 3)
+    }
+
+    pub fn agg_pk(&self) -> &PublicKey {
+        &self.agg_pk
+    }
+
+    pub fn players(&self) -> usize {
+        self.players
+    }
+
+    pub fn initial_deck(&self) -> &[MaskedMessage] {
+        &self.initial
+    }
+
+    pub fn contributions(&self) -> &[Contribution] {
+        &self.contributions
+    }
+
+    /// The seat expected to shuffle next, `None` once every seat has.
+    pub fn next_seat(&self) -> Option<usize> {
+        let seat = self.contributions.len();
+        (seat < self.players).then_some(seat)
+    }
+
+    /// The deck the next seat has to shuffle.
+    pub fn current_deck(&self) -> &[MaskedMessage] {
+        self.contributions.last().map_or(&self.initial, |c| &c.deck)
+    }
+
+    /// Accept the shuffled deck of `seat`, checking its proof against the
+    /// current deck if it came with one.
+    pub fn contribute(
+        &mut self,
+        seat: usize,
+        deck: Vec<MaskedMessage>,
+        evidence: ShuffleEvidence,
+    ) -> Result<(), ShuffleError> {
+        let expected = self.next_seat().ok_or(ShuffleError::Complete)?;
+        if seat != expected {
+            return Err(ShuffleError::OutOfTurn { expected, seat });
+        }
+        let previous = self.current_deck();
+        if deck.len() != previous.len() {
+            return Err(ShuffleError::DeckSize { expected: previous.len(), actual: deck.len() });
+        }
+        if let ShuffleEvidence::Proof(proof) = &evidence {
+            if !proof.verify(&self.agg_pk, previous, &deck) {
+                return Err(ShuffleError::InvalidProof { seat });
+            }
+        }
+        debug!("seat {} shuffled the deck", seat);
+        self.contributions.push(Contribution { seat, deck, evidence });
+        Ok(())
+    }
+
+    /// Shuffle the current deck locally and contribute it with a proof.
+    pub fn shuffle(&mut self, seat: usize) -> Result<ShuffleEncryptResult, ShuffleError> {
+        let expected = self.next_seat().ok_or(ShuffleError::Complete)?;
+        if seat != expected {
+            return Err(ShuffleError::OutOfTurn { expected, seat });
+        }
+        let input = self.current_deck();
+        let result = crate::tile::shuffle_encrypt_deck(&self.agg_pk, input);
+        let proof = ShuffleProof::prove(&self.agg_pk, input, &result);
+        self.contribute(seat, result.tiles.clone(), ShuffleEvidence::Proof(Box::new(proof)))?;
+        Ok(result)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_seat().is_none()
+    }
+
+    /// The final wall, available once every seat has shuffled.
+    pub fn final_deck(&self) -> Option<&[MaskedMessage]> {
+        self.is_complete().then(|| self.current_deck())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::babyjubjub::SecretKey;
+    use crate::tile::shuffle_encrypt_deck;
+
+    #[test]
+    fn test_session() {
+        let agg_pk = PublicKey::aggregate((0..4).map(|_| SecretKey::random().public_key()).collect());
+        let mut session = ShuffleSession::riichi(agg_pk);
+
+        let result = session.shuffle(0).unwrap();
+        assert_eq!(session.current_deck(), &result.tiles[..]);
+        assert_eq!(
+            session.shuffle(2).unwrap_err(),
+            ShuffleError::OutOfTurn { expected: 1, seat: 2 }
+        );
+
+        // a proof for a different input deck is rejected
+        let stale = shuffle_encrypt_deck(&agg_pk, &get_richi_tiles());
+        let proof = ShuffleProof::prove(&agg_pk, &get_richi_tiles(), &stale);
+        assert_eq!(
+            session.contribute(1, stale.tiles, ShuffleEvidence::Proof(Box::new(proof))),
+            Err(ShuffleError::InvalidProof { seat: 1 })
+        );
+
+        // committed shuffles are accepted and audited later
+        for seat in 1..4 {
+            assert!(session.final_deck().is_none());
+            let result = shuffle_encrypt_deck(&agg_pk, session.current_deck());
+            let commitment = ShuffleCommitment::from_result(&result);
+            session
+                .contribute(seat, result.tiles, ShuffleEvidence::Commitment(commitment))
+                .unwrap();
+        }
+        assert_eq!(session.final_deck().unwrap().len(), 136);
+        assert_eq!(session.shuffle(0).unwrap_err(), ShuffleError::Complete);
+    }
+
+    #[test]
+    fn test_sanma_session() {
+        let sks: [SecretKey; 3] = std::array::from_fn(|_| SecretKey::random());
+        let agg_pk = PublicKey::aggregate(sks.iter().map(|sk| sk.public_key()).collect());
+        let mut session = ShuffleSession::sanma(agg_pk);
+        for seat in 0..3 {
+            let result = shuffle_encrypt_deck(&agg_pk, session.current_deck());
+            let commitment = ShuffleCommitment::from_result(&result);
+            session
+                .contribute(seat, result.tiles, ShuffleEvidence::Commitment(commitment))
+                .unwrap();
+        }
+        let mut faces: Vec<usize> = session
+            .final_deck()
+            .unwrap()
+            .iter()
+            .map(|t| crate::tile::lookup_tile(&sks.iter().fold(*t, |acc, sk| acc.unmask(sk)).c1.x).unwrap().idx)
+            .collect();
+        faces.sort();
+        let mut expected: Vec<usize> = crate::tile::DeckSpec::sanma().tiles().iter().map(|t| t.idx).collect();
+        expected.sort();
+        assert_eq!(faces, expected);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::babyjubjub::{Fs, Point};
+use crate::bn128::Fr;
+use ff::Field;
+use crate::elgamal::MaskedMessage;
+use crate::poseidon::poseidon_hash;
+
+/// Fiat-Shamir transcript over Poseidon.
+///
+/// Absorbed elements are buffered and folded into the state 15 at a time,
+/// the widest Poseidon instance being 16 inputs including the state.
+pub(crate) struct Transcript {
+    state: Fr,
+    buf: Vec<Fr>,
+}
+
+impl Transcript {
+    pub fn new(label: &str) -> Self {
+        let mut transcript = Self { state: Fr::ZERO, buf: vec![] };
+        transcript.buf.extend(label.bytes().map(|b| Fr::from(b as u64)));
+        transcript
+    }
+
+    pub fn append_fr(&mut self, x: &Fr) {
+        self.buf.push(*x);
+    }
+
+    pub fn append_point(&mut self, p: &Point) {
+        self.buf.push(p.x);
+        self.buf.push(p.y);
+    }
+
+    pub fn append_messages(&mut self, messages: &[MaskedMessage]) {
+        for m in messages {
+            self.append_point(&m.c0);
+            self.append_point(&m.c1);
+        }
+    }
+
+    /// Hash everything absorbed so far.
+    pub fn digest(&mut self) -> Fr {
+        let mut input = Vec::with_capacity(16);
+        for chunk in self.buf.chunks(15) {
+            input.clear();
+            input.push(self.state);
+            input.extend_from_slice(chunk);
+            self.state = poseidon_hash(&input);
+        }
+        self.buf.clear();
+        let digest = self.state;
+        self.state = poseidon_hash(&[self.state]);
+        digest
+    }
+
+    pub fn challenge(&mut self) -> Fs {
+        Fs::from_fr(&self.digest())
+    }
+}
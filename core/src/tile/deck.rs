@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::babyjubjub::BASE_POINT;
+use crate::bn128::Fr;
+use crate::elgamal::MaskedMessage;
+
+use super::map::TILES;
+use super::{lookup_tile, BaseTile, Tile, TileKind};
+
+/// Index of the first joker, right after the flowers and seasons.
+const JOKER_IDX: usize = 144;
+
+/// How many copies of each five are red.
+///
+/// Every copy already has its own point, so a red five stays distinct after
+/// decryption; this only decides which copies count as red.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AkaDora {
+    pub man: u8,
+    pub pin: u8,
+    pub sou: u8,
+}
+
+impl AkaDora {
+    pub const NONE: AkaDora = AkaDora { man: 0, pin: 0, sou: 0 };
+    /// One red five per suit, as marked in [`super::map::TILES`].
+    pub const STANDARD: AkaDora = AkaDora { man: 1, pin: 1, sou: 1 };
+
+    pub fn count(&self, kind: TileKind) -> u8 {
+        match kind {
+            TileKind::Man => self.man,
+            TileKind::Pin => self.pin,
+            TileKind::Sou => self.sou,
+            _ => 0,
+        }
+    }
+
+    pub fn count_mut(&mut self, kind: TileKind) -> Option<&mut u8> {
+        match kind {
+            TileKind::Man => Some(&mut self.man),
+            TileKind::Pin => Some(&mut self.pin),
+            TileKind::Sou => Some(&mut self.sou),
+            _ => None,
+        }
+    }
+
+    pub fn total(&self) -> u8 {
+        self.man + self.pin + self.sou
+    }
+
+    /// Red copies are the lowest ones, so `count` of 1 marks copy 0.
+    pub fn is_red(&self, tile: &BaseTile) -> bool {
+        tile.ord == 5 && (tile.copy() as u8) < self.count(tile.kind)
+    }
+
+    /// `tile` with its `red` flag set for this config.
+    pub fn mark(&self, mut tile: BaseTile) -> BaseTile {
+        tile.red = self.is_red(&tile);
+        tile
+    }
+
+    /// [`lookup_tile`] with red fives marked for this config.
+    pub fn lookup_tile(&self, x: &Fr) -> Option<BaseTile> {
+        lookup_tile(x).map(|tile| self.mark(tile))
+    }
+}
+
+impl Default for AkaDora {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+impl BaseTile {
+    /// Which of the identical copies of this face the tile is.
+    pub fn copy(&self) -> usize {
+        match self.kind {
+            TileKind::Fa => 0,
+            TileKind::Joker => self.idx - JOKER_IDX,
+            _ => self.idx % 4,
+        }
+    }
+}
+
+/// Which tiles make up a deck.
+///
+/// A tile keeps the `idx` and point it has in [`TILES`] whatever deck it is
+/// in, jokers are numbered on from there. Only the order of the plaintext
+/// deck and the decode table depend on the spec.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeckSpec {
+    /// Faces in the deck, without flowers, seasons or jokers.
+    pub faces: Vec<Tile>,
+    /// Copies of each face, at most 4.
+    pub copies: u8,
+    pub aka: AkaDora,
+    /// One of each flower and season.
+    pub flowers: bool,
+    pub jokers: u8,
+}
+
+impl DeckSpec {
+    /// 136 tiles with one red five per suit.
+    pub fn riichi() -> Self {
+        Self {
+            faces: (0..34).filter_map(Tile::from_index).collect(),
+            copies: 4,
+            aka: AkaDora::STANDARD,
+            flowers: false,
+            jokers: 0,
+        }
+    }
+
+    /// The riichi deck plus flowers and seasons, all of [`TILES`].
+    pub fn full() -> Self {
+        Self { flowers: true, ..Self::riichi() }
+    }
+
+    /// 108 tiles for three players, without 2m to 8m.
+    pub fn sanma() -> Self {
+        Self {
+            faces: (0..34)
+                .filter_map(Tile::from_index)
+                .filter(|t| !matches!(t, Tile::Man(2..=8)))
+                .collect(),
+            aka: AkaDora { man: 0, pin: 1, sou: 1 },
+            ..Self::riichi()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.faces.len() * self.copies as usize
+            + if self.flowers { 8 } else { 0 }
+            + self.jokers as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Every tile, in plaintext deck order.
+    ///
+    /// Panics if a face is a bonus tile or joker, or `copies` is above 4.
+    pub fn tiles(&self) -> Vec<BaseTile> {
+        assert!(self.copies <= 4, "at most 4 copies of a face");
+        let mut tiles = Vec::with_capacity(self.len());
+        for face in &self.faces {
+            let index = face.index().expect("flowers and jokers have their own fields");
+            for copy in 0..self.copies as usize {
+                tiles.push(self.aka.mark(TILES[index * 4 + copy]));
+            }
+        }
+        if self.flowers {
+            tiles.extend_from_slice(&TILES[136..144]);
+        }
+        for idx in JOKER_IDX..JOKER_IDX + self.jokers as usize {
+            tiles.push(BaseTile {
+                idx,
+                point: BASE_POINT.mul_scalar(&Fr::from(idx as u64 + 1)),
+                kind: TileKind::Joker,
+                ord: 1,
+                red: false,
+            });
+        }
+        tiles
+    }
+
+    /// The unshuffled deck, every tile masked with zero randomness.
+    pub fn plaintext(&self) -> Vec<MaskedMessage> {
+        self.tiles().iter().map(|t| MaskedMessage::new(t.point)).collect()
+    }
+
+    pub fn build(&self) -> Deck {
+        Deck::new(self.tiles())
+    }
+}
+
+/// The tiles of a [`DeckSpec`] with a table to decode them.
+#[derive(Clone, Debug)]
+pub struct Deck {
+    tiles: Vec<BaseTile>,
+    table: HashMap<Fr, usize>,
+}
+
+impl Deck {
+    fn new(tiles: Vec<BaseTile>) -> Self {
+        let table = tiles.iter().enumerate().map(|(i, t)| (t.point.x, i)).collect();
+        Self { tiles, table }
+    }
+
+    pub fn tiles(&self) -> &[BaseTile] {
+        &self.tiles
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn plaintext(&self) -> Vec<MaskedMessage> {
+        self.tiles.iter().map(|t| MaskedMessage::new(t.point)).collect()
+    }
+
+    /// Decode an unmasked point, `None` if it is not in this deck.
+    pub fn lookup(&self, x: &Fr) -> Option<BaseTile> {
+        self.position(x).map(|i| self.tiles[i])
+    }
+
+    /// Position of a tile in the plaintext deck.
+    pub fn position(&self, x: &Fr) -> Option<usize> {
+        self.table.get(x).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aka_dora() {
+        let red = |aka: AkaDora| TILES.iter().filter(|t| aka.is_red(t)).map(|t| t.idx).collect::<Vec<_>>();
+        assert_eq!(red(AkaDora::STANDARD), [16, 52, 88]);
+        assert_eq!(red(AkaDora::NONE), Vec::<usize>::new());
+        assert_eq!(red(AkaDora { man: 0, pin: 2, sou: 1 }), [52, 53, 88]);
+        for tile in TILES.iter() {
+            assert_eq!(tile.red, AkaDora::STANDARD.is_red(tile));
+        }
+
+        let five = TILES[53];
+        assert!(!lookup_tile(&five.point.x).unwrap().red);
+        assert!(AkaDora { man: 0, pin: 2, sou: 0 }.lookup_tile(&five.point.x).unwrap().red);
+        assert!(!AkaDora::NONE.lookup_tile(&TILES[16].point.x).unwrap().red);
+    }
+
+    #[test]
+    fn test_presets() {
+        let riichi = DeckSpec::riichi().tiles();
+        assert_eq!(riichi, TILES[..136]);
+        assert_eq!(DeckSpec::full().tiles(), TILES);
+
+        let sanma = DeckSpec::sanma().build();
+        assert_eq!(sanma.len(), 108);
+        assert!(sanma.tiles().iter().all(|t| !matches!(t.tile(), Tile::Man(2..=8))));
+        assert_eq!(sanma.tiles().iter().filter(|t| t.red).count(), 2);
+        assert_eq!(sanma.lookup(&TILES[4].point.x), None);
+        assert_eq!(sanma.position(&TILES[32].point.x), Some(4));
+    }
+
+    #[test]
+    fn test_custom_spec() {
+        let spec = DeckSpec {
+            faces: vec![Tile::Pin(5), Tile::Honor(crate::tile::Honor::Red)],
+            copies: 3,
+            aka: AkaDora { man: 0, pin: 2, sou: 0 },
+            flowers: true,
+            jokers: 2,
+        };
+        let deck = spec.build();
+        assert_eq!(deck.len(), spec.len());
+        assert_eq!(deck.len(), 16);
+        assert_eq!(deck.tiles().iter().filter(|t| t.red).count(), 2);
+        assert_eq!(deck.plaintext(), spec.plaintext());
+
+        let jokers: Vec<_> = deck.tiles().iter().filter(|t| t.tile() == Tile::Joker).collect();
+        assert_eq!(jokers.iter().map(|t| t.copy()).collect::<Vec<_>>(), [0, 1]);
+        for (i, tile) in deck.tiles().iter().enumerate() {
+            assert_eq!(deck.lookup(&tile.point.x), Some(*tile));
+            assert_eq!(deck.position(&tile.point.x), Some(i));
+        }
+    }
+}
@@ -0,0 +1,344 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::map::TILES;
+use super::{BaseTile, TileKind};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Honor {
+    East,
+    South,
+    West,
+    North,
+    White,
+    Green,
+    Red,
+}
+
+impl Honor {
+    pub const ALL: [Honor; 7] = [
+        Honor::East,
+        Honor::South,
+        Honor::West,
+        Honor::North,
+        Honor::White,
+        Honor::Green,
+        Honor::Red,
+    ];
+
+    /// `ord` as used by [`TileKind::Zi`], 1 (East) to 7 (Red).
+    pub fn from_ord(ord: u8) -> Option<Honor> {
+        Self::ALL.get((ord as usize).checked_sub(1)?).copied()
+    }
+
+    pub fn ord(self) -> u8 {
+        self as u8 + 1
+    }
+
+    pub fn is_wind(self) -> bool {
+        self <= Honor::North
+    }
+
+    pub fn is_dragon(self) -> bool {
+        !self.is_wind()
+    }
+}
+
+/// Flowers and seasons, numbered 1 to 4 each.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Bonus {
+    Flower(u8),
+    Season(u8),
+}
+
+impl Bonus {
+    /// `ord` as used by [`TileKind::Fa`], flowers 1 to 4 then seasons 5 to 8.
+    pub fn from_ord(ord: u8) -> Option<Bonus> {
+        match ord {
+            1..=4 => Some(Bonus::Flower(ord)),
+            5..=8 => Some(Bonus::Season(ord - 4)),
+            _ => None,
+        }
+    }
+
+    pub fn ord(self) -> u8 {
+        match self {
+            Bonus::Flower(n) => n,
+            Bonus::Season(n) => n + 4,
+        }
+    }
+}
+
+/// The face of a tile, regardless of which physical copy it is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Tile {
+    Man(u8),
+    Pin(u8),
+    Sou(u8),
+    Honor(Honor),
+    Bonus(Bonus),
+    /// Wildcard tile of [`super::DeckSpec::jokers`], all copies alike.
+    Joker,
+}
+
+impl Tile {
+    pub fn kind(self) -> TileKind {
+        match self {
+            Tile::Man(_) => TileKind::Man,
+            Tile::Pin(_) => TileKind::Pin,
+            Tile::Sou(_) => TileKind::Sou,
+            Tile::Honor(_) => TileKind::Zi,
+            Tile::Bonus(_) => TileKind::Fa,
+            Tile::Joker => TileKind::Joker,
+        }
+    }
+
+    pub fn ord(self) -> u8 {
+        match self {
+            Tile::Man(n) | Tile::Pin(n) | Tile::Sou(n) => n,
+            Tile::Honor(h) => h.ord(),
+            Tile::Bonus(b) => b.ord(),
+            Tile::Joker => 1,
+        }
+    }
+
+    pub fn from_kind_ord(kind: TileKind, ord: u8) -> Option<Tile> {
+        match kind {
+            TileKind::Man if (1..=9).contains(&ord) => Some(Tile::Man(ord)),
+            TileKind::Pin if (1..=9).contains(&ord) => Some(Tile::Pin(ord)),
+            TileKind::Sou if (1..=9).contains(&ord) => Some(Tile::Sou(ord)),
+            TileKind::Zi => Honor::from_ord(ord).map(Tile::Honor),
+            TileKind::Fa => Bonus::from_ord(ord).map(Tile::Bonus),
+            TileKind::Joker if ord == 1 => Some(Tile::Joker),
+            _ => None,
+        }
+    }
+
+    /// Number of a suited tile.
+    pub fn number(self) -> Option<u8> {
+        match self {
+            Tile::Man(n) | Tile::Pin(n) | Tile::Sou(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    pub fn is_suited(self) -> bool {
+        self.number().is_some()
+    }
+
+    pub fn is_honor(self) -> bool {
+        matches!(self, Tile::Honor(_))
+    }
+
+    pub fn is_terminal(self) -> bool {
+        matches!(self.number(), Some(1 | 9))
+    }
+
+    /// Terminal or honor.
+    pub fn is_yaochuu(self) -> bool {
+        self.is_terminal() || self.is_honor()
+    }
+
+    pub fn is_bonus(self) -> bool {
+        matches!(self, Tile::Bonus(_))
+    }
+
+    /// Index in the usual 34 tile order `1m..9m 1p..9p 1s..9s 1z..7z`,
+    /// `None` for bonus tiles, jokers and ranks outside 1 to 9.
+    pub fn index(self) -> Option<usize> {
+        match self {
+            Tile::Man(n @ 1..=9) => Some(n as usize - 1),
+            Tile::Pin(n @ 1..=9) => Some(n as usize + 8),
+            Tile::Sou(n @ 1..=9) => Some(n as usize + 17),
+            Tile::Honor(h) => Some(h as usize + 27),
+            Tile::Man(_) | Tile::Pin(_) | Tile::Sou(_) | Tile::Bonus(_) | Tile::Joker => None,
+        }
+    }
+
+    pub fn from_index(index: usize) -> Option<Tile> {
+        let n = (index % 9) as u8 + 1;
+        match index {
+            0..=8 => Some(Tile::Man(n)),
+            9..=17 => Some(Tile::Pin(n)),
+            18..=26 => Some(Tile::Sou(n)),
+            27..=33 => Honor::from_ord(index as u8 - 26).map(Tile::Honor),
+            _ => None,
+        }
+    }
+
+    /// The `copy`-th physical tile with this face in [`TILES`].
+    pub fn to_base_tile(self, copy: usize) -> Option<BaseTile> {
+        TILES
+            .iter()
+            .filter(|t| Tile::from(*t) == self)
+            .nth(copy)
+            .copied()
+    }
+
+    pub fn to_char(self) -> char {
+        let offset = match self {
+            Tile::Man(n) => 0x07 + n as u32 - 1,
+            Tile::Sou(n) => 0x10 + n as u32 - 1,
+            Tile::Pin(n) => 0x19 + n as u32 - 1,
+            Tile::Honor(Honor::White) => 0x06,
+            Tile::Honor(Honor::Green) => 0x05,
+            Tile::Honor(Honor::Red) => 0x04,
+            Tile::Honor(h) => h as u32,
+            Tile::Bonus(b) => 0x22 + b.ord() as u32 - 1,
+            Tile::Joker => 0x2A,
+        };
+        char::from_u32(0x1F000 + offset).unwrap()
+    }
+
+    pub fn from_char(c: char) -> Option<Tile> {
+        let offset = (c as u32).checked_sub(0x1F000)?;
+        let n = |base: u32| (offset - base + 1) as u8;
+        match offset {
+            0x00..=0x03 => Some(Tile::Honor(Honor::ALL[offset as usize])),
+            0x04 => Some(Tile::Honor(Honor::Red)),
+            0x05 => Some(Tile::Honor(Honor::Green)),
+            0x06 => Some(Tile::Honor(Honor::White)),
+            0x07..=0x0F => Some(Tile::Man(n(0x07))),
+            0x10..=0x18 => Some(Tile::Sou(n(0x10))),
+            0x19..=0x21 => Some(Tile::Pin(n(0x19))),
+            0x22..=0x29 => Bonus::from_ord(n(0x22)).map(Tile::Bonus),
+            0x2A => Some(Tile::Joker),
+            _ => None,
+        }
+    }
+
+    /// Notation suffix, `f` covers flowers `1f..4f` and seasons `5f..8f`,
+    /// a joker is `1j`.
+    pub(crate) fn suffix(self) -> char {
+        match self {
+            Tile::Man(_) => 'm',
+            Tile::Pin(_) => 'p',
+            Tile::Sou(_) => 's',
+            Tile::Honor(_) => 'z',
+            Tile::Bonus(_) => 'f',
+            Tile::Joker => 'j',
+        }
+    }
+
+    pub(crate) fn from_digit_suffix(digit: u8, suffix: char) -> Option<Tile> {
+        let kind = match suffix {
+            'm' => TileKind::Man,
+            'p' => TileKind::Pin,
+            's' => TileKind::Sou,
+            'z' => TileKind::Zi,
+            'f' => TileKind::Fa,
+            'j' => TileKind::Joker,
+            _ => return None,
+        };
+        Tile::from_kind_ord(kind, digit)
+    }
+}
+
+impl From<&BaseTile> for Tile {
+    fn from(tile: &BaseTile) -> Self {
+        Tile::from_kind_ord(tile.kind, tile.ord).unwrap()
+    }
+}
+
+impl From<BaseTile> for Tile {
+    fn from(tile: BaseTile) -> Self {
+        Tile::from(&tile)
+    }
+}
+
+impl BaseTile {
+    pub fn tile(&self) -> Tile {
+        Tile::from(self)
+    }
+}
+
+/// `1m`, `7z`, or the Unicode glyph with the alternate flag (`{:#}`).
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.to_char())
+        } else {
+            write!(f, "{}{}", self.ord(), self.suffix())
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseTileError(pub String);
+
+impl Display for ParseTileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid tile: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseTileError {}
+
+impl FromStr for Tile {
+    type Err = ParseTileError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseTileError(s.to_string());
+        let mut chars = s.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(c), None, None) => Tile::from_char(c).ok_or_else(err),
+            (Some(digit), Some(suffix), None) => digit
+                .to_digit(10)
+                .and_then(|d| Tile::from_digit_suffix(d as u8, suffix))
+                .ok_or_else(err),
+            _ => Err(err()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_tile_round_trip() {
+        for base in TILES.iter() {
+            let tile = base.tile();
+            assert_eq!((tile.kind(), tile.ord()), (base.kind, base.ord));
+            assert!((0..4).any(|copy| tile.to_base_tile(copy) == Some(*base)));
+        }
+        assert_eq!(TILES[108].tile(), Tile::Honor(Honor::East));
+        assert_eq!(TILES[135].tile(), Tile::Honor(Honor::Red));
+        assert_eq!(TILES[143].tile(), Tile::Bonus(Bonus::Season(4)));
+        assert_eq!(Tile::Bonus(Bonus::Flower(1)).to_base_tile(1), None);
+    }
+
+    #[test]
+    fn test_index() {
+        for i in 0..34 {
+            assert_eq!(Tile::from_index(i).unwrap().index(), Some(i));
+        }
+        assert_eq!(Tile::from_index(34), None);
+        assert_eq!(Tile::Honor(Honor::White).index(), Some(31));
+        // ranks a deserialized tile may carry
+        assert_eq!(Tile::Man(0).index(), None);
+        assert_eq!(Tile::Pin(0).index(), None);
+        assert_eq!(Tile::Pin(10).index(), None);
+    }
+
+    #[test]
+    fn test_notation() {
+        for base in TILES.iter() {
+            let tile = base.tile();
+            assert_eq!(tile.to_string().parse::<Tile>(), Ok(tile));
+            assert_eq!(format!("{:#}", tile).parse::<Tile>(), Ok(tile));
+        }
+        assert_eq!("5p".parse::<Tile>(), Ok(Tile::Pin(5)));
+        assert_eq!("7z".parse::<Tile>(), Ok(Tile::Honor(Honor::Red)));
+        assert_eq!(format!("{:#}", Tile::Man(1)), "🀇");
+        assert_eq!(format!("{:#}", Tile::Honor(Honor::Red)), "🀄");
+        assert_eq!("🀆".parse::<Tile>(), Ok(Tile::Honor(Honor::White)));
+        assert_eq!("1j".parse::<Tile>(), Ok(Tile::Joker));
+        assert_eq!(format!("{:#}", Tile::Joker).parse::<Tile>(), Ok(Tile::Joker));
+        assert!("8z".parse::<Tile>().is_err());
+        assert!("0m".parse::<Tile>().is_err());
+        assert!("1x".parse::<Tile>().is_err());
+    }
+}
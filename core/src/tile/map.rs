@@ -3,150 +3,150 @@ use crate::bn128::Fr;
 use crate::tile::*;
 
 pub const TILES: [BaseTile; 144] = [
-    BaseTile { idx: 0, point: Point { x: Fr::from_raw([0x0a8fc7bc1a89fa86, 0xa7d9d786e9e48627, 0xee6158b465bea369, 0x14a0ff6d2f874519, ]), y: Fr::from_raw([0xb83342d20d0201aa, 0x2ffef2f7cdcfeac7, 0xbfa79a9425a6e625, 0x0dfb859dc3a44b70, ]) }, kind: TileKind::Man, ord: 1 },
-    BaseTile { idx: 1, point: Point { x: Fr::from_raw([0xee997be0bc98a0a6, 0x217f0d6e0991d346, 0x05eca11c22133ac3, 0x0bd6d33b661a263b, ]), y: Fr::from_raw([0xef5e76322749533e, 0x5e5647daec7375f1, 0x0901ac29597a2def, 0x0b715a7fbd840830, ]) }, kind: TileKind::Man, ord: 1 },
-    BaseTile { idx: 2, point: Point { x: Fr::from_raw([0xd5cbcc5718f8e86f, 0x2963a270b38e60ba, 0x04fc18704692faf8, 0x1cbb2849dbf79a70, ]), y: Fr::from_raw([0xbb41c8702d2790be, 0x139b7576ad0f61db, 0x930b9fe37fbb42ee, 0x052fe2e89b47b2fc, ]) }, kind: TileKind::Man, ord: 1 },
-    BaseTile { idx: 3, point: Point { x: Fr::from_raw([0x215a431e9077fe84, 0x6790672a335063eb, 0x07495bef4ab884f0, 0x13456903f5712721, ]), y: Fr::from_raw([0xa766f89e7c0da8aa, 0x6c9f989125ca5c72, 0xd479bc5889b69dd7, 0x159efa9468d1465a, ]) }, kind: TileKind::Man, ord: 1 },
-    BaseTile { idx: 4, point: Point { x: Fr::from_raw([0xc8184e50277ac74a, 0x5b2505922b62927d, 0xaab207b9bc835b3a, 0x2506051ff27b4c19, ]), y: Fr::from_raw([0x4729225f6c684fd5, 0xb28d7a69f63b8435, 0x6b38e0452656a971, 0x086e71ecbdba02b3, ]) }, kind: TileKind::Man, ord: 2 },
-    BaseTile { idx: 5, point: Point { x: Fr::from_raw([0x9d07f32b65343ce6, 0x1e63022b398dd2ab, 0x5fe5c3da4c17ef34, 0x212e8697d990ede2, ]), y: Fr::from_raw([0x39a5106d84b3e0e7, 0x7b6e50f18e02204c, 0x3f10f71e1a113af0, 0x2379320420b29b90, ]) }, kind: TileKind::Man, ord: 2 },
-    BaseTile { idx: 6, point: Point { x: Fr::from_raw([0x8c4feef6f3f64f5b, 0x708a47c90f0d513b, 0x23c6b8e7792ffc30, 0x01fc1ff78b9bac81, ]), y: Fr::from_raw([0x71fbf02767a855ab, 0xa073d69073e88aed, 0xc7af6ca281a02878, 0x21d65f60940eb189, ]) }, kind: TileKind::Man, ord: 2 },
-    BaseTile { idx: 7, point: Point { x: Fr::from_raw([0x8f4194eab440fda2, 0x701bbdc7fa69ceed, 0x69b9ee0231d35ac5, 0x2f26ed4c985d5817, ]), y: Fr::from_raw([0xef8ed8d8ac04f79b, 0x738bb35b7d337db2, 0x94618d123ca5c43d, 0x2cbef600ab75c562, ]) }, kind: TileKind::Man, ord: 2 },
-    BaseTile { idx: 8, point: Point { x: Fr::from_raw([0x46219553525b1ee8, 0x030126b0ab5c8694, 0x91c44cd7bbf5668f, 0x262f5192436032fa, ]), y: Fr::from_raw([0xf772d00857c4fb0e, 0xe3619385c46b40db, 0x5d3587911d6b45b7, 0x2e0f0dbf77a62f15, ]) }, kind: TileKind::Man, ord: 3 },
-    BaseTile { idx: 9, point: Point { x: Fr::from_raw([0x7af5a93f6d511002, 0x758bc7ed53e668b9, 0xea9efbbd6cca4b99, 0x1fa8dc0f6e2e5021, ]), y: Fr::from_raw([0x04865621c06a5b98, 0xeeb4b177a8ab867b, 0x41e81ef6200b34da, 0x1be90a4be87b69d0, ]) }, kind: TileKind::Man, ord: 3 },
-    BaseTile { idx: 10, point: Point { x: Fr::from_raw([0x08f63e3d86a4c409, 0x9388ec511d0358c6, 0x62a05e3ecefbce76, 0x19b19413ff7e596d, ]), y: Fr::from_raw([0x4cfda432a16a87c7, 0xc93bcd48136bfcfe, 0x5bf41b7c7d2fcbff, 0x078ee57d87c2d878, ]) }, kind: TileKind::Man, ord: 3 },
-    BaseTile { idx: 11, point: Point { x: Fr::from_raw([0x7d97baad8a0413a7, 0x3c5361f65db0b00f, 0x0e98081d1f787162, 0x213a0d3e4cff6e91, ]), y: Fr::from_raw([0xc6a4fe4ffff84609, 0x7e7f4b906922c4a7, 0x2ad8c9f06079cd6e, 0x1938e7debc36016b, ]) }, kind: TileKind::Man, ord: 3 },
-    BaseTile { idx: 12, point: Point { x: Fr::from_raw([0x5375bf0b3c46c12d, 0x991e6f5fde2311e0, 0x99ac6fca52c722f5, 0x2fd1d9fdbe5825d2, ]), y: Fr::from_raw([0x31c5834728af47e4, 0xf5124cb017e0a030, 0xec2cfb81f9367002, 0x2508d7a4e224b18a, ]) }, kind: TileKind::Man, ord: 4 },
-    BaseTile { idx: 13, point: Point { x: Fr::from_raw([0x671eb14e5ba626f3, 0xf47136a65be90b26, 0xb5ebf0710c65ac4a, 0x243cdf31d1f51a95, ]), y: Fr::from_raw([0xa2a34f15f5a290f3, 0x9431d0c372818efe, 0xf418117ff3e1e9cd, 0x1705c0c98a510818, ]) }, kind: TileKind::Man, ord: 4 },
-    BaseTile { idx: 14, point: Point { x: Fr::from_raw([0xd50bdb30a9d8a106, 0x4fe98341e3304178, 0x2a6a171a85a58d12, 0x22027d587dc5f7ce, ]), y: Fr::from_raw([0xefee81995fb3fdb2, 0x38aa4cf85fd960dd, 0xd63c51fcd47ca02a, 0x29f7cca044657426, ]) }, kind: TileKind::Man, ord: 4 },
-    BaseTile { idx: 15, point: Point { x: Fr::from_raw([0x6ace774835df2902, 0x01caf0ad4655e98e, 0x43b643c2f926208d, 0x2d60507cbcc3a15b, ]), y: Fr::from_raw([0xdaab3713d1a78f09, 0x8ea4a675213e2f03, 0xa624feaf4a608d00, 0x2fd947a6984f6e00, ]) }, kind: TileKind::Man, ord: 4 },
-    BaseTile { idx: 16, point: Point { x: Fr::from_raw([0x6b044d4087d92e11, 0x9775eb155e57120b, 0x20891ca57d948706, 0x29f404584277bd1d, ]), y: Fr::from_raw([0xfe184eea19703b35, 0x059ebf25b57c4b9a, 0x75869860f5327a58, 0x06b3c51b5d262159, ]) }, kind: TileKind::Man, ord: 5 },
-    BaseTile { idx: 17, point: Point { x: Fr::from_raw([0x2953d886dc3c800f, 0x13d4bf591d4480fe, 0x239696717cf923f8, 0x21d0a6d7ff0c5796, ]), y: Fr::from_raw([0x1284a98eacc58626, 0x33bef6b9bded267b, 0x4e57c7baa05f8897, 0x0cac7c2a75c61c76, ]) }, kind: TileKind::Man, ord: 5 },
-    BaseTile { idx: 18, point: Point { x: Fr::from_raw([0x0abab80c00bce54d, 0xe7768345e68b991d, 0x9e46e93b0236d3f9, 0x18346fee0657dfe9, ]), y: Fr::from_raw([0xc2a62864285a13cc, 0x7afad644515da406, 0x34a4828a210a614a, 0x1776bef0b795593c, ]) }, kind: TileKind::Man, ord: 5 },
-    BaseTile { idx: 19, point: Point { x: Fr::from_raw([0x89f5b01880973334, 0x403d4b541f48ad2d, 0x3fbfc0ae285cc637, 0x2d056207ba418278, ]), y: Fr::from_raw([0x7010b007559c4b46, 0x66b19c75a1b7efc9, 0xdcc0402d1b8e6342, 0x1066d534e2afeb19, ]) }, kind: TileKind::Man, ord: 5 },
-    BaseTile { idx: 20, point: Point { x: Fr::from_raw([0x5a9d8b2eec3c2a80, 0x2aed945ac84aeeca, 0xeafbfd899b071864, 0x114191a8f59bb25d, ]), y: Fr::from_raw([0x1594e121cb6539cd, 0x697cb6c30e8d0f4d, 0x30c3f04daee3057e, 0x0f3acb2da850be63, ]) }, kind: TileKind::Man, ord: 6 },
-    BaseTile { idx: 21, point: Point { x: Fr::from_raw([0xd0c284187cf572e5, 0xb3df7950311c6468, 0x9fa9e196d955626b, 0x11817b09cbade219, ]), y: Fr::from_raw([0xac8d8e62cf004f96, 0x411e5be92dae9f18, 0x6c1e04aa4c7f413d, 0x04da17bf6e7426e9, ]) }, kind: TileKind::Man, ord: 6 },
-    BaseTile { idx: 22, point: Point { x: Fr::from_raw([0xc689ffff455e0340, 0xe802a4a5cbc618a1, 0x1e6136cc7c9a85a9, 0x1e0d96081df74aeb, ]), y: Fr::from_raw([0x3d06325e9ea9579b, 0xa76462e984a0cb38, 0x4f0b66983c701dc2, 0x1b95f39e54aad1ce, ]) }, kind: TileKind::Man, ord: 6 },
-    BaseTile { idx: 23, point: Point { x: Fr::from_raw([0xe0ab9ac9dd8a4848, 0xd7fd355a628bd7b6, 0xa9839ec96e63658f, 0x23290460b451f749, ]), y: Fr::from_raw([0x9bbbb7be3f5419ee, 0x4a697e7bda944cf5, 0x6d425fa497695527, 0x083c100367b211a2, ]) }, kind: TileKind::Man, ord: 6 },
-    BaseTile { idx: 24, point: Point { x: Fr::from_raw([0x94ba616b53a42fc9, 0xd94287ee8b6ef02c, 0x5a6fed859eb8caef, 0x238d964da4ccbbb1, ]), y: Fr::from_raw([0x2ec91e4df6eb58ca, 0x6c6fd93e55efe3dd, 0x333405d236eb611c, 0x1192a31cecbc178f, ]) }, kind: TileKind::Man, ord: 7 },
-    BaseTile { idx: 25, point: Point { x: Fr::from_raw([0x2afb9c9c5c0c9d6c, 0xf62b2a97f03a5b82, 0xf599e7b774834681, 0x00f5afb5901b8583, ]), y: Fr::from_raw([0xd380c6814749e201, 0x9b7fc3cb6ae92ad2, 0x1ad1e8bc26393cd2, 0x17264e56a0dbee5f, ]) }, kind: TileKind::Man, ord: 7 },
-    BaseTile { idx: 26, point: Point { x: Fr::from_raw([0xff530608ed713552, 0xfb3992f968cbb1b7, 0x48b1a419e3e6fe09, 0x175a5c53d68aa7db, ]), y: Fr::from_raw([0x55dcf2685d8f753e, 0x64d75f0c779f25a9, 0x142ebb98b689cecd, 0x117b76e86dcd6b32, ]) }, kind: TileKind::Man, ord: 7 },
-    BaseTile { idx: 27, point: Point { x: Fr::from_raw([0xb1b2d07820f3570f, 0x2dd14219c45a4eb0, 0x849706e5c624738e, 0x2c3c757c680bb5fa, ]), y: Fr::from_raw([0x831400bb2cac2b56, 0xd5f25a2f0236fed8, 0x7c1292476044aa4d, 0x10a9ec97c588d100, ]) }, kind: TileKind::Man, ord: 7 },
-    BaseTile { idx: 28, point: Point { x: Fr::from_raw([0x05d8e55704fd3513, 0xd27ed37f7632549b, 0x6f862c6280d030bd, 0x24ce704805ed2613, ]), y: Fr::from_raw([0xc67e49eb7ba5c120, 0x09736e1b533eff5e, 0xe5604ffb21a93f7b, 0x034f81d6460dd8bf, ]) }, kind: TileKind::Man, ord: 8 },
-    BaseTile { idx: 29, point: Point { x: Fr::from_raw([0x0d5430b26df6460b, 0x0ef89c676bd8c893, 0x64d8b0608ef807a3, 0x25353aa744aa8fad, ]), y: Fr::from_raw([0xe3030a425982641e, 0x95620db64a7acc29, 0x0753d462ed8d8f93, 0x254df43b0a67721f, ]) }, kind: TileKind::Man, ord: 8 },
-    BaseTile { idx: 30, point: Point { x: Fr::from_raw([0xd1ba61536cfe5b21, 0xeb3efd0f9f61ab2c, 0x5ecce07773a4a5cd, 0x22a594f9781d4525, ]), y: Fr::from_raw([0xe6b08a0edef6b09d, 0x165af945812010a4, 0x7a2ba8cf7f106e5f, 0x151732fd7ddb39df, ]) }, kind: TileKind::Man, ord: 8 },
-    BaseTile { idx: 31, point: Point { x: Fr::from_raw([0x9b8c2852c079838f, 0x6b97b0f3c45416d9, 0x1faabd2ed0b075ff, 0x0f3ba189f44cda1a, ]), y: Fr::from_raw([0x2bee0d384970b051, 0x5e9c0a27891eb12e, 0xdec92986c58c3135, 0x2501514354fa8a0b, ]) }, kind: TileKind::Man, ord: 8 },
-    BaseTile { idx: 32, point: Point { x: Fr::from_raw([0x98d0135650cb55cc, 0xe70ad79b108890f6, 0xb770669bfc376171, 0x0c8cb7dfdf9d6221, ]), y: Fr::from_raw([0x328743c6b791377f, 0x130a64fd1c919584, 0x6cc3904f03aec9f8, 0x16e2e71ed49677a9, ]) }, kind: TileKind::Man, ord: 9 },
-    BaseTile { idx: 33, point: Point { x: Fr::from_raw([0xb61153d9527219a4, 0x6c41fcf23f0ffd1c, 0x4c5c2b9160ee4859, 0x0696fffa28744a84, ]), y: Fr::from_raw([0xac33fd8b7f5aab42, 0xb64fc1a1b7d2d494, 0xc3cf9c5fc22982cd, 0x2e400c765aac16bf, ]) }, kind: TileKind::Man, ord: 9 },
-    BaseTile { idx: 34, point: Point { x: Fr::from_raw([0x1fef130ce3d83af0, 0x20c0aead9cdf4046, 0xc72a225a463ae3e2, 0x20151414f15ffaa6, ]), y: Fr::from_raw([0xe91c2051fe73d13c, 0x64929104f996b82c, 0xe69e8212465f3fde, 0x2550fa7f27dc8fcc, ]) }, kind: TileKind::Man, ord: 9 },
-    BaseTile { idx: 35, point: Point { x: Fr::from_raw([0x20db82d3d4b92fe2, 0xfefac4df89103714, 0x728ebfb6690ce05e, 0x1e1f8b76f93ec61c, ]), y: Fr::from_raw([0xe51f665cc952eb26, 0x4a15705c13a4e8bb, 0x9f2cbb2cd4669f10, 0x1e6aa987f215df15, ]) }, kind: TileKind::Man, ord: 9 },
-    BaseTile { idx: 36, point: Point { x: Fr::from_raw([0xcc8132f46c4da4d1, 0xf8d6fe18e66e8e02, 0x3dd73523909aa1fd, 0x25b8bcf3a07030bc, ]), y: Fr::from_raw([0x2f7105aec075d030, 0xd118b109c90bb2f4, 0x7470713998f9c76e, 0x2fefaf5e21727141, ]) }, kind: TileKind::Pin, ord: 1 },
-    BaseTile { idx: 37, point: Point { x: Fr::from_raw([0xd13b6e68a15b5718, 0xeac7e20d5c78914c, 0xbbcaa6625c60a18e, 0x215ef5046cf6e4eb, ]), y: Fr::from_raw([0x0ef603eb2516d479, 0xe9d1fc37a4e4ed4f, 0xa97d92fd291f6e5e, 0x1bf758de928b774e, ]) }, kind: TileKind::Pin, ord: 1 },
-    BaseTile { idx: 38, point: Point { x: Fr::from_raw([0xfec2f7871b7bbfa9, 0x3682c83e37a0c380, 0x52c378d546b2761c, 0x20b1308532fc32d5, ]), y: Fr::from_raw([0x17db2cb14d946356, 0xfeafbb1df5bf9b1d, 0xa1a0cd4e984ea618, 0x1625f7cd957462f5, ]) }, kind: TileKind::Pin, ord: 1 },
-    BaseTile { idx: 39, point: Point { x: Fr::from_raw([0xa6c10350f3288116, 0x84f49e9d77cd0555, 0xc0e7b58e04cc9077, 0x076eb39a57fd9a35, ]), y: Fr::from_raw([0x572ab27f6862bb32, 0xd4997077024944a5, 0xe74b2b13c795dfcf, 0x1c84387c982885e4, ]) }, kind: TileKind::Pin, ord: 1 },
-    BaseTile { idx: 40, point: Point { x: Fr::from_raw([0x058a1a83366ed800, 0x888d27e4f95e7652, 0x94a7f709f18d5467, 0x238fc7f1ada5a7c8, ]), y: Fr::from_raw([0x5d8e0272f460a507, 0x5a2fae80698a6f1e, 0x893f25674e51a837, 0x2659824980209073, ]) }, kind: TileKind::Pin, ord: 2 },
-    BaseTile { idx: 41, point: Point { x: Fr::from_raw([0x3e71d7cfd46358e4, 0x2ddfca4d86fb7f31, 0xa7a2694dad42ea4e, 0x25ec1c813168eedf, ]), y: Fr::from_raw([0x7a25d628c67381cd, 0x5cd968931b834bc1, 0xe5cdb9ce7084fd4c, 0x091309a6404fdefc, ]) }, kind: TileKind::Pin, ord: 2 },
-    BaseTile { idx: 42, point: Point { x: Fr::from_raw([0x5d62157198ad26ae, 0x639b4f04f2da81f9, 0x7c89c397e1868229, 0x014fb022cc0b720f, ]), y: Fr::from_raw([0x913e6f7ac726aaa1, 0xf562fc79b91201d1, 0xe7978114ac13ece5, 0x19498ea112a1451c, ]) }, kind: TileKind::Pin, ord: 2 },
-    BaseTile { idx: 43, point: Point { x: Fr::from_raw([0xc9b7f80d247048a4, 0x846310662e8125b3, 0x08e66538bb15ffb3, 0x276d83fd93739a8d, ]), y: Fr::from_raw([0x575d9c3534cace23, 0x43c82bf84e229f89, 0xa35a94c03736337b, 0x20498139ae9a48a0, ]) }, kind: TileKind::Pin, ord: 2 },
-    BaseTile { idx: 44, point: Point { x: Fr::from_raw([0xb840de11ee9c1b66, 0xbd13eb094586ef95, 0xc3c00eac38b9728a, 0x1698d1914f83e925, ]), y: Fr::from_raw([0xcd93561a00ffb92e, 0x9f76258254393a7a, 0x6456cf398ad848de, 0x1b78405409acb34e, ]) }, kind: TileKind::Pin, ord: 3 },
-    BaseTile { idx: 45, point: Point { x: Fr::from_raw([0x9e57b509ea4ce4a0, 0xdb9f39cdf785d3a1, 0x876bad5927481aac, 0x16a09b71bffd337c, ]), y: Fr::from_raw([0x3c59da4b68c2743a, 0xbbdf0b7d16911e7e, 0xd7c2111062551f24, 0x2d3d196356b87c9f, ]) }, kind: TileKind::Pin, ord: 3 },
-    BaseTile { idx: 46, point: Point { x: Fr::from_raw([0xe43298d8d12819d6, 0xc795cccd6161cb6d, 0x0330c1d49c143d44, 0x0fb49058eed4d88f, ]), y: Fr::from_raw([0x44f8da237d5e5f65, 0x29b1d4e588fe61c9, 0x12330275e92073d1, 0x172f1a6c51445848, ]) }, kind: TileKind::Pin, ord: 3 },
-    BaseTile { idx: 47, point: Point { x: Fr::from_raw([0x794d6fdbf6fd0bb8, 0x362abc1f25138389, 0x0af6ffe06d07f429, 0x2ca86cfc9af257be, ]), y: Fr::from_raw([0x7a0d2b45e9718bb6, 0x64ef08ef51a12c92, 0x090d6a39456e49df, 0x3040d527782d349f, ]) }, kind: TileKind::Pin, ord: 3 },
-    BaseTile { idx: 48, point: Point { x: Fr::from_raw([0x36a55a9d51521749, 0xe397aafb233ec950, 0xa9b16f82ca687818, 0x0a381321ade867b6, ]), y: Fr::from_raw([0xcde62b3c67bff1cf, 0x39e1dfec166fcbcd, 0xaa78f3662e0ea47e, 0x186b4495357d802b, ]) }, kind: TileKind::Pin, ord: 4 },
-    BaseTile { idx: 49, point: Point { x: Fr::from_raw([0xcc0d8a8a0416161c, 0x9aba307d362abc4d, 0x6827a5906d058f97, 0x0bef86c35703f973, ]), y: Fr::from_raw([0x845b31449f54c0da, 0x5cbe103eaf531727, 0xec6b584c2b86106b, 0x272c0f3f59403489, ]) }, kind: TileKind::Pin, ord: 4 },
-    BaseTile { idx: 50, point: Point { x: Fr::from_raw([0x64c3e0f6530d0628, 0x3218c14cefa6458d, 0x1a347c11413d97f7, 0x02b132aa486b5781, ]), y: Fr::from_raw([0x337b9b46a5140d5d, 0x5135f950c7264800, 0x42e021884ee22bce, 0x169c8ba9242bd0b9, ]) }, kind: TileKind::Pin, ord: 4 },
-    BaseTile { idx: 51, point: Point { x: Fr::from_raw([0xfdf94d6192932ce9, 0xb80bb53b4409981c, 0xa6ba117e4f3e3625, 0x2441a5009269b547, ]), y: Fr::from_raw([0x67075a8a7c6a477a, 0x1137daf95c534c9e, 0x2096e20990c55242, 0x2800d4f0503d1058, ]) }, kind: TileKind::Pin, ord: 4 },
-    BaseTile { idx: 52, point: Point { x: Fr::from_raw([0x5aa5235583a16d4c, 0xb870da77356f9cd3, 0x4de4589dc290270b, 0x21a39629fb8784ca, ]), y: Fr::from_raw([0x72d3ea87e03a6c3d, 0xd8f3304f14a046b2, 0xb2fb4d4efd8d9a3d, 0x2d92d0fc800bf3a0, ]) }, kind: TileKind::Pin, ord: 5 },
-    BaseTile { idx: 53, point: Point { x: Fr::from_raw([0x7bec06ed8842539c, 0xbe1b97f38c3ab966, 0xb23ef4805322c67c, 0x0a9ab726d949ed83, ]), y: Fr::from_raw([0x3649eb31add63169, 0x6f56b7bd71d8bb31, 0x19fa0e311606df69, 0x11dcf24a6eab944b, ]) }, kind: TileKind::Pin, ord: 5 },
-    BaseTile { idx: 54, point: Point { x: Fr::from_raw([0x82da44ba7610fe33, 0x8b15b427084c7328, 0xa9f90f4ca4fd5f68, 0x0c2b6aeb584e8466, ]), y: Fr::from_raw([0x55c6b1363998719e, 0xd7af24a1a0594356, 0xa8917a472ce3d394, 0x1eea4152617cb474, ]) }, kind: TileKind::Pin, ord: 5 },
-    BaseTile { idx: 55, point: Point { x: Fr::from_raw([0x4391c8772c0e6cfe, 0x27abd069fece25a9, 0x625c2a2b68e21db7, 0x1ed22adb9f5558fe, ]), y: Fr::from_raw([0xfbb30d59ec131a96, 0xadd660b589d174d8, 0x3cfeec42dcff110c, 0x2ee4c8dd06fda431, ]) }, kind: TileKind::Pin, ord: 5 },
-    BaseTile { idx: 56, point: Point { x: Fr::from_raw([0x5c217f40bf385144, 0x61d2c6e0fbc8fb89, 0x679b9a5835dd9b7c, 0x14b6b4c7811b59c4, ]), y: Fr::from_raw([0x208cc34328cb7726, 0xed22e9d10627171d, 0x38e18fc3735e102d, 0x0f2369df5381d928, ]) }, kind: TileKind::Pin, ord: 6 },
-    BaseTile { idx: 57, point: Point { x: Fr::from_raw([0xb8fa7f69e5942fc9, 0x6721e320b87b64d4, 0x06c62f2304830a59, 0x2c4f2c2b473fb02d, ]), y: Fr::from_raw([0x6840acb51cb8eaa2, 0x9f5001bf42d1de94, 0x6a330b3ea27523a6, 0x2b02a6d324c77bf9, ]) }, kind: TileKind::Pin, ord: 6 },
-    BaseTile { idx: 58, point: Point { x: Fr::from_raw([0x812aa50a53b57276, 0xae85e2a82e45b244, 0x0b744ab41bffa7cf, 0x0c24a0c768d0e6ab, ]), y: Fr::from_raw([0xf4032420fe0e0d2a, 0x8ef3f417cfc4259f, 0x61785ae987497ed0, 0x2b30e9f98f2f0842, ]) }, kind: TileKind::Pin, ord: 6 },
-    BaseTile { idx: 59, point: Point { x: Fr::from_raw([0x0ea92b2dfc791538, 0x251ff24c05f4be4f, 0x6a39d271460f7327, 0x2a7cf541a6d3af71, ]), y: Fr::from_raw([0xdd373ec763973d70, 0xfe4f225496f69e55, 0x7743a09612cf12d2, 0x2b9f3c9ed09e6f96, ]) }, kind: TileKind::Pin, ord: 6 },
-    BaseTile { idx: 60, point: Point { x: Fr::from_raw([0x47227a836741369f, 0xc867faf23a9852c9, 0x646dac512a5d6ca8, 0x2b4365de5c7d0ccb, ]), y: Fr::from_raw([0xc1698e427998d659, 0x654ef4869ad8e327, 0xe6c0b81ab7e928e3, 0x097addf1b16c8ea6, ]) }, kind: TileKind::Pin, ord: 7 },
-    BaseTile { idx: 61, point: Point { x: Fr::from_raw([0xa3ab0af686fb64e4, 0xb2462630e2cf8e48, 0x5da1f8033078f04b, 0x08ce3b1a7d7834fb, ]), y: Fr::from_raw([0xde0e5fdbd50fdced, 0x4cec034dd1536c46, 0x9dc53757b00e874e, 0x202e40e1398c6262, ]) }, kind: TileKind::Pin, ord: 7 },
-    BaseTile { idx: 62, point: Point { x: Fr::from_raw([0xa7cffb8840418efe, 0x530df386eb105b9e, 0x263a036ea849661b, 0x0e2c718ba1a3e420, ]), y: Fr::from_raw([0x13aa2da253a8aad8, 0x0e5bad047417ebf5, 0x6d5a0d1859cc8978, 0x0c1a09854f807097, ]) }, kind: TileKind::Pin, ord: 7 },
-    BaseTile { idx: 63, point: Point { x: Fr::from_raw([0x83bd59f89be0d17a, 0x68a637dab4c7c564, 0x814971a0901c7cec, 0x29087a7213378034, ]), y: Fr::from_raw([0x4e59cf62d28fc4cf, 0x987be039beccfdfc, 0xfbedbb52fadaf134, 0x25685b49b56bb777, ]) }, kind: TileKind::Pin, ord: 7 },
-    BaseTile { idx: 64, point: Point { x: Fr::from_raw([0x78e0081ad7150951, 0x6163e799f261a4fb, 0xb709dc1660b74b6e, 0x2bd9b31ccf5e3629, ]), y: Fr::from_raw([0x821d04c4769f099f, 0x3768b7c5e3ea349e, 0x4ca7a4d57afdea7a, 0x28b40a37f9360599, ]) }, kind: TileKind::Pin, ord: 8 },
-    BaseTile { idx: 65, point: Point { x: Fr::from_raw([0x589aa36cfe0f898e, 0xbe834cb910c3b9c1, 0xd7e693524d3a60fa, 0x1deab2cdb7faee04, ]), y: Fr::from_raw([0x48e5b005cbc6d1a3, 0x6a22dd20b3b77c6d, 0x3f8ee26d2f261234, 0x21c579d7be1b39c4, ]) }, kind: TileKind::Pin, ord: 8 },
-    BaseTile { idx: 66, point: Point { x: Fr::from_raw([0x95801212ba78664d, 0x6f36ea98828cab58, 0xcbe0c1b26b50eff3, 0x11965b4e06e13236, ]), y: Fr::from_raw([0xaa70962c4bc1ded2, 0x4bdda0ba4322e463, 0x753d607f64e24956, 0x1119bb75c325f8d5, ]) }, kind: TileKind::Pin, ord: 8 },
-    BaseTile { idx: 67, point: Point { x: Fr::from_raw([0x2e6bd72fd087d3d7, 0x0481a2a03cbdd74b, 0xef573f8cf65642ec, 0x26f7846aa037f022, ]), y: Fr::from_raw([0xa7c4adf1a0ae81c9, 0xe97b435947d72383, 0x73e75e2f84c87847, 0x207ee04a3950082b, ]) }, kind: TileKind::Pin, ord: 8 },
-    BaseTile { idx: 68, point: Point { x: Fr::from_raw([0x8ada5d4431530b06, 0x04f1d109724cab15, 0xc0aea0558b630459, 0x0fffdff5456e1f9f, ]), y: Fr::from_raw([0xbaa0c683fd801467, 0x1ceb895d81f06503, 0xe4754ce1ee6fef35, 0x2e1ce64f16e4ae9a, ]) }, kind: TileKind::Pin, ord: 9 },
-    BaseTile { idx: 69, point: Point { x: Fr::from_raw([0xe383ed74dd59914b, 0xe74c348c0edb8685, 0x62b79ee38ef4bddc, 0x1a661b41c101eff6, ]), y: Fr::from_raw([0x1e9774dcb8aebb2f, 0xca16791cd49c3fa3, 0x2edb96037b5fcadf, 0x123c9fca07b9e915, ]) }, kind: TileKind::Pin, ord: 9 },
-    BaseTile { idx: 70, point: Point { x: Fr::from_raw([0x1dcf6b41104a775e, 0x76c20e19f5e13960, 0xb4bafca8a42eb86e, 0x0a2ed645d5a36bbb, ]), y: Fr::from_raw([0x7747dd11ba1cb03c, 0x36e2e8b6650bf07b, 0xaa0a07b067da53f3, 0x254c579a9b743ed4, ]) }, kind: TileKind::Pin, ord: 9 },
-    BaseTile { idx: 71, point: Point { x: Fr::from_raw([0x5fcb262b30c0e241, 0x50b90a31c391ff4b, 0xc89c413232ea7565, 0x01129a74ae957891, ]), y: Fr::from_raw([0x265f316ba136e862, 0x6b962d1d0c7958c4, 0xcf79a497324ef937, 0x157d35835ab76872, ]) }, kind: TileKind::Pin, ord: 9 },
-    BaseTile { idx: 72, point: Point { x: Fr::from_raw([0x9187a2e38105c11b, 0xe5aa821e639ce098, 0xff73af8fa1bdf624, 0x261e7f49d9664c76, ]), y: Fr::from_raw([0xa29d169c05361929, 0xc2d5b39930c88283, 0x59538b6a22bd0029, 0x08fc232ebea326fa, ]) }, kind: TileKind::Sou, ord: 1 },
-    BaseTile { idx: 73, point: Point { x: Fr::from_raw([0x926a1c8b6a5f0b22, 0x83e16044f11cbf68, 0x573d1872c94dd605, 0x0c52f37912a27f2f, ]), y: Fr::from_raw([0xadc662a816a4404a, 0x4e043cd69a125876, 0xa43f6e91ef559ece, 0x0ba6264343bfde06, ]) }, kind: TileKind::Sou, ord: 1 },
-    BaseTile { idx: 74, point: Point { x: Fr::from_raw([0x0bcba378ff523e0d, 0x63d105118c1f6baf, 0x12d4b54cdf46976a, 0x2b7fa257c1093d80, ]), y: Fr::from_raw([0xc076c04cb2204e39, 0x1a96e3035118b583, 0xf6c358efdb9a7740, 0x1362b72be3b6433e, ]) }, kind: TileKind::Sou, ord: 1 },
-    BaseTile { idx: 75, point: Point { x: Fr::from_raw([0xa3b45f4fcf2b3194, 0xde3cab34e48d5d94, 0x5e138bc1fd94c673, 0x24d341cb76acca75, ]), y: Fr::from_raw([0x15c48d3a8476bc40, 0x49af4d90376f049a, 0xd7ceab8ffbc2825a, 0x0b55d9984a88ccf8, ]) }, kind: TileKind::Sou, ord: 1 },
-    BaseTile { idx: 76, point: Point { x: Fr::from_raw([0x5ea5e74eaa164ef5, 0xe573e2fdf70f05bc, 0xfc7871d5c541b030, 0x0a19fa2502b904f1, ]), y: Fr::from_raw([0x72af3978b717c2b0, 0x3d8dc89088d27e23, 0x74ee6fc6761e355a, 0x2c1d19b55652d87c, ]) }, kind: TileKind::Sou, ord: 2 },
-    BaseTile { idx: 77, point: Point { x: Fr::from_raw([0x0ce24a63fc91a57e, 0xcd4f4846b655deb3, 0x4467c72aa0c08588, 0x0d29fa27e651c31c, ]), y: Fr::from_raw([0xd016950ee8b965c5, 0x3762de5c70112dd9, 0x843b1078a489ae4d, 0x1aeb6a63b19e48dc, ]) }, kind: TileKind::Sou, ord: 2 },
-    BaseTile { idx: 78, point: Point { x: Fr::from_raw([0x1be74f494b566628, 0x9cd45ccbed725419, 0xa710aac316b75611, 0x26dbc72e43fb76d4, ]), y: Fr::from_raw([0x22cb585b476e40dd, 0x93d0be7cb6992b1f, 0x9d87f13f5ffa8c4d, 0x002d6b7bfa09263a, ]) }, kind: TileKind::Sou, ord: 2 },
-    BaseTile { idx: 79, point: Point { x: Fr::from_raw([0x985765bb2c762ca8, 0xaa3e189f693bcdf2, 0xdb86333145103b92, 0x267602dd5f8e5827, ]), y: Fr::from_raw([0xa93c345736247d95, 0x70d8176c58e78fd9, 0x97e948f0bf0bb95e, 0x2046c4c25a514796, ]) }, kind: TileKind::Sou, ord: 2 },
-    BaseTile { idx: 80, point: Point { x: Fr::from_raw([0xfa75e05a1b0dd2d8, 0xc6d3e0b0ac432e44, 0x25a2aa4238905c68, 0x29babe8305016609, ]), y: Fr::from_raw([0xa4f927ccd92d2ab7, 0xc1923ba024b70aa2, 0xeda3a2a409ce2bbe, 0x2d6b9adb741540c8, ]) }, kind: TileKind::Sou, ord: 3 },
-    BaseTile { idx: 81, point: Point { x: Fr::from_raw([0x6dfa4f405f2e4270, 0x6f94825a80bb1a29, 0x0414d2f91dd61a00, 0x2d944eae56f6660d, ]), y: Fr::from_raw([0xe113657a66d1fb34, 0xedb4d2b77eed77be, 0xee86692b35afb9fe, 0x113ec7049cd2047b, ]) }, kind: TileKind::Sou, ord: 3 },
-    BaseTile { idx: 82, point: Point { x: Fr::from_raw([0x40d61c469fe5dd81, 0xf9e15dd5f1aaba47, 0x1628dfd8b2bb56f4, 0x0a6571b1e30c179c, ]), y: Fr::from_raw([0x41f2ce144d6923fc, 0x7bce5c0bfc96f941, 0x753dbc34586ebf86, 0x2d33c02cf6869b5e, ]) }, kind: TileKind::Sou, ord: 3 },
-    BaseTile { idx: 83, point: Point { x: Fr::from_raw([0x7ed266dbf03f0c94, 0x1eb4a41743f84775, 0x9322b29de95cf7a4, 0x20c927370c4951e0, ]), y: Fr::from_raw([0x1eb2807ef7ac8dda, 0xe0f8637bc9572f48, 0x3496738ac600c0e4, 0x21a39e065e22c2a4, ]) }, kind: TileKind::Sou, ord: 3 },
-    BaseTile { idx: 84, point: Point { x: Fr::from_raw([0x868a9ba87863dc32, 0xd4c4a32fc2483f98, 0x4a8f6fbacb776ee7, 0x0a83b725d8416819, ]), y: Fr::from_raw([0xaf4cc09a3f53f5b8, 0x08f254b376535302, 0xde3604a979261673, 0x159ce9bfe3dea135, ]) }, kind: TileKind::Sou, ord: 4 },
-    BaseTile { idx: 85, point: Point { x: Fr::from_raw([0xd6ea714e7597eea9, 0xc04dcbb6089e88a0, 0x18ed0eb34dde7e9f, 0x25955dcbc5e0813b, ]), y: Fr::from_raw([0x0a3c8209477246d3, 0x3b63dd79317e3edc, 0xc4bef84e42b4900b, 0x079b48412fa8607d, ]) }, kind: TileKind::Sou, ord: 4 },
-    BaseTile { idx: 86, point: Point { x: Fr::from_raw([0xecf138fa21dd8fec, 0xbecc4abcccb9fea3, 0x7495bd95e492e0c8, 0x23120a34309290e8, ]), y: Fr::from_raw([0x65eb38615edc986b, 0xb3885f07a9d9edb4, 0x0f33d484b0c9e879, 0x2cf082185416c59e, ]) }, kind: TileKind::Sou, ord: 4 },
-    BaseTile { idx: 87, point: Point { x: Fr::from_raw([0x9b1949743715352b, 0x624d90268bf0fa1c, 0x3db562cf6e995fbc, 0x16c9957d5e54292c, ]), y: Fr::from_raw([0xeb8b5f2ec758d20c, 0xf7b3998d697f78e9, 0x547b05758b2290a4, 0x274d1fdc83ca17a1, ]) }, kind: TileKind::Sou, ord: 4 },
-    BaseTile { idx: 88, point: Point { x: Fr::from_raw([0x3c6d9fefb5ff031a, 0x48f0adfbbfe48d06, 0x7b6430a7128dd001, 0x0a22bc516ec03022, ]), y: Fr::from_raw([0x6cec3c25dd86897c, 0x1875a961813e50b3, 0x4508f168c903a815, 0x16ee816493dad1a1, ]) }, kind: TileKind::Sou, ord: 5 },
-    BaseTile { idx: 89, point: Point { x: Fr::from_raw([0x6e55a2f9dd14c3b1, 0x14013ecbe15bddf4, 0x5f020d70122ba839, 0x2c7abdf88a8623b2, ]), y: Fr::from_raw([0xd604b635dfdd9367, 0xf5a7d48fe3e60380, 0x93b0716253ebd619, 0x09a966d11376e89a, ]) }, kind: TileKind::Sou, ord: 5 },
-    BaseTile { idx: 90, point: Point { x: Fr::from_raw([0x86bbd53c1fd2d5b0, 0x2b3cef6c352aa0b5, 0x032fd133222cbbc3, 0x04a62cf9c1092c46, ]), y: Fr::from_raw([0x3c5adb73ee44c2db, 0xd52cbc5f01ab3325, 0x2d68e8569a78110e, 0x1072d53383c558d7, ]) }, kind: TileKind::Sou, ord: 5 },
-    BaseTile { idx: 91, point: Point { x: Fr::from_raw([0x95c958f734d6fc76, 0x60753a038204d48e, 0xf7dca4f59a3ab695, 0x2476590a89b894e2, ]), y: Fr::from_raw([0x71f753bce684fafc, 0xdfc4543de52da9b2, 0xd79435271f5dd02d, 0x1b5858d524d520c3, ]) }, kind: TileKind::Sou, ord: 5 },
-    BaseTile { idx: 92, point: Point { x: Fr::from_raw([0xcdf843780147614f, 0x911a7161b5be9bd3, 0xcee5fe94ba846f15, 0x26bf6154b8a49c1c, ]), y: Fr::from_raw([0xf034981155346517, 0xc6e2b703a4f0d12a, 0xe4d4e217bb91c3f6, 0x05e6b2492d526a66, ]) }, kind: TileKind::Sou, ord: 6 },
-    BaseTile { idx: 93, point: Point { x: Fr::from_raw([0x4c020a25698c676e, 0x2a543f4a70671e33, 0xde7003783654231b, 0x08066aed6c7cb571, ]), y: Fr::from_raw([0x549e85403c8e7a43, 0x033f6b8d1e2a2587, 0x33a7967009c7a658, 0x263d0e3d1bf3cffc, ]) }, kind: TileKind::Sou, ord: 6 },
-    BaseTile { idx: 94, point: Point { x: Fr::from_raw([0xa3c21547367872fe, 0xc96e9edb6432534f, 0x938f24face64b4de, 0x0d659f2dc538926c, ]), y: Fr::from_raw([0x102bd187ebc55917, 0x52d580b5fa274e9c, 0x1ac63ba80b03ae9d, 0x1858772484512c62, ]) }, kind: TileKind::Sou, ord: 6 },
-    BaseTile { idx: 95, point: Point { x: Fr::from_raw([0xed532dcae56dea00, 0x7baf861f9c0a2dc0, 0x31b732d788da296f, 0x03e98eeeabfb73a4, ]), y: Fr::from_raw([0x764d81cffb1bc897, 0xbb5ff6cd71f186d4, 0x4f5d2fe19f853b72, 0x054bc61e3fd2494e, ]) }, kind: TileKind::Sou, ord: 6 },
-    BaseTile { idx: 96, point: Point { x: Fr::from_raw([0x262574f89f3fc7fd, 0x1aa08dac588d3aa3, 0x940fb69583ab230a, 0x073176786ba9207c, ]), y: Fr::from_raw([0x4111f716f35e0b2e, 0x2bb26d93dc8ec579, 0x19c5357625847540, 0x06426f46756a160f, ]) }, kind: TileKind::Sou, ord: 7 },
-    BaseTile { idx: 97, point: Point { x: Fr::from_raw([0xa6fa84b5b4bce648, 0x2ea475cf242c4296, 0xd2762365ee9f1337, 0x21ae13b6d1e7e1b7, ]), y: Fr::from_raw([0xb0df464a47087cd8, 0x44bc3cb40e7cc195, 0x04827716b8aa5a3b, 0x17fbd5532707c5ac, ]) }, kind: TileKind::Sou, ord: 7 },
-    BaseTile { idx: 98, point: Point { x: Fr::from_raw([0x56945a014e84d8d8, 0xaf50e884e24f954d, 0xd05c307f994395f7, 0x0a90989cacd9a64b, ]), y: Fr::from_raw([0x99d0e2b5ad4d4cc5, 0x33996a6c6691ff39, 0x049038c1068909de, 0x0b84a6247a13c1dc, ]) }, kind: TileKind::Sou, ord: 7 },
-    BaseTile { idx: 99, point: Point { x: Fr::from_raw([0xfc43813be64eb779, 0x4809684a37304f58, 0xd541f8e349def5fb, 0x2450b03c252e6b23, ]), y: Fr::from_raw([0x4255edfc410ba877, 0x34232d1a60f0875b, 0xe43f7d8ca65d3972, 0x0e2bb9f1fd79ea69, ]) }, kind: TileKind::Sou, ord: 7 },
-    BaseTile { idx: 100, point: Point { x: Fr::from_raw([0x1e1be922dc7d3b4b, 0x55147692dea7f804, 0x79505eb5b40aad92, 0x2d1049689ac6a661, ]), y: Fr::from_raw([0xfd0a84a04b0ec2b0, 0x419e8920c0e48ecf, 0x8add033bf60837a9, 0x16693e105a0df98b, ]) }, kind: TileKind::Sou, ord: 8 },
-    BaseTile { idx: 101, point: Point { x: Fr::from_raw([0x5b61347de0c1e905, 0x2aca695a7d8e23f4, 0x14b56f8cafad3b25, 0x0548cf55387fd205, ]), y: Fr::from_raw([0xb6e61b315ff8da28, 0xc70b55d3d6b93e08, 0x4e516af393400ba4, 0x16ffbfe4fa163b5b, ]) }, kind: TileKind::Sou, ord: 8 },
-    BaseTile { idx: 102, point: Point { x: Fr::from_raw([0x5bd738994561a09b, 0xc7536c6d5aa4db48, 0xf95b29d2593d47a3, 0x1a59f913b01b78ac, ]), y: Fr::from_raw([0x2342dba356197826, 0xf328b5d48194f704, 0x1f3bb7b4888d80e3, 0x2b1719c5b934db53, ]) }, kind: TileKind::Sou, ord: 8 },
-    BaseTile { idx: 103, point: Point { x: Fr::from_raw([0x16518af3e239a4c7, 0x63728d1c3245d8a7, 0xa23561a4154b2ea0, 0x0d1e37ef618e996c, ]), y: Fr::from_raw([0xd43dc0eb22fd6028, 0x263b9183baa250ba, 0x98b5012aede7709a, 0x0e79326257dd04f0, ]) }, kind: TileKind::Sou, ord: 8 },
-    BaseTile { idx: 104, point: Point { x: Fr::from_raw([0x78309969be5f3d2a, 0x8d73960c25c39617, 0x17a4fbd48080fef3, 0x2f02ba059b030191, ]), y: Fr::from_raw([0xaacc5b2e7857b661, 0x5e13831c22f5660b, 0x71e97cabef7d9505, 0x0e81ff40e57c4e62, ]) }, kind: TileKind::Sou, ord: 9 },
-    BaseTile { idx: 105, point: Point { x: Fr::from_raw([0xc77dfcbdca93be7c, 0xa0a161f44db6704c, 0xf160aac81d25e589, 0x14f573055bbb5d15, ]), y: Fr::from_raw([0x653cd421e96538d9, 0xf438460351b1a8e2, 0x43212bd8806c7e95, 0x2642641bf28c93cb, ]) }, kind: TileKind::Sou, ord: 9 },
-    BaseTile { idx: 106, point: Point { x: Fr::from_raw([0x0e9c4010d4c1b60e, 0x4d0625b0d26d5021, 0x84635a97c3f8679e, 0x112157ee2ba6a740, ]), y: Fr::from_raw([0x4ad722d90fc114ec, 0xc0c7d8931fbfb3eb, 0x0ed509948d3e8fe2, 0x12f4212ddd9438df, ]) }, kind: TileKind::Sou, ord: 9 },
-    BaseTile { idx: 107, point: Point { x: Fr::from_raw([0x66dd15995a65fccd, 0x09c220bef9e5eba1, 0xd141229d9e1f20c7, 0x08895b893f1a328a, ]), y: Fr::from_raw([0xedbfbf762ad43709, 0xed4f57345a505faf, 0xe135e7049621c1f0, 0x120336bdaa688016, ]) }, kind: TileKind::Sou, ord: 9 },
-    BaseTile { idx: 108, point: Point { x: Fr::from_raw([0xad75309b43218f64, 0x7a076561bdb9cf73, 0x5be5c021516d0ab6, 0x174c70f0a8b41db9, ]), y: Fr::from_raw([0xb746c113b308a4f8, 0xf75418d8d387fc47, 0x2e6d09c4b225f508, 0x2ce69f51714ae5c7, ]) }, kind: TileKind::Zi, ord: 1 },
-    BaseTile { idx: 109, point: Point { x: Fr::from_raw([0x870fcb721d4f155f, 0xb75e3a91b6f80d72, 0x7bb3f2c21258c733, 0x2c54ee977596df03, ]), y: Fr::from_raw([0xa48a08b45e547378, 0x748ba117f110f08f, 0x9441d49f7ed4182c, 0x121efdad98a90f01, ]) }, kind: TileKind::Zi, ord: 1 },
-    BaseTile { idx: 110, point: Point { x: Fr::from_raw([0x832f0046dac848bc, 0x5f9bf68004e0a13d, 0x98bee3605e9059b4, 0x0cea5efb39620b47, ]), y: Fr::from_raw([0xad9b2b97bc249464, 0x809d31e2c4798894, 0xfdc9e94387e0ad9a, 0x0be4dcdf26607b26, ]) }, kind: TileKind::Zi, ord: 1 },
-    BaseTile { idx: 111, point: Point { x: Fr::from_raw([0x6666fa38960de868, 0x18dccf1491320072, 0x4c51ee4932f2043d, 0x1466b81ee40fc45a, ]), y: Fr::from_raw([0x64f00347c2ee090c, 0x2edc3f13e0ce0ed3, 0x61ee0d94ee9a8d96, 0x221ba366258b3081, ]) }, kind: TileKind::Zi, ord: 1 },
-    BaseTile { idx: 112, point: Point { x: Fr::from_raw([0x1c110e3d61e69a2f, 0xbcae074f96427c5d, 0xb96a79a29b96541e, 0x12ee8b7b73f64878, ]), y: Fr::from_raw([0x73c9bc76f1a26c42, 0x20ef9b72bb174aa7, 0x4a2e7989669c1f5c, 0x1f3544c99ef1bbdc, ]) }, kind: TileKind::Zi, ord: 2 },
-    BaseTile { idx: 113, point: Point { x: Fr::from_raw([0xe6b549483da6805c, 0x8a7be3181358534b, 0x91f2e06d705788a3, 0x0730a48e7340e6d4, ]), y: Fr::from_raw([0x238d6c5b65c4fcd5, 0x2a68e3cf7cbabe59, 0x9c3d7a7d31772aca, 0x1c7e10adf32e0a56, ]) }, kind: TileKind::Zi, ord: 2 },
-    BaseTile { idx: 114, point: Point { x: Fr::from_raw([0x820d6d4fcaee61fa, 0x6089d6feeaffc6f9, 0xbead631f3834f0e1, 0x008d4c0040a8f508, ]), y: Fr::from_raw([0x617209fc770b6199, 0xd2cddfd1581faef0, 0x5e7b03c789ba7080, 0x1d58c8a7f55ead15, ]) }, kind: TileKind::Zi, ord: 2 },
-    BaseTile { idx: 115, point: Point { x: Fr::from_raw([0xba5b493abe9e90f3, 0xa9cbf76c29e9c715, 0x3d33aaf6128647a6, 0x0bfb403fa2fdf4f0, ]), y: Fr::from_raw([0x08a3332cd35876e9, 0x39e1fc414aaa9158, 0xd5289d3802448a3c, 0x0866f7fecce22a56, ]) }, kind: TileKind::Zi, ord: 2 },
-    BaseTile { idx: 116, point: Point { x: Fr::from_raw([0x6a8fe8f99a47800d, 0x116fe32533cd4c80, 0xb6e2a01b313b1ad6, 0x01492dece4fec044, ]), y: Fr::from_raw([0x12b3bcc66c16e510, 0x87aabf1030eada43, 0x37bc2ce499b98170, 0x09e0cb3a63ba0f11, ]) }, kind: TileKind::Zi, ord: 3 },
-    BaseTile { idx: 117, point: Point { x: Fr::from_raw([0x01477589d9838ccf, 0x5bcd58a2ca1a1f10, 0xe1123ae2895304f1, 0x21610ef559be1f07, ]), y: Fr::from_raw([0xde31482c0f16bd1b, 0x0f562147b6de3ee8, 0xebdef8a9005d595b, 0x1d8ab2ae32392db1, ]) }, kind: TileKind::Zi, ord: 3 },
-    BaseTile { idx: 118, point: Point { x: Fr::from_raw([0x537b35efdd9e2108, 0x26d52e7f28e8ef65, 0xa7607e1c06e16851, 0x22a57bafaccc2d31, ]), y: Fr::from_raw([0x20bf7464614aabec, 0xb00bb42e3f6a29e9, 0x7cd3776237eb56db, 0x19fdadb5adda6449, ]) }, kind: TileKind::Zi, ord: 3 },
-    BaseTile { idx: 119, point: Point { x: Fr::from_raw([0x095ecba9efa00076, 0x2831e257cd9c6d42, 0x825b04f5a4ac0528, 0x1d6e53c114a14634, ]), y: Fr::from_raw([0xfe98befc2cf9d05d, 0x5221030a399c2a87, 0x3d5e3e9c1ffde9ba, 0x21b9c6e0ddae2a2e, ]) }, kind: TileKind::Zi, ord: 3 },
-    BaseTile { idx: 120, point: Point { x: Fr::from_raw([0x9d365df4708ec516, 0xae1cda9867d71eed, 0x00f2d1de32b021ed, 0x159fd51ffde541a0, ]), y: Fr::from_raw([0x4477be945cac93e8, 0x755091df5f5c09dd, 0xe8ce445dbf50f922, 0x25fd611a01fb6e16, ]) }, kind: TileKind::Zi, ord: 4 },
-    BaseTile { idx: 121, point: Point { x: Fr::from_raw([0xf3f9d69fff89caba, 0xaeba731a86cb0f25, 0xffce36a6872c124a, 0x00cf789fa5cbb61f, ]), y: Fr::from_raw([0x6cee62a23e02dc03, 0x6724568b97f95de9, 0xd6a970c971d22799, 0x1e736eec97d5c537, ]) }, kind: TileKind::Zi, ord: 4 },
-    BaseTile { idx: 122, point: Point { x: Fr::from_raw([0x6eb30f22b5d40239, 0x70a049ca9001f1da, 0x7628a59d4afcb550, 0x10cec1a80ca177ce, ]), y: Fr::from_raw([0xd4722c213497ce61, 0x09085cdf3e33f1c3, 0x3402ac74b4e0c652, 0x28afeca6185ab202, ]) }, kind: TileKind::Zi, ord: 4 },
-    BaseTile { idx: 123, point: Point { x: Fr::from_raw([0x05425120d5249c06, 0x59ac2719588a541a, 0xafff0a00d92f2449, 0x235486f9e39ddf8a, ]), y: Fr::from_raw([0x2261cad4bd09827e, 0x820431ba06273058, 0xa079605452309b74, 0x2437ec73ee862bb8, ]) }, kind: TileKind::Zi, ord: 4 },
-    BaseTile { idx: 124, point: Point { x: Fr::from_raw([0x67473d686a999835, 0x34233e193007326b, 0xa5090d5397bc6343, 0x1d1fa84727731c3b, ]), y: Fr::from_raw([0x35c70fec9ec4b64c, 0x09d96d4060e7030e, 0x4184ebb40eb176b5, 0x2bfa400dfda52a9d, ]) }, kind: TileKind::Zi, ord: 5 },
-    BaseTile { idx: 125, point: Point { x: Fr::from_raw([0xa9f7144936d7b694, 0x20e97788f8ecf16c, 0x2f758ffc56225ddb, 0x18fe83fa609ea21d, ]), y: Fr::from_raw([0xb9ed105a7fecfc1c, 0xc0eae4da146fddc6, 0x89fbed6b2307b7d7, 0x0bfcb5fa527c7950, ]) }, kind: TileKind::Zi, ord: 5 },
-    BaseTile { idx: 126, point: Point { x: Fr::from_raw([0x5719963e798c7d40, 0xc086f103b66a68ef, 0xf702bc963dc85890, 0x2b0f7d4f68c9b73b, ]), y: Fr::from_raw([0x8c9766330a93d3f4, 0x8f21450b9899380f, 0xd91718a008d80c44, 0x1f899a5511d47802, ]) }, kind: TileKind::Zi, ord: 5 },
-    BaseTile { idx: 127, point: Point { x: Fr::from_raw([0x501c8e1b33378025, 0x250f01183cd4b7a6, 0x615510a1671266be, 0x1d8040ccd51adea7, ]), y: Fr::from_raw([0xca8f32b0accab904, 0x7b332e492417c44e, 0xa1851272bb974c0b, 0x13e42bbcf7d088f7, ]) }, kind: TileKind::Zi, ord: 5 },
-    BaseTile { idx: 128, point: Point { x: Fr::from_raw([0xd61de654d8891d0a, 0x16350b1c0a9c2d3d, 0x0be8b70aab30dacd, 0x2612433bc21486aa, ]), y: Fr::from_raw([0x0a66bd8b522f1377, 0xb677560aaf30bb6b, 0x2558f7dc1fcef78b, 0x296b9184830d9040, ]) }, kind: TileKind::Zi, ord: 6 },
-    BaseTile { idx: 129, point: Point { x: Fr::from_raw([0xbcdd020d649744cc, 0x29040a4553fbbdea, 0x4654700b3a929140, 0x2144b5878f73b8e2, ]), y: Fr::from_raw([0x6b6e295d61b5492f, 0x058086a985cb306b, 0x43057f0aedc35d9b, 0x2386d38564e093cf, ]) }, kind: TileKind::Zi, ord: 6 },
-    BaseTile { idx: 130, point: Point { x: Fr::from_raw([0x3a1bb47904550e1e, 0x5f9532e53bba1b75, 0xe9118b356d839e62, 0x2c0987ae3ad7116f, ]), y: Fr::from_raw([0xb7b0683812bb08ab, 0x35fb4cd427c5f183, 0x7eab6f9baa9d0943, 0x1def133efc3ed3b6, ]) }, kind: TileKind::Zi, ord: 6 },
-    BaseTile { idx: 131, point: Point { x: Fr::from_raw([0x15cfb9435edb8775, 0xe22c1db7219f8b70, 0x711c76c79f7f5f52, 0x2cdc1e0db4bcc78c, ]), y: Fr::from_raw([0x4085a1384be100a2, 0x093a012e1f11e32f, 0x003d269290bbd7ed, 0x1e2abbf9249a1b7e, ]) }, kind: TileKind::Zi, ord: 6 },
-    BaseTile { idx: 132, point: Point { x: Fr::from_raw([0x689eb97d983b75a4, 0xba3dbfaaf169c8ef, 0xcbde2ae195beb60f, 0x082e69884b96575d, ]), y: Fr::from_raw([0x71ac1bdba656f7b6, 0xc32e2a0e5f26d028, 0xb00695ea98b1bced, 0x11962211c4fb0617, ]) }, kind: TileKind::Zi, ord: 7 },
-    BaseTile { idx: 133, point: Point { x: Fr::from_raw([0xc43c394741f94ce4, 0x7b863be3eec2be5d, 0x1fc4b1a7958359e7, 0x24cda411a9f77955, ]), y: Fr::from_raw([0xaa2a48570ba61531, 0xaf3f939d708a4226, 0x9d2002cc3b683112, 0x140955105e9ff505, ]) }, kind: TileKind::Zi, ord: 7 },
-    BaseTile { idx: 134, point: Point { x: Fr::from_raw([0xf083e668bf1761a3, 0xa68df3a142e17da0, 0xa4e6a8865950976a, 0x14811fb412e7d2de, ]), y: Fr::from_raw([0x15a9398c325a5781, 0xab0fee53a8c39183, 0x0940e7075da04cc4, 0x13d014702480708f, ]) }, kind: TileKind::Zi, ord: 7 },
-    BaseTile { idx: 135, point: Point { x: Fr::from_raw([0x341d7dcf481e4e7c, 0x72749b4b7ccdae98, 0x68011ff0a236b48d, 0x10e70e0c81f7838b, ]), y: Fr::from_raw([0xabe1627a21373fa4, 0x69b06d061990ef02, 0x4e1f5b2ae6740833, 0x114b8fa8945becf0, ]) }, kind: TileKind::Zi, ord: 7 },
-    BaseTile { idx: 136, point: Point { x: Fr::from_raw([0x41eae970c09d11f8, 0x7e54877ab4a36e77, 0x0dff86b83f67f431, 0x22ae3d9205aeec79, ]), y: Fr::from_raw([0xc636140b9de6e12e, 0x8f7071741b45633b, 0xeae24405b8d635e8, 0x20d618b701e6cccd, ]) }, kind: TileKind::Fa, ord: 1 },
-    BaseTile { idx: 137, point: Point { x: Fr::from_raw([0x0f06d99cbd9ef23e, 0x6104f3ec41ccb1fa, 0xd51a8108f02d5948, 0x0b393f39061312b6, ]), y: Fr::from_raw([0x03ee9b7f70f40f66, 0x1e8e90783c2d1736, 0xa0d5b0929e1aff10, 0x263630fc48a0d4dc, ]) }, kind: TileKind::Fa, ord: 2 },
-    BaseTile { idx: 138, point: Point { x: Fr::from_raw([0x7c5a2e9ac3584563, 0x7dbc3f4d11e9f97c, 0x40ab685f003f4f47, 0x2cfe140f041e7d98, ]), y: Fr::from_raw([0xef9b35983a8a908c, 0x43ac3f010992e921, 0xd5262a7e2a008d2d, 0x26416cb333a9862e, ]) }, kind: TileKind::Fa, ord: 3 },
-    BaseTile { idx: 139, point: Point { x: Fr::from_raw([0xf13d82272d044ed4, 0x8893d39c2dd19c41, 0xbb64354bf087dc05, 0x07d9c881b88d83a2, ]), y: Fr::from_raw([0x3e9703026714c3c2, 0xa62d0614bafa4482, 0x58c934578a28ab5b, 0x288c9a6ac4322e99, ]) }, kind: TileKind::Fa, ord: 4 },
-    BaseTile { idx: 140, point: Point { x: Fr::from_raw([0x81de9dbb48667004, 0xd0ec5223009e0799, 0x3b40c8206c8825c6, 0x11f0fcdf5b8cc321, ]), y: Fr::from_raw([0x9245743fac8244fe, 0x0df6587601c32760, 0xfd4b497e4d25cd84, 0x27cc72e4f433bfc0, ]) }, kind: TileKind::Fa, ord: 5 },
-    BaseTile { idx: 141, point: Point { x: Fr::from_raw([0xa0cd4c6748304141, 0x0fdc2fb7269824d8, 0x0bdaaa2bb3cd2187, 0x1ac093601d19e2c1, ]), y: Fr::from_raw([0x4b956a280c55c7bc, 0xb66093eb1e1a9f7c, 0xeb895047318eccda, 0x050607a482d9384b, ]) }, kind: TileKind::Fa, ord: 6 },
-    BaseTile { idx: 142, point: Point { x: Fr::from_raw([0xb45dfa4a322eba47, 0x29dbd7168d1711ce, 0x4d0bd0478bbbc263, 0x135ee181958de41b, ]), y: Fr::from_raw([0x5c577909a71696b1, 0xe40afbbc3b665b4c, 0xe0c6dea123541271, 0x2576f2efb4543db1, ]) }, kind: TileKind::Fa, ord: 7 },
-    BaseTile { idx: 143, point: Point { x: Fr::from_raw([0xebf81910e59530fc, 0x85bb25b3559cc779, 0x70f3754e75b9239d, 0x1b03bc878ac6d090, ]), y: Fr::from_raw([0xa05c5f9c0dc8e077, 0xa1feeb0462e99188, 0xe1f72509498c12c1, 0x0bfeaa533962caf4, ]) }, kind: TileKind::Fa, ord: 8 },
+    BaseTile { idx: 0, point: Point { x: Fr::from_raw([0x0a8fc7bc1a89fa86, 0xa7d9d786e9e48627, 0xee6158b465bea369, 0x14a0ff6d2f874519, ]), y: Fr::from_raw([0xb83342d20d0201aa, 0x2ffef2f7cdcfeac7, 0xbfa79a9425a6e625, 0x0dfb859dc3a44b70, ]) }, kind: TileKind::Man, ord: 1, red: false },
+    BaseTile { idx: 1, point: Point { x: Fr::from_raw([0xee997be0bc98a0a6, 0x217f0d6e0991d346, 0x05eca11c22133ac3, 0x0bd6d33b661a263b, ]), y: Fr::from_raw([0xef5e76322749533e, 0x5e5647daec7375f1, 0x0901ac29597a2def, 0x0b715a7fbd840830, ]) }, kind: TileKind::Man, ord: 1, red: false },
+    BaseTile { idx: 2, point: Point { x: Fr::from_raw([0xd5cbcc5718f8e86f, 0x2963a270b38e60ba, 0x04fc18704692faf8, 0x1cbb2849dbf79a70, ]), y: Fr::from_raw([0xbb41c8702d2790be, 0x139b7576ad0f61db, 0x930b9fe37fbb42ee, 0x052fe2e89b47b2fc, ]) }, kind: TileKind::Man, ord: 1, red: false },
+    BaseTile { idx: 3, point: Point { x: Fr::from_raw([0x215a431e9077fe84, 0x6790672a335063eb, 0x07495bef4ab884f0, 0x13456903f5712721, ]), y: Fr::from_raw([0xa766f89e7c0da8aa, 0x6c9f989125ca5c72, 0xd479bc5889b69dd7, 0x159efa9468d1465a, ]) }, kind: TileKind::Man, ord: 1, red: false },
+    BaseTile { idx: 4, point: Point { x: Fr::from_raw([0xc8184e50277ac74a, 0x5b2505922b62927d, 0xaab207b9bc835b3a, 0x2506051ff27b4c19, ]), y: Fr::from_raw([0x4729225f6c684fd5, 0xb28d7a69f63b8435, 0x6b38e0452656a971, 0x086e71ecbdba02b3, ]) }, kind: TileKind::Man, ord: 2, red: false },
+    BaseTile { idx: 5, point: Point { x: Fr::from_raw([0x9d07f32b65343ce6, 0x1e63022b398dd2ab, 0x5fe5c3da4c17ef34, 0x212e8697d990ede2, ]), y: Fr::from_raw([0x39a5106d84b3e0e7, 0x7b6e50f18e02204c, 0x3f10f71e1a113af0, 0x2379320420b29b90, ]) }, kind: TileKind::Man, ord: 2, red: false },
+    BaseTile { idx: 6, point: Point { x: Fr::from_raw([0x8c4feef6f3f64f5b, 0x708a47c90f0d513b, 0x23c6b8e7792ffc30, 0x01fc1ff78b9bac81, ]), y: Fr::from_raw([0x71fbf02767a855ab, 0xa073d69073e88aed, 0xc7af6ca281a02878, 0x21d65f60940eb189, ]) }, kind: TileKind::Man, ord: 2, red: false },
+    BaseTile { idx: 7, point: Point { x: Fr::from_raw([0x8f4194eab440fda2, 0x701bbdc7fa69ceed, 0x69b9ee0231d35ac5, 0x2f26ed4c985d5817, ]), y: Fr::from_raw([0xef8ed8d8ac04f79b, 0x738bb35b7d337db2, 0x94618d123ca5c43d, 0x2cbef600ab75c562, ]) }, kind: TileKind::Man, ord: 2, red: false },
+    BaseTile { idx: 8, point: Point { x: Fr::from_raw([0x46219553525b1ee8, 0x030126b0ab5c8694, 0x91c44cd7bbf5668f, 0x262f5192436032fa, ]), y: Fr::from_raw([0xf772d00857c4fb0e, 0xe3619385c46b40db, 0x5d3587911d6b45b7, 0x2e0f0dbf77a62f15, ]) }, kind: TileKind::Man, ord: 3, red: false },
+    BaseTile { idx: 9, point: Point { x: Fr::from_raw([0x7af5a93f6d511002, 0x758bc7ed53e668b9, 0xea9efbbd6cca4b99, 0x1fa8dc0f6e2e5021, ]), y: Fr::from_raw([0x04865621c06a5b98, 0xeeb4b177a8ab867b, 0x41e81ef6200b34da, 0x1be90a4be87b69d0, ]) }, kind: TileKind::Man, ord: 3, red: false },
+    BaseTile { idx: 10, point: Point { x: Fr::from_raw([0x08f63e3d86a4c409, 0x9388ec511d0358c6, 0x62a05e3ecefbce76, 0x19b19413ff7e596d, ]), y: Fr::from_raw([0x4cfda432a16a87c7, 0xc93bcd48136bfcfe, 0x5bf41b7c7d2fcbff, 0x078ee57d87c2d878, ]) }, kind: TileKind::Man, ord: 3, red: false },
+    BaseTile { idx: 11, point: Point { x: Fr::from_raw([0x7d97baad8a0413a7, 0x3c5361f65db0b00f, 0x0e98081d1f787162, 0x213a0d3e4cff6e91, ]), y: Fr::from_raw([0xc6a4fe4ffff84609, 0x7e7f4b906922c4a7, 0x2ad8c9f06079cd6e, 0x1938e7debc36016b, ]) }, kind: TileKind::Man, ord: 3, red: false },
+    BaseTile { idx: 12, point: Point { x: Fr::from_raw([0x5375bf0b3c46c12d, 0x991e6f5fde2311e0, 0x99ac6fca52c722f5, 0x2fd1d9fdbe5825d2, ]), y: Fr::from_raw([0x31c5834728af47e4, 0xf5124cb017e0a030, 0xec2cfb81f9367002, 0x2508d7a4e224b18a, ]) }, kind: TileKind::Man, ord: 4, red: false },
+    BaseTile { idx: 13, point: Point { x: Fr::from_raw([0x671eb14e5ba626f3, 0xf47136a65be90b26, 0xb5ebf0710c65ac4a, 0x243cdf31d1f51a95, ]), y: Fr::from_raw([0xa2a34f15f5a290f3, 0x9431d0c372818efe, 0xf418117ff3e1e9cd, 0x1705c0c98a510818, ]) }, kind: TileKind::Man, ord: 4, red: false },
+    BaseTile { idx: 14, point: Point { x: Fr::from_raw([0xd50bdb30a9d8a106, 0x4fe98341e3304178, 0x2a6a171a85a58d12, 0x22027d587dc5f7ce, ]), y: Fr::from_raw([0xefee81995fb3fdb2, 0x38aa4cf85fd960dd, 0xd63c51fcd47ca02a, 0x29f7cca044657426, ]) }, kind: TileKind::Man, ord: 4, red: false },
+    BaseTile { idx: 15, point: Point { x: Fr::from_raw([0x6ace774835df2902, 0x01caf0ad4655e98e, 0x43b643c2f926208d, 0x2d60507cbcc3a15b, ]), y: Fr::from_raw([0xdaab3713d1a78f09, 0x8ea4a675213e2f03, 0xa624feaf4a608d00, 0x2fd947a6984f6e00, ]) }, kind: TileKind::Man, ord: 4, red: false },
+    BaseTile { idx: 16, point: Point { x: Fr::from_raw([0x6b044d4087d92e11, 0x9775eb155e57120b, 0x20891ca57d948706, 0x29f404584277bd1d, ]), y: Fr::from_raw([0xfe184eea19703b35, 0x059ebf25b57c4b9a, 0x75869860f5327a58, 0x06b3c51b5d262159, ]) }, kind: TileKind::Man, ord: 5, red: true },
+    BaseTile { idx: 17, point: Point { x: Fr::from_raw([0x2953d886dc3c800f, 0x13d4bf591d4480fe, 0x239696717cf923f8, 0x21d0a6d7ff0c5796, ]), y: Fr::from_raw([0x1284a98eacc58626, 0x33bef6b9bded267b, 0x4e57c7baa05f8897, 0x0cac7c2a75c61c76, ]) }, kind: TileKind::Man, ord: 5, red: false },
+    BaseTile { idx: 18, point: Point { x: Fr::from_raw([0x0abab80c00bce54d, 0xe7768345e68b991d, 0x9e46e93b0236d3f9, 0x18346fee0657dfe9, ]), y: Fr::from_raw([0xc2a62864285a13cc, 0x7afad644515da406, 0x34a4828a210a614a, 0x1776bef0b795593c, ]) }, kind: TileKind::Man, ord: 5, red: false },
+    BaseTile { idx: 19, point: Point { x: Fr::from_raw([0x89f5b01880973334, 0x403d4b541f48ad2d, 0x3fbfc0ae285cc637, 0x2d056207ba418278, ]), y: Fr::from_raw([0x7010b007559c4b46, 0x66b19c75a1b7efc9, 0xdcc0402d1b8e6342, 0x1066d534e2afeb19, ]) }, kind: TileKind::Man, ord: 5, red: false },
+    BaseTile { idx: 20, point: Point { x: Fr::from_raw([0x5a9d8b2eec3c2a80, 0x2aed945ac84aeeca, 0xeafbfd899b071864, 0x114191a8f59bb25d, ]), y: Fr::from_raw([0x1594e121cb6539cd, 0x697cb6c30e8d0f4d, 0x30c3f04daee3057e, 0x0f3acb2da850be63, ]) }, kind: TileKind::Man, ord: 6, red: false },
+    BaseTile { idx: 21, point: Point { x: Fr::from_raw([0xd0c284187cf572e5, 0xb3df7950311c6468, 0x9fa9e196d955626b, 0x11817b09cbade219, ]), y: Fr::from_raw([0xac8d8e62cf004f96, 0x411e5be92dae9f18, 0x6c1e04aa4c7f413d, 0x04da17bf6e7426e9, ]) }, kind: TileKind::Man, ord: 6, red: false },
+    BaseTile { idx: 22, point: Point { x: Fr::from_raw([0xc689ffff455e0340, 0xe802a4a5cbc618a1, 0x1e6136cc7c9a85a9, 0x1e0d96081df74aeb, ]), y: Fr::from_raw([0x3d06325e9ea9579b, 0xa76462e984a0cb38, 0x4f0b66983c701dc2, 0x1b95f39e54aad1ce, ]) }, kind: TileKind::Man, ord: 6, red: false },
+    BaseTile { idx: 23, point: Point { x: Fr::from_raw([0xe0ab9ac9dd8a4848, 0xd7fd355a628bd7b6, 0xa9839ec96e63658f, 0x23290460b451f749, ]), y: Fr::from_raw([0x9bbbb7be3f5419ee, 0x4a697e7bda944cf5, 0x6d425fa497695527, 0x083c100367b211a2, ]) }, kind: TileKind::Man, ord: 6, red: false },
+    BaseTile { idx: 24, point: Point { x: Fr::from_raw([0x94ba616b53a42fc9, 0xd94287ee8b6ef02c, 0x5a6fed859eb8caef, 0x238d964da4ccbbb1, ]), y: Fr::from_raw([0x2ec91e4df6eb58ca, 0x6c6fd93e55efe3dd, 0x333405d236eb611c, 0x1192a31cecbc178f, ]) }, kind: TileKind::Man, ord: 7, red: false },
+    BaseTile { idx: 25, point: Point { x: Fr::from_raw([0x2afb9c9c5c0c9d6c, 0xf62b2a97f03a5b82, 0xf599e7b774834681, 0x00f5afb5901b8583, ]), y: Fr::from_raw([0xd380c6814749e201, 0x9b7fc3cb6ae92ad2, 0x1ad1e8bc26393cd2, 0x17264e56a0dbee5f, ]) }, kind: TileKind::Man, ord: 7, red: false },
+    BaseTile { idx: 26, point: Point { x: Fr::from_raw([0xff530608ed713552, 0xfb3992f968cbb1b7, 0x48b1a419e3e6fe09, 0x175a5c53d68aa7db, ]), y: Fr::from_raw([0x55dcf2685d8f753e, 0x64d75f0c779f25a9, 0x142ebb98b689cecd, 0x117b76e86dcd6b32, ]) }, kind: TileKind::Man, ord: 7, red: false },
+    BaseTile { idx: 27, point: Point { x: Fr::from_raw([0xb1b2d07820f3570f, 0x2dd14219c45a4eb0, 0x849706e5c624738e, 0x2c3c757c680bb5fa, ]), y: Fr::from_raw([0x831400bb2cac2b56, 0xd5f25a2f0236fed8, 0x7c1292476044aa4d, 0x10a9ec97c588d100, ]) }, kind: TileKind::Man, ord: 7, red: false },
+    BaseTile { idx: 28, point: Point { x: Fr::from_raw([0x05d8e55704fd3513, 0xd27ed37f7632549b, 0x6f862c6280d030bd, 0x24ce704805ed2613, ]), y: Fr::from_raw([0xc67e49eb7ba5c120, 0x09736e1b533eff5e, 0xe5604ffb21a93f7b, 0x034f81d6460dd8bf, ]) }, kind: TileKind::Man, ord: 8, red: false },
+    BaseTile { idx: 29, point: Point { x: Fr::from_raw([0x0d5430b26df6460b, 0x0ef89c676bd8c893, 0x64d8b0608ef807a3, 0x25353aa744aa8fad, ]), y: Fr::from_raw([0xe3030a425982641e, 0x95620db64a7acc29, 0x0753d462ed8d8f93, 0x254df43b0a67721f, ]) }, kind: TileKind::Man, ord: 8, red: false },
+    BaseTile { idx: 30, point: Point { x: Fr::from_raw([0xd1ba61536cfe5b21, 0xeb3efd0f9f61ab2c, 0x5ecce07773a4a5cd, 0x22a594f9781d4525, ]), y: Fr::from_raw([0xe6b08a0edef6b09d, 0x165af945812010a4, 0x7a2ba8cf7f106e5f, 0x151732fd7ddb39df, ]) }, kind: TileKind::Man, ord: 8, red: false },
+    BaseTile { idx: 31, point: Point { x: Fr::from_raw([0x9b8c2852c079838f, 0x6b97b0f3c45416d9, 0x1faabd2ed0b075ff, 0x0f3ba189f44cda1a, ]), y: Fr::from_raw([0x2bee0d384970b051, 0x5e9c0a27891eb12e, 0xdec92986c58c3135, 0x2501514354fa8a0b, ]) }, kind: TileKind::Man, ord: 8, red: false },
+    BaseTile { idx: 32, point: Point { x: Fr::from_raw([0x98d0135650cb55cc, 0xe70ad79b108890f6, 0xb770669bfc376171, 0x0c8cb7dfdf9d6221, ]), y: Fr::from_raw([0x328743c6b791377f, 0x130a64fd1c919584, 0x6cc3904f03aec9f8, 0x16e2e71ed49677a9, ]) }, kind: TileKind::Man, ord: 9, red: false },
+    BaseTile { idx: 33, point: Point { x: Fr::from_raw([0xb61153d9527219a4, 0x6c41fcf23f0ffd1c, 0x4c5c2b9160ee4859, 0x0696fffa28744a84, ]), y: Fr::from_raw([0xac33fd8b7f5aab42, 0xb64fc1a1b7d2d494, 0xc3cf9c5fc22982cd, 0x2e400c765aac16bf, ]) }, kind: TileKind::Man, ord: 9, red: false },
+    BaseTile { idx: 34, point: Point { x: Fr::from_raw([0x1fef130ce3d83af0, 0x20c0aead9cdf4046, 0xc72a225a463ae3e2, 0x20151414f15ffaa6, ]), y: Fr::from_raw([0xe91c2051fe73d13c, 0x64929104f996b82c, 0xe69e8212465f3fde, 0x2550fa7f27dc8fcc, ]) }, kind: TileKind::Man, ord: 9, red: false },
+    BaseTile { idx: 35, point: Point { x: Fr::from_raw([0x20db82d3d4b92fe2, 0xfefac4df89103714, 0x728ebfb6690ce05e, 0x1e1f8b76f93ec61c, ]), y: Fr::from_raw([0xe51f665cc952eb26, 0x4a15705c13a4e8bb, 0x9f2cbb2cd4669f10, 0x1e6aa987f215df15, ]) }, kind: TileKind::Man, ord: 9, red: false },
+    BaseTile { idx: 36, point: Point { x: Fr::from_raw([0xcc8132f46c4da4d1, 0xf8d6fe18e66e8e02, 0x3dd73523909aa1fd, 0x25b8bcf3a07030bc, ]), y: Fr::from_raw([0x2f7105aec075d030, 0xd118b109c90bb2f4, 0x7470713998f9c76e, 0x2fefaf5e21727141, ]) }, kind: TileKind::Pin, ord: 1, red: false },
+    BaseTile { idx: 37, point: Point { x: Fr::from_raw([0xd13b6e68a15b5718, 0xeac7e20d5c78914c, 0xbbcaa6625c60a18e, 0x215ef5046cf6e4eb, ]), y: Fr::from_raw([0x0ef603eb2516d479, 0xe9d1fc37a4e4ed4f, 0xa97d92fd291f6e5e, 0x1bf758de928b774e, ]) }, kind: TileKind::Pin, ord: 1, red: false },
+    BaseTile { idx: 38, point: Point { x: Fr::from_raw([0xfec2f7871b7bbfa9, 0x3682c83e37a0c380, 0x52c378d546b2761c, 0x20b1308532fc32d5, ]), y: Fr::from_raw([0x17db2cb14d946356, 0xfeafbb1df5bf9b1d, 0xa1a0cd4e984ea618, 0x1625f7cd957462f5, ]) }, kind: TileKind::Pin, ord: 1, red: false },
+    BaseTile { idx: 39, point: Point { x: Fr::from_raw([0xa6c10350f3288116, 0x84f49e9d77cd0555, 0xc0e7b58e04cc9077, 0x076eb39a57fd9a35, ]), y: Fr::from_raw([0x572ab27f6862bb32, 0xd4997077024944a5, 0xe74b2b13c795dfcf, 0x1c84387c982885e4, ]) }, kind: TileKind::Pin, ord: 1, red: false },
+    BaseTile { idx: 40, point: Point { x: Fr::from_raw([0x058a1a83366ed800, 0x888d27e4f95e7652, 0x94a7f709f18d5467, 0x238fc7f1ada5a7c8, ]), y: Fr::from_raw([0x5d8e0272f460a507, 0x5a2fae80698a6f1e, 0x893f25674e51a837, 0x2659824980209073, ]) }, kind: TileKind::Pin, ord: 2, red: false },
+    BaseTile { idx: 41, point: Point { x: Fr::from_raw([0x3e71d7cfd46358e4, 0x2ddfca4d86fb7f31, 0xa7a2694dad42ea4e, 0x25ec1c813168eedf, ]), y: Fr::from_raw([0x7a25d628c67381cd, 0x5cd968931b834bc1, 0xe5cdb9ce7084fd4c, 0x091309a6404fdefc, ]) }, kind: TileKind::Pin, ord: 2, red: false },
+    BaseTile { idx: 42, point: Point { x: Fr::from_raw([0x5d62157198ad26ae, 0x639b4f04f2da81f9, 0x7c89c397e1868229, 0x014fb022cc0b720f, ]), y: Fr::from_raw([0x913e6f7ac726aaa1, 0xf562fc79b91201d1, 0xe7978114ac13ece5, 0x19498ea112a1451c, ]) }, kind: TileKind::Pin, ord: 2, red: false },
+    BaseTile { idx: 43, point: Point { x: Fr::from_raw([0xc9b7f80d247048a4, 0x846310662e8125b3, 0x08e66538bb15ffb3, 0x276d83fd93739a8d, ]), y: Fr::from_raw([0x575d9c3534cace23, 0x43c82bf84e229f89, 0xa35a94c03736337b, 0x20498139ae9a48a0, ]) }, kind: TileKind::Pin, ord: 2, red: false },
+    BaseTile { idx: 44, point: Point { x: Fr::from_raw([0xb840de11ee9c1b66, 0xbd13eb094586ef95, 0xc3c00eac38b9728a, 0x1698d1914f83e925, ]), y: Fr::from_raw([0xcd93561a00ffb92e, 0x9f76258254393a7a, 0x6456cf398ad848de, 0x1b78405409acb34e, ]) }, kind: TileKind::Pin, ord: 3, red: false },
+    BaseTile { idx: 45, point: Point { x: Fr::from_raw([0x9e57b509ea4ce4a0, 0xdb9f39cdf785d3a1, 0x876bad5927481aac, 0x16a09b71bffd337c, ]), y: Fr::from_raw([0x3c59da4b68c2743a, 0xbbdf0b7d16911e7e, 0xd7c2111062551f24, 0x2d3d196356b87c9f, ]) }, kind: TileKind::Pin, ord: 3, red: false },
+    BaseTile { idx: 46, point: Point { x: Fr::from_raw([0xe43298d8d12819d6, 0xc795cccd6161cb6d, 0x0330c1d49c143d44, 0x0fb49058eed4d88f, ]), y: Fr::from_raw([0x44f8da237d5e5f65, 0x29b1d4e588fe61c9, 0x12330275e92073d1, 0x172f1a6c51445848, ]) }, kind: TileKind::Pin, ord: 3, red: false },
+    BaseTile { idx: 47, point: Point { x: Fr::from_raw([0x794d6fdbf6fd0bb8, 0x362abc1f25138389, 0x0af6ffe06d07f429, 0x2ca86cfc9af257be, ]), y: Fr::from_raw([0x7a0d2b45e9718bb6, 0x64ef08ef51a12c92, 0x090d6a39456e49df, 0x3040d527782d349f, ]) }, kind: TileKind::Pin, ord: 3, red: false },
+    BaseTile { idx: 48, point: Point { x: Fr::from_raw([0x36a55a9d51521749, 0xe397aafb233ec950, 0xa9b16f82ca687818, 0x0a381321ade867b6, ]), y: Fr::from_raw([0xcde62b3c67bff1cf, 0x39e1dfec166fcbcd, 0xaa78f3662e0ea47e, 0x186b4495357d802b, ]) }, kind: TileKind::Pin, ord: 4, red: false },
+    BaseTile { idx: 49, point: Point { x: Fr::from_raw([0xcc0d8a8a0416161c, 0x9aba307d362abc4d, 0x6827a5906d058f97, 0x0bef86c35703f973, ]), y: Fr::from_raw([0x845b31449f54c0da, 0x5cbe103eaf531727, 0xec6b584c2b86106b, 0x272c0f3f59403489, ]) }, kind: TileKind::Pin, ord: 4, red: false },
+    BaseTile { idx: 50, point: Point { x: Fr::from_raw([0x64c3e0f6530d0628, 0x3218c14cefa6458d, 0x1a347c11413d97f7, 0x02b132aa486b5781, ]), y: Fr::from_raw([0x337b9b46a5140d5d, 0x5135f950c7264800, 0x42e021884ee22bce, 0x169c8ba9242bd0b9, ]) }, kind: TileKind::Pin, ord: 4, red: false },
+    BaseTile { idx: 51, point: Point { x: Fr::from_raw([0xfdf94d6192932ce9, 0xb80bb53b4409981c, 0xa6ba117e4f3e3625, 0x2441a5009269b547, ]), y: Fr::from_raw([0x67075a8a7c6a477a, 0x1137daf95c534c9e, 0x2096e20990c55242, 0x2800d4f0503d1058, ]) }, kind: TileKind::Pin, ord: 4, red: false },
+    BaseTile { idx: 52, point: Point { x: Fr::from_raw([0x5aa5235583a16d4c, 0xb870da77356f9cd3, 0x4de4589dc290270b, 0x21a39629fb8784ca, ]), y: Fr::from_raw([0x72d3ea87e03a6c3d, 0xd8f3304f14a046b2, 0xb2fb4d4efd8d9a3d, 0x2d92d0fc800bf3a0, ]) }, kind: TileKind::Pin, ord: 5, red: true },
+    BaseTile { idx: 53, point: Point { x: Fr::from_raw([0x7bec06ed8842539c, 0xbe1b97f38c3ab966, 0xb23ef4805322c67c, 0x0a9ab726d949ed83, ]), y: Fr::from_raw([0x3649eb31add63169, 0x6f56b7bd71d8bb31, 0x19fa0e311606df69, 0x11dcf24a6eab944b, ]) }, kind: TileKind::Pin, ord: 5, red: false },
+    BaseTile { idx: 54, point: Point { x: Fr::from_raw([0x82da44ba7610fe33, 0x8b15b427084c7328, 0xa9f90f4ca4fd5f68, 0x0c2b6aeb584e8466, ]), y: Fr::from_raw([0x55c6b1363998719e, 0xd7af24a1a0594356, 0xa8917a472ce3d394, 0x1eea4152617cb474, ]) }, kind: TileKind::Pin, ord: 5, red: false },
+    BaseTile { idx: 55, point: Point { x: Fr::from_raw([0x4391c8772c0e6cfe, 0x27abd069fece25a9, 0x625c2a2b68e21db7, 0x1ed22adb9f5558fe, ]), y: Fr::from_raw([0xfbb30d59ec131a96, 0xadd660b589d174d8, 0x3cfeec42dcff110c, 0x2ee4c8dd06fda431, ]) }, kind: TileKind::Pin, ord: 5, red: false },
+    BaseTile { idx: 56, point: Point { x: Fr::from_raw([0x5c217f40bf385144, 0x61d2c6e0fbc8fb89, 0x679b9a5835dd9b7c, 0x14b6b4c7811b59c4, ]), y: Fr::from_raw([0x208cc34328cb7726, 0xed22e9d10627171d, 0x38e18fc3735e102d, 0x0f2369df5381d928, ]) }, kind: TileKind::Pin, ord: 6, red: false },
+    BaseTile { idx: 57, point: Point { x: Fr::from_raw([0xb8fa7f69e5942fc9, 0x6721e320b87b64d4, 0x06c62f2304830a59, 0x2c4f2c2b473fb02d, ]), y: Fr::from_raw([0x6840acb51cb8eaa2, 0x9f5001bf42d1de94, 0x6a330b3ea27523a6, 0x2b02a6d324c77bf9, ]) }, kind: TileKind::Pin, ord: 6, red: false },
+    BaseTile { idx: 58, point: Point { x: Fr::from_raw([0x812aa50a53b57276, 0xae85e2a82e45b244, 0x0b744ab41bffa7cf, 0x0c24a0c768d0e6ab, ]), y: Fr::from_raw([0xf4032420fe0e0d2a, 0x8ef3f417cfc4259f, 0x61785ae987497ed0, 0x2b30e9f98f2f0842, ]) }, kind: TileKind::Pin, ord: 6, red: false },
+    BaseTile { idx: 59, point: Point { x: Fr::from_raw([0x0ea92b2dfc791538, 0x251ff24c05f4be4f, 0x6a39d271460f7327, 0x2a7cf541a6d3af71, ]), y: Fr::from_raw([0xdd373ec763973d70, 0xfe4f225496f69e55, 0x7743a09612cf12d2, 0x2b9f3c9ed09e6f96, ]) }, kind: TileKind::Pin, ord: 6, red: false },
+    BaseTile { idx: 60, point: Point { x: Fr::from_raw([0x47227a836741369f, 0xc867faf23a9852c9, 0x646dac512a5d6ca8, 0x2b4365de5c7d0ccb, ]), y: Fr::from_raw([0xc1698e427998d659, 0x654ef4869ad8e327, 0xe6c0b81ab7e928e3, 0x097addf1b16c8ea6, ]) }, kind: TileKind::Pin, ord: 7, red: false },
+    BaseTile { idx: 61, point: Point { x: Fr::from_raw([0xa3ab0af686fb64e4, 0xb2462630e2cf8e48, 0x5da1f8033078f04b, 0x08ce3b1a7d7834fb, ]), y: Fr::from_raw([0xde0e5fdbd50fdced, 0x4cec034dd1536c46, 0x9dc53757b00e874e, 0x202e40e1398c6262, ]) }, kind: TileKind::Pin, ord: 7, red: false },
+    BaseTile { idx: 62, point: Point { x: Fr::from_raw([0xa7cffb8840418efe, 0x530df386eb105b9e, 0x263a036ea849661b, 0x0e2c718ba1a3e420, ]), y: Fr::from_raw([0x13aa2da253a8aad8, 0x0e5bad047417ebf5, 0x6d5a0d1859cc8978, 0x0c1a09854f807097, ]) }, kind: TileKind::Pin, ord: 7, red: false },
+    BaseTile { idx: 63, point: Point { x: Fr::from_raw([0x83bd59f89be0d17a, 0x68a637dab4c7c564, 0x814971a0901c7cec, 0x29087a7213378034, ]), y: Fr::from_raw([0x4e59cf62d28fc4cf, 0x987be039beccfdfc, 0xfbedbb52fadaf134, 0x25685b49b56bb777, ]) }, kind: TileKind::Pin, ord: 7, red: false },
+    BaseTile { idx: 64, point: Point { x: Fr::from_raw([0x78e0081ad7150951, 0x6163e799f261a4fb, 0xb709dc1660b74b6e, 0x2bd9b31ccf5e3629, ]), y: Fr::from_raw([0x821d04c4769f099f, 0x3768b7c5e3ea349e, 0x4ca7a4d57afdea7a, 0x28b40a37f9360599, ]) }, kind: TileKind::Pin, ord: 8, red: false },
+    BaseTile { idx: 65, point: Point { x: Fr::from_raw([0x589aa36cfe0f898e, 0xbe834cb910c3b9c1, 0xd7e693524d3a60fa, 0x1deab2cdb7faee04, ]), y: Fr::from_raw([0x48e5b005cbc6d1a3, 0x6a22dd20b3b77c6d, 0x3f8ee26d2f261234, 0x21c579d7be1b39c4, ]) }, kind: TileKind::Pin, ord: 8, red: false },
+    BaseTile { idx: 66, point: Point { x: Fr::from_raw([0x95801212ba78664d, 0x6f36ea98828cab58, 0xcbe0c1b26b50eff3, 0x11965b4e06e13236, ]), y: Fr::from_raw([0xaa70962c4bc1ded2, 0x4bdda0ba4322e463, 0x753d607f64e24956, 0x1119bb75c325f8d5, ]) }, kind: TileKind::Pin, ord: 8, red: false },
+    BaseTile { idx: 67, point: Point { x: Fr::from_raw([0x2e6bd72fd087d3d7, 0x0481a2a03cbdd74b, 0xef573f8cf65642ec, 0x26f7846aa037f022, ]), y: Fr::from_raw([0xa7c4adf1a0ae81c9, 0xe97b435947d72383, 0x73e75e2f84c87847, 0x207ee04a3950082b, ]) }, kind: TileKind::Pin, ord: 8, red: false },
+    BaseTile { idx: 68, point: Point { x: Fr::from_raw([0x8ada5d4431530b06, 0x04f1d109724cab15, 0xc0aea0558b630459, 0x0fffdff5456e1f9f, ]), y: Fr::from_raw([0xbaa0c683fd801467, 0x1ceb895d81f06503, 0xe4754ce1ee6fef35, 0x2e1ce64f16e4ae9a, ]) }, kind: TileKind::Pin, ord: 9, red: false },
+    BaseTile { idx: 69, point: Point { x: Fr::from_raw([0xe383ed74dd59914b, 0xe74c348c0edb8685, 0x62b79ee38ef4bddc, 0x1a661b41c101eff6, ]), y: Fr::from_raw([0x1e9774dcb8aebb2f, 0xca16791cd49c3fa3, 0x2edb96037b5fcadf, 0x123c9fca07b9e915, ]) }, kind: TileKind::Pin, ord: 9, red: false },
+    BaseTile { idx: 70, point: Point { x: Fr::from_raw([0x1dcf6b41104a775e, 0x76c20e19f5e13960, 0xb4bafca8a42eb86e, 0x0a2ed645d5a36bbb, ]), y: Fr::from_raw([0x7747dd11ba1cb03c, 0x36e2e8b6650bf07b, 0xaa0a07b067da53f3, 0x254c579a9b743ed4, ]) }, kind: TileKind::Pin, ord: 9, red: false },
+    BaseTile { idx: 71, point: Point { x: Fr::from_raw([0x5fcb262b30c0e241, 0x50b90a31c391ff4b, 0xc89c413232ea7565, 0x01129a74ae957891, ]), y: Fr::from_raw([0x265f316ba136e862, 0x6b962d1d0c7958c4, 0xcf79a497324ef937, 0x157d35835ab76872, ]) }, kind: TileKind::Pin, ord: 9, red: false },
+    BaseTile { idx: 72, point: Point { x: Fr::from_raw([0x9187a2e38105c11b, 0xe5aa821e639ce098, 0xff73af8fa1bdf624, 0x261e7f49d9664c76, ]), y: Fr::from_raw([0xa29d169c05361929, 0xc2d5b39930c88283, 0x59538b6a22bd0029, 0x08fc232ebea326fa, ]) }, kind: TileKind::Sou, ord: 1, red: false },
+    BaseTile { idx: 73, point: Point { x: Fr::from_raw([0x926a1c8b6a5f0b22, 0x83e16044f11cbf68, 0x573d1872c94dd605, 0x0c52f37912a27f2f, ]), y: Fr::from_raw([0xadc662a816a4404a, 0x4e043cd69a125876, 0xa43f6e91ef559ece, 0x0ba6264343bfde06, ]) }, kind: TileKind::Sou, ord: 1, red: false },
+    BaseTile { idx: 74, point: Point { x: Fr::from_raw([0x0bcba378ff523e0d, 0x63d105118c1f6baf, 0x12d4b54cdf46976a, 0x2b7fa257c1093d80, ]), y: Fr::from_raw([0xc076c04cb2204e39, 0x1a96e3035118b583, 0xf6c358efdb9a7740, 0x1362b72be3b6433e, ]) }, kind: TileKind::Sou, ord: 1, red: false },
+    BaseTile { idx: 75, point: Point { x: Fr::from_raw([0xa3b45f4fcf2b3194, 0xde3cab34e48d5d94, 0x5e138bc1fd94c673, 0x24d341cb76acca75, ]), y: Fr::from_raw([0x15c48d3a8476bc40, 0x49af4d90376f049a, 0xd7ceab8ffbc2825a, 0x0b55d9984a88ccf8, ]) }, kind: TileKind::Sou, ord: 1, red: false },
+    BaseTile { idx: 76, point: Point { x: Fr::from_raw([0x5ea5e74eaa164ef5, 0xe573e2fdf70f05bc, 0xfc7871d5c541b030, 0x0a19fa2502b904f1, ]), y: Fr::from_raw([0x72af3978b717c2b0, 0x3d8dc89088d27e23, 0x74ee6fc6761e355a, 0x2c1d19b55652d87c, ]) }, kind: TileKind::Sou, ord: 2, red: false },
+    BaseTile { idx: 77, point: Point { x: Fr::from_raw([0x0ce24a63fc91a57e, 0xcd4f4846b655deb3, 0x4467c72aa0c08588, 0x0d29fa27e651c31c, ]), y: Fr::from_raw([0xd016950ee8b965c5, 0x3762de5c70112dd9, 0x843b1078a489ae4d, 0x1aeb6a63b19e48dc, ]) }, kind: TileKind::Sou, ord: 2, red: false },
+    BaseTile { idx: 78, point: Point { x: Fr::from_raw([0x1be74f494b566628, 0x9cd45ccbed725419, 0xa710aac316b75611, 0x26dbc72e43fb76d4, ]), y: Fr::from_raw([0x22cb585b476e40dd, 0x93d0be7cb6992b1f, 0x9d87f13f5ffa8c4d, 0x002d6b7bfa09263a, ]) }, kind: TileKind::Sou, ord: 2, red: false },
+    BaseTile { idx: 79, point: Point { x: Fr::from_raw([0x985765bb2c762ca8, 0xaa3e189f693bcdf2, 0xdb86333145103b92, 0x267602dd5f8e5827, ]), y: Fr::from_raw([0xa93c345736247d95, 0x70d8176c58e78fd9, 0x97e948f0bf0bb95e, 0x2046c4c25a514796, ]) }, kind: TileKind::Sou, ord: 2, red: false },
+    BaseTile { idx: 80, point: Point { x: Fr::from_raw([0xfa75e05a1b0dd2d8, 0xc6d3e0b0ac432e44, 0x25a2aa4238905c68, 0x29babe8305016609, ]), y: Fr::from_raw([0xa4f927ccd92d2ab7, 0xc1923ba024b70aa2, 0xeda3a2a409ce2bbe, 0x2d6b9adb741540c8, ]) }, kind: TileKind::Sou, ord: 3, red: false },
+    BaseTile { idx: 81, point: Point { x: Fr::from_raw([0x6dfa4f405f2e4270, 0x6f94825a80bb1a29, 0x0414d2f91dd61a00, 0x2d944eae56f6660d, ]), y: Fr::from_raw([0xe113657a66d1fb34, 0xedb4d2b77eed77be, 0xee86692b35afb9fe, 0x113ec7049cd2047b, ]) }, kind: TileKind::Sou, ord: 3, red: false },
+    BaseTile { idx: 82, point: Point { x: Fr::from_raw([0x40d61c469fe5dd81, 0xf9e15dd5f1aaba47, 0x1628dfd8b2bb56f4, 0x0a6571b1e30c179c, ]), y: Fr::from_raw([0x41f2ce144d6923fc, 0x7bce5c0bfc96f941, 0x753dbc34586ebf86, 0x2d33c02cf6869b5e, ]) }, kind: TileKind::Sou, ord: 3, red: false },
+    BaseTile { idx: 83, point: Point { x: Fr::from_raw([0x7ed266dbf03f0c94, 0x1eb4a41743f84775, 0x9322b29de95cf7a4, 0x20c927370c4951e0, ]), y: Fr::from_raw([0x1eb2807ef7ac8dda, 0xe0f8637bc9572f48, 0x3496738ac600c0e4, 0x21a39e065e22c2a4, ]) }, kind: TileKind::Sou, ord: 3, red: false },
+    BaseTile { idx: 84, point: Point { x: Fr::from_raw([0x868a9ba87863dc32, 0xd4c4a32fc2483f98, 0x4a8f6fbacb776ee7, 0x0a83b725d8416819, ]), y: Fr::from_raw([0xaf4cc09a3f53f5b8, 0x08f254b376535302, 0xde3604a979261673, 0x159ce9bfe3dea135, ]) }, kind: TileKind::Sou, ord: 4, red: false },
+    BaseTile { idx: 85, point: Point { x: Fr::from_raw([0xd6ea714e7597eea9, 0xc04dcbb6089e88a0, 0x18ed0eb34dde7e9f, 0x25955dcbc5e0813b, ]), y: Fr::from_raw([0x0a3c8209477246d3, 0x3b63dd79317e3edc, 0xc4bef84e42b4900b, 0x079b48412fa8607d, ]) }, kind: TileKind::Sou, ord: 4, red: false },
+    BaseTile { idx: 86, point: Point { x: Fr::from_raw([0xecf138fa21dd8fec, 0xbecc4abcccb9fea3, 0x7495bd95e492e0c8, 0x23120a34309290e8, ]), y: Fr::from_raw([0x65eb38615edc986b, 0xb3885f07a9d9edb4, 0x0f33d484b0c9e879, 0x2cf082185416c59e, ]) }, kind: TileKind::Sou, ord: 4, red: false },
+    BaseTile { idx: 87, point: Point { x: Fr::from_raw([0x9b1949743715352b, 0x624d90268bf0fa1c, 0x3db562cf6e995fbc, 0x16c9957d5e54292c, ]), y: Fr::from_raw([0xeb8b5f2ec758d20c, 0xf7b3998d697f78e9, 0x547b05758b2290a4, 0x274d1fdc83ca17a1, ]) }, kind: TileKind::Sou, ord: 4, red: false },
+    BaseTile { idx: 88, point: Point { x: Fr::from_raw([0x3c6d9fefb5ff031a, 0x48f0adfbbfe48d06, 0x7b6430a7128dd001, 0x0a22bc516ec03022, ]), y: Fr::from_raw([0x6cec3c25dd86897c, 0x1875a961813e50b3, 0x4508f168c903a815, 0x16ee816493dad1a1, ]) }, kind: TileKind::Sou, ord: 5, red: true },
+    BaseTile { idx: 89, point: Point { x: Fr::from_raw([0x6e55a2f9dd14c3b1, 0x14013ecbe15bddf4, 0x5f020d70122ba839, 0x2c7abdf88a8623b2, ]), y: Fr::from_raw([0xd604b635dfdd9367, 0xf5a7d48fe3e60380, 0x93b0716253ebd619, 0x09a966d11376e89a, ]) }, kind: TileKind::Sou, ord: 5, red: false },
+    BaseTile { idx: 90, point: Point { x: Fr::from_raw([0x86bbd53c1fd2d5b0, 0x2b3cef6c352aa0b5, 0x032fd133222cbbc3, 0x04a62cf9c1092c46, ]), y: Fr::from_raw([0x3c5adb73ee44c2db, 0xd52cbc5f01ab3325, 0x2d68e8569a78110e, 0x1072d53383c558d7, ]) }, kind: TileKind::Sou, ord: 5, red: false },
+    BaseTile { idx: 91, point: Point { x: Fr::from_raw([0x95c958f734d6fc76, 0x60753a038204d48e, 0xf7dca4f59a3ab695, 0x2476590a89b894e2, ]), y: Fr::from_raw([0x71f753bce684fafc, 0xdfc4543de52da9b2, 0xd79435271f5dd02d, 0x1b5858d524d520c3, ]) }, kind: TileKind::Sou, ord: 5, red: false },
+    BaseTile { idx: 92, point: Point { x: Fr::from_raw([0xcdf843780147614f, 0x911a7161b5be9bd3, 0xcee5fe94ba846f15, 0x26bf6154b8a49c1c, ]), y: Fr::from_raw([0xf034981155346517, 0xc6e2b703a4f0d12a, 0xe4d4e217bb91c3f6, 0x05e6b2492d526a66, ]) }, kind: TileKind::Sou, ord: 6, red: false },
+    BaseTile { idx: 93, point: Point { x: Fr::from_raw([0x4c020a25698c676e, 0x2a543f4a70671e33, 0xde7003783654231b, 0x08066aed6c7cb571, ]), y: Fr::from_raw([0x549e85403c8e7a43, 0x033f6b8d1e2a2587, 0x33a7967009c7a658, 0x263d0e3d1bf3cffc, ]) }, kind: TileKind::Sou, ord: 6, red: false },
+    BaseTile { idx: 94, point: Point { x: Fr::from_raw([0xa3c21547367872fe, 0xc96e9edb6432534f, 0x938f24face64b4de, 0x0d659f2dc538926c, ]), y: Fr::from_raw([0x102bd187ebc55917, 0x52d580b5fa274e9c, 0x1ac63ba80b03ae9d, 0x1858772484512c62, ]) }, kind: TileKind::Sou, ord: 6, red: false },
+    BaseTile { idx: 95, point: Point { x: Fr::from_raw([0xed532dcae56dea00, 0x7baf861f9c0a2dc0, 0x31b732d788da296f, 0x03e98eeeabfb73a4, ]), y: Fr::from_raw([0x764d81cffb1bc897, 0xbb5ff6cd71f186d4, 0x4f5d2fe19f853b72, 0x054bc61e3fd2494e, ]) }, kind: TileKind::Sou, ord: 6, red: false },
+    BaseTile { idx: 96, point: Point { x: Fr::from_raw([0x262574f89f3fc7fd, 0x1aa08dac588d3aa3, 0x940fb69583ab230a, 0x073176786ba9207c, ]), y: Fr::from_raw([0x4111f716f35e0b2e, 0x2bb26d93dc8ec579, 0x19c5357625847540, 0x06426f46756a160f, ]) }, kind: TileKind::Sou, ord: 7, red: false },
+    BaseTile { idx: 97, point: Point { x: Fr::from_raw([0xa6fa84b5b4bce648, 0x2ea475cf242c4296, 0xd2762365ee9f1337, 0x21ae13b6d1e7e1b7, ]), y: Fr::from_raw([0xb0df464a47087cd8, 0x44bc3cb40e7cc195, 0x04827716b8aa5a3b, 0x17fbd5532707c5ac, ]) }, kind: TileKind::Sou, ord: 7, red: false },
+    BaseTile { idx: 98, point: Point { x: Fr::from_raw([0x56945a014e84d8d8, 0xaf50e884e24f954d, 0xd05c307f994395f7, 0x0a90989cacd9a64b, ]), y: Fr::from_raw([0x99d0e2b5ad4d4cc5, 0x33996a6c6691ff39, 0x049038c1068909de, 0x0b84a6247a13c1dc, ]) }, kind: TileKind::Sou, ord: 7, red: false },
+    BaseTile { idx: 99, point: Point { x: Fr::from_raw([0xfc43813be64eb779, 0x4809684a37304f58, 0xd541f8e349def5fb, 0x2450b03c252e6b23, ]), y: Fr::from_raw([0x4255edfc410ba877, 0x34232d1a60f0875b, 0xe43f7d8ca65d3972, 0x0e2bb9f1fd79ea69, ]) }, kind: TileKind::Sou, ord: 7, red: false },
+    BaseTile { idx: 100, point: Point { x: Fr::from_raw([0x1e1be922dc7d3b4b, 0x55147692dea7f804, 0x79505eb5b40aad92, 0x2d1049689ac6a661, ]), y: Fr::from_raw([0xfd0a84a04b0ec2b0, 0x419e8920c0e48ecf, 0x8add033bf60837a9, 0x16693e105a0df98b, ]) }, kind: TileKind::Sou, ord: 8, red: false },
+    BaseTile { idx: 101, point: Point { x: Fr::from_raw([0x5b61347de0c1e905, 0x2aca695a7d8e23f4, 0x14b56f8cafad3b25, 0x0548cf55387fd205, ]), y: Fr::from_raw([0xb6e61b315ff8da28, 0xc70b55d3d6b93e08, 0x4e516af393400ba4, 0x16ffbfe4fa163b5b, ]) }, kind: TileKind::Sou, ord: 8, red: false },
+    BaseTile { idx: 102, point: Point { x: Fr::from_raw([0x5bd738994561a09b, 0xc7536c6d5aa4db48, 0xf95b29d2593d47a3, 0x1a59f913b01b78ac, ]), y: Fr::from_raw([0x2342dba356197826, 0xf328b5d48194f704, 0x1f3bb7b4888d80e3, 0x2b1719c5b934db53, ]) }, kind: TileKind::Sou, ord: 8, red: false },
+    BaseTile { idx: 103, point: Point { x: Fr::from_raw([0x16518af3e239a4c7, 0x63728d1c3245d8a7, 0xa23561a4154b2ea0, 0x0d1e37ef618e996c, ]), y: Fr::from_raw([0xd43dc0eb22fd6028, 0x263b9183baa250ba, 0x98b5012aede7709a, 0x0e79326257dd04f0, ]) }, kind: TileKind::Sou, ord: 8, red: false },
+    BaseTile { idx: 104, point: Point { x: Fr::from_raw([0x78309969be5f3d2a, 0x8d73960c25c39617, 0x17a4fbd48080fef3, 0x2f02ba059b030191, ]), y: Fr::from_raw([0xaacc5b2e7857b661, 0x5e13831c22f5660b, 0x71e97cabef7d9505, 0x0e81ff40e57c4e62, ]) }, kind: TileKind::Sou, ord: 9, red: false },
+    BaseTile { idx: 105, point: Point { x: Fr::from_raw([0xc77dfcbdca93be7c, 0xa0a161f44db6704c, 0xf160aac81d25e589, 0x14f573055bbb5d15, ]), y: Fr::from_raw([0x653cd421e96538d9, 0xf438460351b1a8e2, 0x43212bd8806c7e95, 0x2642641bf28c93cb, ]) }, kind: TileKind::Sou, ord: 9, red: false },
+    BaseTile { idx: 106, point: Point { x: Fr::from_raw([0x0e9c4010d4c1b60e, 0x4d0625b0d26d5021, 0x84635a97c3f8679e, 0x112157ee2ba6a740, ]), y: Fr::from_raw([0x4ad722d90fc114ec, 0xc0c7d8931fbfb3eb, 0x0ed509948d3e8fe2, 0x12f4212ddd9438df, ]) }, kind: TileKind::Sou, ord: 9, red: false },
+    BaseTile { idx: 107, point: Point { x: Fr::from_raw([0x66dd15995a65fccd, 0x09c220bef9e5eba1, 0xd141229d9e1f20c7, 0x08895b893f1a328a, ]), y: Fr::from_raw([0xedbfbf762ad43709, 0xed4f57345a505faf, 0xe135e7049621c1f0, 0x120336bdaa688016, ]) }, kind: TileKind::Sou, ord: 9, red: false },
+    BaseTile { idx: 108, point: Point { x: Fr::from_raw([0xad75309b43218f64, 0x7a076561bdb9cf73, 0x5be5c021516d0ab6, 0x174c70f0a8b41db9, ]), y: Fr::from_raw([0xb746c113b308a4f8, 0xf75418d8d387fc47, 0x2e6d09c4b225f508, 0x2ce69f51714ae5c7, ]) }, kind: TileKind::Zi, ord: 1, red: false },
+    BaseTile { idx: 109, point: Point { x: Fr::from_raw([0x870fcb721d4f155f, 0xb75e3a91b6f80d72, 0x7bb3f2c21258c733, 0x2c54ee977596df03, ]), y: Fr::from_raw([0xa48a08b45e547378, 0x748ba117f110f08f, 0x9441d49f7ed4182c, 0x121efdad98a90f01, ]) }, kind: TileKind::Zi, ord: 1, red: false },
+    BaseTile { idx: 110, point: Point { x: Fr::from_raw([0x832f0046dac848bc, 0x5f9bf68004e0a13d, 0x98bee3605e9059b4, 0x0cea5efb39620b47, ]), y: Fr::from_raw([0xad9b2b97bc249464, 0x809d31e2c4798894, 0xfdc9e94387e0ad9a, 0x0be4dcdf26607b26, ]) }, kind: TileKind::Zi, ord: 1, red: false },
+    BaseTile { idx: 111, point: Point { x: Fr::from_raw([0x6666fa38960de868, 0x18dccf1491320072, 0x4c51ee4932f2043d, 0x1466b81ee40fc45a, ]), y: Fr::from_raw([0x64f00347c2ee090c, 0x2edc3f13e0ce0ed3, 0x61ee0d94ee9a8d96, 0x221ba366258b3081, ]) }, kind: TileKind::Zi, ord: 1, red: false },
+    BaseTile { idx: 112, point: Point { x: Fr::from_raw([0x1c110e3d61e69a2f, 0xbcae074f96427c5d, 0xb96a79a29b96541e, 0x12ee8b7b73f64878, ]), y: Fr::from_raw([0x73c9bc76f1a26c42, 0x20ef9b72bb174aa7, 0x4a2e7989669c1f5c, 0x1f3544c99ef1bbdc, ]) }, kind: TileKind::Zi, ord: 2, red: false },
+    BaseTile { idx: 113, point: Point { x: Fr::from_raw([0xe6b549483da6805c, 0x8a7be3181358534b, 0x91f2e06d705788a3, 0x0730a48e7340e6d4, ]), y: Fr::from_raw([0x238d6c5b65c4fcd5, 0x2a68e3cf7cbabe59, 0x9c3d7a7d31772aca, 0x1c7e10adf32e0a56, ]) }, kind: TileKind::Zi, ord: 2, red: false },
+    BaseTile { idx: 114, point: Point { x: Fr::from_raw([0x820d6d4fcaee61fa, 0x6089d6feeaffc6f9, 0xbead631f3834f0e1, 0x008d4c0040a8f508, ]), y: Fr::from_raw([0x617209fc770b6199, 0xd2cddfd1581faef0, 0x5e7b03c789ba7080, 0x1d58c8a7f55ead15, ]) }, kind: TileKind::Zi, ord: 2, red: false },
+    BaseTile { idx: 115, point: Point { x: Fr::from_raw([0xba5b493abe9e90f3, 0xa9cbf76c29e9c715, 0x3d33aaf6128647a6, 0x0bfb403fa2fdf4f0, ]), y: Fr::from_raw([0x08a3332cd35876e9, 0x39e1fc414aaa9158, 0xd5289d3802448a3c, 0x0866f7fecce22a56, ]) }, kind: TileKind::Zi, ord: 2, red: false },
+    BaseTile { idx: 116, point: Point { x: Fr::from_raw([0x6a8fe8f99a47800d, 0x116fe32533cd4c80, 0xb6e2a01b313b1ad6, 0x01492dece4fec044, ]), y: Fr::from_raw([0x12b3bcc66c16e510, 0x87aabf1030eada43, 0x37bc2ce499b98170, 0x09e0cb3a63ba0f11, ]) }, kind: TileKind::Zi, ord: 3, red: false },
+    BaseTile { idx: 117, point: Point { x: Fr::from_raw([0x01477589d9838ccf, 0x5bcd58a2ca1a1f10, 0xe1123ae2895304f1, 0x21610ef559be1f07, ]), y: Fr::from_raw([0xde31482c0f16bd1b, 0x0f562147b6de3ee8, 0xebdef8a9005d595b, 0x1d8ab2ae32392db1, ]) }, kind: TileKind::Zi, ord: 3, red: false },
+    BaseTile { idx: 118, point: Point { x: Fr::from_raw([0x537b35efdd9e2108, 0x26d52e7f28e8ef65, 0xa7607e1c06e16851, 0x22a57bafaccc2d31, ]), y: Fr::from_raw([0x20bf7464614aabec, 0xb00bb42e3f6a29e9, 0x7cd3776237eb56db, 0x19fdadb5adda6449, ]) }, kind: TileKind::Zi, ord: 3, red: false },
+    BaseTile { idx: 119, point: Point { x: Fr::from_raw([0x095ecba9efa00076, 0x2831e257cd9c6d42, 0x825b04f5a4ac0528, 0x1d6e53c114a14634, ]), y: Fr::from_raw([0xfe98befc2cf9d05d, 0x5221030a399c2a87, 0x3d5e3e9c1ffde9ba, 0x21b9c6e0ddae2a2e, ]) }, kind: TileKind::Zi, ord: 3, red: false },
+    BaseTile { idx: 120, point: Point { x: Fr::from_raw([0x9d365df4708ec516, 0xae1cda9867d71eed, 0x00f2d1de32b021ed, 0x159fd51ffde541a0, ]), y: Fr::from_raw([0x4477be945cac93e8, 0x755091df5f5c09dd, 0xe8ce445dbf50f922, 0x25fd611a01fb6e16, ]) }, kind: TileKind::Zi, ord: 4, red: false },
+    BaseTile { idx: 121, point: Point { x: Fr::from_raw([0xf3f9d69fff89caba, 0xaeba731a86cb0f25, 0xffce36a6872c124a, 0x00cf789fa5cbb61f, ]), y: Fr::from_raw([0x6cee62a23e02dc03, 0x6724568b97f95de9, 0xd6a970c971d22799, 0x1e736eec97d5c537, ]) }, kind: TileKind::Zi, ord: 4, red: false },
+    BaseTile { idx: 122, point: Point { x: Fr::from_raw([0x6eb30f22b5d40239, 0x70a049ca9001f1da, 0x7628a59d4afcb550, 0x10cec1a80ca177ce, ]), y: Fr::from_raw([0xd4722c213497ce61, 0x09085cdf3e33f1c3, 0x3402ac74b4e0c652, 0x28afeca6185ab202, ]) }, kind: TileKind::Zi, ord: 4, red: false },
+    BaseTile { idx: 123, point: Point { x: Fr::from_raw([0x05425120d5249c06, 0x59ac2719588a541a, 0xafff0a00d92f2449, 0x235486f9e39ddf8a, ]), y: Fr::from_raw([0x2261cad4bd09827e, 0x820431ba06273058, 0xa079605452309b74, 0x2437ec73ee862bb8, ]) }, kind: TileKind::Zi, ord: 4, red: false },
+    BaseTile { idx: 124, point: Point { x: Fr::from_raw([0x67473d686a999835, 0x34233e193007326b, 0xa5090d5397bc6343, 0x1d1fa84727731c3b, ]), y: Fr::from_raw([0x35c70fec9ec4b64c, 0x09d96d4060e7030e, 0x4184ebb40eb176b5, 0x2bfa400dfda52a9d, ]) }, kind: TileKind::Zi, ord: 5, red: false },
+    BaseTile { idx: 125, point: Point { x: Fr::from_raw([0xa9f7144936d7b694, 0x20e97788f8ecf16c, 0x2f758ffc56225ddb, 0x18fe83fa609ea21d, ]), y: Fr::from_raw([0xb9ed105a7fecfc1c, 0xc0eae4da146fddc6, 0x89fbed6b2307b7d7, 0x0bfcb5fa527c7950, ]) }, kind: TileKind::Zi, ord: 5, red: false },
+    BaseTile { idx: 126, point: Point { x: Fr::from_raw([0x5719963e798c7d40, 0xc086f103b66a68ef, 0xf702bc963dc85890, 0x2b0f7d4f68c9b73b, ]), y: Fr::from_raw([0x8c9766330a93d3f4, 0x8f21450b9899380f, 0xd91718a008d80c44, 0x1f899a5511d47802, ]) }, kind: TileKind::Zi, ord: 5, red: false },
+    BaseTile { idx: 127, point: Point { x: Fr::from_raw([0x501c8e1b33378025, 0x250f01183cd4b7a6, 0x615510a1671266be, 0x1d8040ccd51adea7, ]), y: Fr::from_raw([0xca8f32b0accab904, 0x7b332e492417c44e, 0xa1851272bb974c0b, 0x13e42bbcf7d088f7, ]) }, kind: TileKind::Zi, ord: 5, red: false },
+    BaseTile { idx: 128, point: Point { x: Fr::from_raw([0xd61de654d8891d0a, 0x16350b1c0a9c2d3d, 0x0be8b70aab30dacd, 0x2612433bc21486aa, ]), y: Fr::from_raw([0x0a66bd8b522f1377, 0xb677560aaf30bb6b, 0x2558f7dc1fcef78b, 0x296b9184830d9040, ]) }, kind: TileKind::Zi, ord: 6, red: false },
+    BaseTile { idx: 129, point: Point { x: Fr::from_raw([0xbcdd020d649744cc, 0x29040a4553fbbdea, 0x4654700b3a929140, 0x2144b5878f73b8e2, ]), y: Fr::from_raw([0x6b6e295d61b5492f, 0x058086a985cb306b, 0x43057f0aedc35d9b, 0x2386d38564e093cf, ]) }, kind: TileKind::Zi, ord: 6, red: false },
+    BaseTile { idx: 130, point: Point { x: Fr::from_raw([0x3a1bb47904550e1e, 0x5f9532e53bba1b75, 0xe9118b356d839e62, 0x2c0987ae3ad7116f, ]), y: Fr::from_raw([0xb7b0683812bb08ab, 0x35fb4cd427c5f183, 0x7eab6f9baa9d0943, 0x1def133efc3ed3b6, ]) }, kind: TileKind::Zi, ord: 6, red: false },
+    BaseTile { idx: 131, point: Point { x: Fr::from_raw([0x15cfb9435edb8775, 0xe22c1db7219f8b70, 0x711c76c79f7f5f52, 0x2cdc1e0db4bcc78c, ]), y: Fr::from_raw([0x4085a1384be100a2, 0x093a012e1f11e32f, 0x003d269290bbd7ed, 0x1e2abbf9249a1b7e, ]) }, kind: TileKind::Zi, ord: 6, red: false },
+    BaseTile { idx: 132, point: Point { x: Fr::from_raw([0x689eb97d983b75a4, 0xba3dbfaaf169c8ef, 0xcbde2ae195beb60f, 0x082e69884b96575d, ]), y: Fr::from_raw([0x71ac1bdba656f7b6, 0xc32e2a0e5f26d028, 0xb00695ea98b1bced, 0x11962211c4fb0617, ]) }, kind: TileKind::Zi, ord: 7, red: false },
+    BaseTile { idx: 133, point: Point { x: Fr::from_raw([0xc43c394741f94ce4, 0x7b863be3eec2be5d, 0x1fc4b1a7958359e7, 0x24cda411a9f77955, ]), y: Fr::from_raw([0xaa2a48570ba61531, 0xaf3f939d708a4226, 0x9d2002cc3b683112, 0x140955105e9ff505, ]) }, kind: TileKind::Zi, ord: 7, red: false },
+    BaseTile { idx: 134, point: Point { x: Fr::from_raw([0xf083e668bf1761a3, 0xa68df3a142e17da0, 0xa4e6a8865950976a, 0x14811fb412e7d2de, ]), y: Fr::from_raw([0x15a9398c325a5781, 0xab0fee53a8c39183, 0x0940e7075da04cc4, 0x13d014702480708f, ]) }, kind: TileKind::Zi, ord: 7, red: false },
+    BaseTile { idx: 135, point: Point { x: Fr::from_raw([0x341d7dcf481e4e7c, 0x72749b4b7ccdae98, 0x68011ff0a236b48d, 0x10e70e0c81f7838b, ]), y: Fr::from_raw([0xabe1627a21373fa4, 0x69b06d061990ef02, 0x4e1f5b2ae6740833, 0x114b8fa8945becf0, ]) }, kind: TileKind::Zi, ord: 7, red: false },
+    BaseTile { idx: 136, point: Point { x: Fr::from_raw([0x41eae970c09d11f8, 0x7e54877ab4a36e77, 0x0dff86b83f67f431, 0x22ae3d9205aeec79, ]), y: Fr::from_raw([0xc636140b9de6e12e, 0x8f7071741b45633b, 0xeae24405b8d635e8, 0x20d618b701e6cccd, ]) }, kind: TileKind::Fa, ord: 1, red: false },
+    BaseTile { idx: 137, point: Point { x: Fr::from_raw([0x0f06d99cbd9ef23e, 0x6104f3ec41ccb1fa, 0xd51a8108f02d5948, 0x0b393f39061312b6, ]), y: Fr::from_raw([0x03ee9b7f70f40f66, 0x1e8e90783c2d1736, 0xa0d5b0929e1aff10, 0x263630fc48a0d4dc, ]) }, kind: TileKind::Fa, ord: 2, red: false },
+    BaseTile { idx: 138, point: Point { x: Fr::from_raw([0x7c5a2e9ac3584563, 0x7dbc3f4d11e9f97c, 0x40ab685f003f4f47, 0x2cfe140f041e7d98, ]), y: Fr::from_raw([0xef9b35983a8a908c, 0x43ac3f010992e921, 0xd5262a7e2a008d2d, 0x26416cb333a9862e, ]) }, kind: TileKind::Fa, ord: 3, red: false },
+    BaseTile { idx: 139, point: Point { x: Fr::from_raw([0xf13d82272d044ed4, 0x8893d39c2dd19c41, 0xbb64354bf087dc05, 0x07d9c881b88d83a2, ]), y: Fr::from_raw([0x3e9703026714c3c2, 0xa62d0614bafa4482, 0x58c934578a28ab5b, 0x288c9a6ac4322e99, ]) }, kind: TileKind::Fa, ord: 4, red: false },
+    BaseTile { idx: 140, point: Point { x: Fr::from_raw([0x81de9dbb48667004, 0xd0ec5223009e0799, 0x3b40c8206c8825c6, 0x11f0fcdf5b8cc321, ]), y: Fr::from_raw([0x9245743fac8244fe, 0x0df6587601c32760, 0xfd4b497e4d25cd84, 0x27cc72e4f433bfc0, ]) }, kind: TileKind::Fa, ord: 5, red: false },
+    BaseTile { idx: 141, point: Point { x: Fr::from_raw([0xa0cd4c6748304141, 0x0fdc2fb7269824d8, 0x0bdaaa2bb3cd2187, 0x1ac093601d19e2c1, ]), y: Fr::from_raw([0x4b956a280c55c7bc, 0xb66093eb1e1a9f7c, 0xeb895047318eccda, 0x050607a482d9384b, ]) }, kind: TileKind::Fa, ord: 6, red: false },
+    BaseTile { idx: 142, point: Point { x: Fr::from_raw([0xb45dfa4a322eba47, 0x29dbd7168d1711ce, 0x4d0bd0478bbbc263, 0x135ee181958de41b, ]), y: Fr::from_raw([0x5c577909a71696b1, 0xe40afbbc3b665b4c, 0xe0c6dea123541271, 0x2576f2efb4543db1, ]) }, kind: TileKind::Fa, ord: 7, red: false },
+    BaseTile { idx: 143, point: Point { x: Fr::from_raw([0xebf81910e59530fc, 0x85bb25b3559cc779, 0x70f3754e75b9239d, 0x1b03bc878ac6d090, ]), y: Fr::from_raw([0xa05c5f9c0dc8e077, 0xa1feeb0462e99188, 0xe1f72509498c12c1, 0x0bfeaa533962caf4, ]) }, kind: TileKind::Fa, ord: 8, red: false },
 ];
 
 pub static TILE_MAP: phf::Map<Fr, BaseTile> = ::phf::Map {
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+use super::Tile;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MeldKind {
+    Chi,
+    Pon,
+    /// Open kan called on a discard.
+    Daiminkan,
+    /// Added kan, a fourth tile put on an open pon.
+    Kakan,
+    /// Closed kan.
+    Ankan,
+}
+
+impl MeldKind {
+    pub fn is_kan(self) -> bool {
+        matches!(self, MeldKind::Daiminkan | MeldKind::Kakan | MeldKind::Ankan)
+    }
+
+    pub fn is_open(self) -> bool {
+        self != MeldKind::Ankan
+    }
+}
+
+/// Seat a tile was called from, relative to the caller.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Relative {
+    /// Next seat, to the right.
+    Shimocha,
+    /// Opposite seat.
+    Toimen,
+    /// Previous seat, to the left.
+    Kamicha,
+}
+
+impl Relative {
+    /// Seat offset from the caller in turn order.
+    pub fn offset(self) -> usize {
+        match self {
+            Relative::Shimocha => 1,
+            Relative::Toimen => 2,
+            Relative::Kamicha => 3,
+        }
+    }
+
+    pub fn from_offset(offset: usize) -> Option<Relative> {
+        match offset {
+            1 => Some(Relative::Shimocha),
+            2 => Some(Relative::Toimen),
+            3 => Some(Relative::Kamicha),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Meld {
+    pub kind: MeldKind,
+    /// All tiles of the meld, sorted.
+    pub tiles: Vec<Tile>,
+    /// The tile taken from another player, `None` for an ankan. For a kakan
+    /// this is the tile originally called for the pon.
+    pub called: Option<Tile>,
+    pub from: Option<Relative>,
+}
+
+impl Meld {
+    /// Lowest tile of the meld.
+    pub fn first(&self) -> Tile {
+        self.tiles[0]
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.kind.is_open()
+    }
+
+    /// Whether `tiles` has the right shape for `kind`.
+    pub fn is_valid_shape(kind: MeldKind, tiles: &[Tile]) -> bool {
+        let Some(&first) = tiles.first() else {
+            return false;
+        };
+        if first.is_bonus() || first == Tile::Joker {
+            return false;
+        }
+        match kind {
+            MeldKind::Chi => {
+                let mut sorted = tiles.to_vec();
+                sorted.sort();
+                sorted.len() == 3
+                    && sorted[0].is_suited()
+                    && sorted.windows(2).all(|w| {
+                        w[0].kind() == w[1].kind() && w[0].ord() + 1 == w[1].ord()
+                    })
+            }
+            MeldKind::Pon => tiles.len() == 3 && tiles.iter().all(|&t| t == first),
+            _ => tiles.len() == 4 && tiles.iter().all(|&t| t == first),
+        }
+    }
+}
@@ -1,11 +1,23 @@
+use std::ops::Range;
+
 use rand::Rng;
-use serde::Serialize;
+use serde::{Serialize, Deserialize};
 use crate::{babyjubjub::{Point, PublicKey}, bn128::Fr, elgamal::MaskedMessage};
 use ff::Field;
-use self::map::{TILE_MAP, TILES};
+use self::map::TILE_MAP;
 
 #[rustfmt::skip]
 pub(crate) mod map;
+pub mod deck;
+pub mod identity;
+pub mod meld;
+pub mod notation;
+pub mod waksman;
+
+pub use deck::{AkaDora, Deck, DeckSpec};
+pub use identity::{Bonus, Honor, ParseTileError, Tile};
+pub use meld::{Meld, MeldKind, Relative};
+pub use notation::{format_hand, parse_hand, parse_hand_with_melds, ParseHandError, ParsedHand};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum TileKind {
@@ -13,7 +25,8 @@ pub enum TileKind {
     Pin,
     Sou,
     Zi,
-    Fa
+    Fa,
+    Joker,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -22,20 +35,29 @@ pub struct BaseTile {
     pub point: Point,
     pub kind: TileKind,
     pub ord: u8,
+    /// Red five, the first copy of each 5m, 5p and 5s.
+    pub red: bool,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PermutationMatrix(Vec<Vec<u8>>);
 
 impl PermutationMatrix {
     pub fn new(n: usize) -> Self {
+        Self::new_in_range(n, 0..n)
+    }
+
+    /// A random permutation of the rows in `range`, identity elsewhere.
+    pub fn new_in_range(n: usize, range: Range<usize>) -> Self {
+        assert!(range.start <= range.end && range.end <= n);
         let mut rng = rand::thread_rng();
         let mut matrix = vec![vec![0; n]; n];
-        for i in 0..n {
-            matrix[i][i] = 1;
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = 1;
         }
-        for i in 0..n {
-            let j = rng.gen_range(0..n);
+        // Fisher-Yates, so every permutation is equally likely
+        for i in range.clone().rev() {
+            let j = rng.gen_range(range.start..=i);
             matrix.swap(i, j);
         }
         Self(matrix)
@@ -49,9 +71,30 @@ impl PermutationMatrix {
             v[i] = temp[row.iter().position(|&x| x == 1).unwrap()];
         }
     }
+
+    /// Whether this is an `n x n` permutation matrix.
+    pub fn is_valid(&self, n: usize) -> bool {
+        let mut seen = vec![false; n];
+        self.0.len() == n
+            && self.0.iter().all(|row| {
+                row.len() == n
+                    && row.iter().all(|&x| x <= 1)
+                    && row.iter().filter(|&&x| x == 1).count() == 1
+                    && !std::mem::replace(&mut seen[row.iter().position(|&x| x == 1).unwrap()], true)
+            })
+    }
+
+    /// The permutation as a vector, where input `i` moves to position `p[i]`.
+    pub fn to_permutation(&self) -> Vec<usize> {
+        let mut permutation = vec![0; self.0.len()];
+        for (i, row) in self.0.iter().enumerate() {
+            permutation[row.iter().position(|&x| x == 1).unwrap()] = i;
+        }
+        permutation
+    }
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ShuffleEncryptResult {
     pub randomness: Vec<Fr>,
     pub tiles: Vec<MaskedMessage>,
@@ -63,33 +106,112 @@ pub fn gen_randomness(n: usize) -> Vec<Fr> {
     (0..n).map(|_| Fr::random(&mut rng)).collect()
 }
 
+/// Plaintext [`DeckSpec::riichi`] deck.
 pub fn get_richi_tiles() -> Vec<MaskedMessage> {
-    TILES[0..136].iter().map(|t| MaskedMessage::new(t.point)).collect()
+    DeckSpec::riichi().plaintext()
+}
+
+/// Plaintext [`DeckSpec::sanma`] deck.
+pub fn get_sanma_tiles() -> Vec<MaskedMessage> {
+    DeckSpec::sanma().plaintext()
 }
 
+/// Plaintext [`DeckSpec::full`] deck.
 pub fn get_full_tiles() -> Vec<MaskedMessage> {
-    TILES.iter().map(|t| MaskedMessage::new(t.point)).collect()
+    DeckSpec::full().plaintext()
 }
 
 pub fn shuffle_encrypt_deck(
     agg_pk: &PublicKey,
     tiles: &[MaskedMessage],
 ) -> ShuffleEncryptResult {
-    let randomness = gen_randomness(136);
+    shuffle_encrypt_range(agg_pk, tiles, 0..tiles.len())
+}
+
+/// Remask and shuffle only `tiles[range]`, e.g. the live wall after a kan
+/// or the dead wall.
+///
+/// The result still covers the whole deck, with zero randomness and the
+/// identity permutation outside `range`, so the tiles there are untouched
+/// and the step can be proven and audited like a full shuffle.
+pub fn shuffle_encrypt_range(
+    agg_pk: &PublicKey,
+    tiles: &[MaskedMessage],
+    range: Range<usize>,
+) -> ShuffleEncryptResult {
+    let n = tiles.len();
+    let mut randomness = vec![Fr::ZERO; n];
+    randomness[range.clone()].copy_from_slice(&gen_randomness(range.len()));
+    let permutation = PermutationMatrix::new_in_range(n, range);
+    let tiles = replay_shuffle(agg_pk, tiles, &randomness, &permutation);
+    ShuffleEncryptResult { randomness, tiles, permutation }
+}
+
+/// Remask `tiles[i]` with `randomness[i]` and permute the result, i.e. redo
+/// a shuffle step from its revealed secrets.
+pub fn replay_shuffle(
+    agg_pk: &PublicKey,
+    tiles: &[MaskedMessage],
+    randomness: &[Fr],
+    permutation: &PermutationMatrix,
+) -> Vec<MaskedMessage> {
     let mut tiles: Vec<MaskedMessage> = tiles
         .iter()
         .zip(randomness.iter())
         .map(|(tile, randomness)| tile.remask(agg_pk, randomness))
         .collect();
-    let permutation = PermutationMatrix::new(136);
     permutation.apply(&mut tiles);
-    ShuffleEncryptResult { randomness, tiles, permutation }
+    tiles
 }
 
 pub fn lookup_tile(x: &Fr) -> Option<BaseTile> {
     TILE_MAP.get(x).copied()
 }
 
+#[test]
+fn test_shuffle_encrypt_deck() {
+    use std::collections::HashMap;
+
+    // every permutation of three rows about as often
+    let mut seen: HashMap<Vec<Vec<u8>>, usize> = HashMap::new();
+    for _ in 0..60000 {
+        *seen.entry(PermutationMatrix::new(3).0).or_default() += 1;
+    }
+    assert_eq!(seen.len(), 6);
+    assert!(seen.values().all(|&count| (9000..11000).contains(&count)));
+
+    // decks of any size, e.g. with flowers and seasons
+    let deck = get_full_tiles();
+    let result = shuffle_encrypt_deck(&PublicKey::default(), &deck);
+    assert_eq!(result.tiles.len(), deck.len());
+    assert!(result.permutation.is_valid(deck.len()));
+}
+
+#[test]
+fn test_shuffle_encrypt_range() {
+    use crate::babyjubjub::SecretKey;
+    use crate::shuffle::ShuffleProof;
+
+    let sks: [SecretKey; 4] = std::array::from_fn(|_| SecretKey::random());
+    let agg_pk = PublicKey::aggregate(sks.iter().map(|sk| sk.public_key()).collect());
+    let deck = shuffle_encrypt_deck(&agg_pk, &get_richi_tiles()).tiles;
+
+    let result = shuffle_encrypt_range(&agg_pk, &deck, 100..122);
+    assert_eq!(result.tiles[..100], deck[..100]);
+    assert_eq!(result.tiles[122..], deck[122..]);
+    assert!(ShuffleProof::prove(&agg_pk, &deck, &result).verify(&agg_pk, &deck, &result.tiles));
+
+    let reveal = |tiles: &[MaskedMessage]| {
+        let mut idx: Vec<usize> = tiles
+            .iter()
+            .map(|t| lookup_tile(&sks.iter().fold(*t, |acc, sk| acc.unmask(sk)).c1.x).unwrap().idx)
+            .collect();
+        idx.sort();
+        idx
+    };
+    assert_eq!(reveal(&result.tiles[100..122]), reveal(&deck[100..122]));
+}
+
 #[test]
 #[ignore]
 fn gen_tile_map() {
@@ -106,8 +228,8 @@ fn gen_tile_map() {
     let literal_fn = |n: Fr| {
         let mut buf = String::from("Fr::from_raw([");
         let raw = n.into_raw();
-        for i in 0..4 {
-            buf.push_str(&format!("0x{:016x}, ", raw[i]));
+        for limb in raw {
+            buf.push_str(&format!("0x{:016x}, ", limb));
         }
         buf.push_str("])");
         buf
@@ -124,19 +246,19 @@ fn gen_tile_map() {
         for ord in 0..9 {
             for dup in 0..4 {
                 let idx = i * 9 * 4 + ord * 4 + dup;
-                writeln!(file, "    BaseTile {{ idx: {idx}, point: Point {{ x: {}, y: {} }}, kind: TileKind::{:?}, ord: {} }},", literals[idx].0, literals[idx].1, kind, ord + 1).unwrap();
+                writeln!(file, "    BaseTile {{ idx: {idx}, point: Point {{ x: {}, y: {} }}, kind: TileKind::{:?}, ord: {}, red: {} }},", literals[idx].0, literals[idx].1, kind, ord + 1, ord == 4 && dup == 0).unwrap();
             }
         }
     }
     for ord in 0..7 {
         for dup in 0..4 {
             let idx = 3 * 9 * 4 + ord * 4 + dup;
-            writeln!(file, "    BaseTile {{ idx: {idx}, point: Point {{ x: {}, y: {} }}, kind: TileKind::Zi, ord: {} }},", literals[idx].0, literals[idx].1, ord + 1).unwrap();
+            writeln!(file, "    BaseTile {{ idx: {idx}, point: Point {{ x: {}, y: {} }}, kind: TileKind::Zi, ord: {}, red: false }},", literals[idx].0, literals[idx].1, ord + 1).unwrap();
         }
     }
     for ord in 0..8 {
         let idx = 3 * 9 * 4 + 7 * 4 + ord;
-        writeln!(file, "    BaseTile {{ idx: {idx}, point: Point {{ x: {}, y: {} }}, kind: TileKind::Fa, ord: {} }},", literals[idx].0, literals[idx].1, ord + 1).unwrap();
+        writeln!(file, "    BaseTile {{ idx: {idx}, point: Point {{ x: {}, y: {} }}, kind: TileKind::Fa, ord: {}, red: false }},", literals[idx].0, literals[idx].1, ord + 1).unwrap();
     }
     writeln!(file, "];\n").unwrap();
 
@@ -151,5 +273,5 @@ fn gen_tile_map() {
         map.build()
     )
     .unwrap();
-    write!(&mut file, ";\n").unwrap();
+    writeln!(&mut file, ";").unwrap();
 }
\ No newline at end of file
@@ -0,0 +1,305 @@
+//! MPSZ hand notation.
+//!
+//! Closed tiles are written as digits followed by their suit, e.g.
+//! `123m456p789s11122z`, with `0` for a red five, `f` for flowers (`1f..4f`)
+//! and seasons (`5f..8f`) and `1j` for a joker. Unicode glyphs are accepted
+//! as well.
+//!
+//! Called melds are written in brackets after the closed tiles, with a
+//! Tenhou style marker letter in front of the called tile:
+//! `c` chi, `p` pon, `m` daiminkan, `k` kakan and `a` ankan. The position of
+//! the marker tells who the tile came from: first is kamicha, last is
+//! shimocha and anything in between is toimen. For example
+//! `123m [c312s] [55p5p] [a7777z]` has a chi of 3s, a pon of 5p from
+//! shimocha and a closed kan of red dragons.
+
+use std::fmt::Display;
+
+use super::deck::AkaDora;
+use super::meld::{Meld, MeldKind, Relative};
+use super::Tile;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseHandError(pub String);
+
+impl Display for ParseHandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid hand notation: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseHandError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsedHand {
+    pub tiles: Vec<Tile>,
+    pub melds: Vec<Meld>,
+    /// Red fives written as `0` in the closed tiles, by suit.
+    pub aka: AkaDora,
+    /// Red fives written as `0` in each of `melds`.
+    pub meld_aka: Vec<u8>,
+}
+
+impl ParsedHand {
+    /// Red fives in the melds, by suit.
+    pub fn melded_aka(&self) -> AkaDora {
+        let mut aka = AkaDora::NONE;
+        for (meld, &red) in self.melds.iter().zip(&self.meld_aka) {
+            if let Some(n) = aka.count_mut(meld.first().kind()) {
+                *n += red;
+            }
+        }
+        aka
+    }
+}
+
+/// Parse closed tiles, in the order written.
+pub fn parse_hand(s: &str) -> Result<Vec<Tile>, ParseHandError> {
+    let parsed = parse_hand_with_melds(s)?;
+    if !parsed.melds.is_empty() {
+        return Err(ParseHandError(format!("unexpected meld in {s:?}")));
+    }
+    Ok(parsed.tiles)
+}
+
+/// Parse closed tiles followed by bracketed melds.
+pub fn parse_hand_with_melds(s: &str) -> Result<ParsedHand, ParseHandError> {
+    let mut parsed = ParsedHand { tiles: vec![], melds: vec![], aka: AkaDora::NONE, meld_aka: vec![] };
+    let mut digits = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '0'..='9' => digits.push(c),
+            'm' | 'p' | 's' | 'z' | 'f' | 'j' if !digits.is_empty() => {
+                for d in digits.drain(..) {
+                    let tile = parse_tile(d, c)?;
+                    if d == '0' {
+                        *parsed.aka.count_mut(tile.kind()).expect("only suited tiles have a 0") += 1;
+                    }
+                    parsed.tiles.push(tile);
+                }
+            }
+            '[' => {
+                let rest = chars.as_str();
+                let end = rest.find(']').ok_or_else(|| ParseHandError(format!("unclosed '[' in {s:?}")))?;
+                let group = &rest[..end];
+                chars = rest[end + 1..].chars();
+                parsed.meld_aka.push(group.matches('0').count() as u8);
+                parsed.melds.push(parse_meld(group)?);
+            }
+            c if c.is_whitespace() && digits.is_empty() => {}
+            c => match Tile::from_char(c) {
+                Some(tile) if digits.is_empty() => parsed.tiles.push(tile),
+                _ => return Err(ParseHandError(format!("unexpected {c:?} in {s:?}"))),
+            },
+        }
+    }
+    if !digits.is_empty() {
+        return Err(ParseHandError(format!("missing suit after {digits:?} in {s:?}")));
+    }
+    Ok(parsed)
+}
+
+fn parse_tile(digit: char, suffix: char) -> Result<Tile, ParseHandError> {
+    let digit = match (digit, suffix) {
+        ('0', 'm' | 'p' | 's') => 5,
+        (d, _) => d.to_digit(10).unwrap() as u8,
+    };
+    Tile::from_digit_suffix(digit, suffix)
+        .ok_or_else(|| ParseHandError(format!("no tile {digit}{suffix}")))
+}
+
+fn parse_meld(group: &str) -> Result<Meld, ParseHandError> {
+    let err = |reason: &str| ParseHandError(format!("{reason} in meld [{group}]"));
+    let suffix = group.chars().last().ok_or_else(|| err("empty"))?;
+    let body = &group[..group.len() - suffix.len_utf8()];
+
+    let mut marker = None;
+    let mut tiles = vec![];
+    for c in body.chars() {
+        if c.is_ascii_digit() {
+            tiles.push(parse_tile(c, suffix)?);
+        } else if marker.is_none() {
+            marker = Some((c, tiles.len()));
+        } else {
+            return Err(err("more than one marker"));
+        }
+    }
+    let (letter, position) = marker.ok_or_else(|| err("no marker"))?;
+    let kind = match letter {
+        'c' => MeldKind::Chi,
+        'p' => MeldKind::Pon,
+        'm' => MeldKind::Daiminkan,
+        'k' => MeldKind::Kakan,
+        'a' => MeldKind::Ankan,
+        _ => return Err(err("unknown marker")),
+    };
+    if !Meld::is_valid_shape(kind, &tiles) {
+        return Err(err("wrong shape"));
+    }
+
+    let (called, from) = match kind {
+        MeldKind::Ankan => (None, None),
+        _ => {
+            let called = *tiles.get(position).ok_or_else(|| err("marker after the last tile"))?;
+            let from = match position {
+                0 => Relative::Kamicha,
+                p if p == tiles.len() - 1 => Relative::Shimocha,
+                _ => Relative::Toimen,
+            };
+            if kind == MeldKind::Chi && from != Relative::Kamicha {
+                return Err(err("chi not called from kamicha"));
+            }
+            (Some(called), Some(from))
+        }
+    };
+    tiles.sort();
+    Ok(Meld { kind, tiles, called, from })
+}
+
+/// Canonical notation: tiles sorted and grouped by suit, e.g. `123m406p11z`,
+/// the first `aka` fives of each suit written as red.
+pub fn format_hand(tiles: &[Tile], aka: AkaDora) -> String {
+    let mut sorted = tiles.to_vec();
+    sorted.sort();
+    let mut aka = aka;
+    let mut out = String::new();
+    for (i, tile) in sorted.iter().enumerate() {
+        push_tile(&mut out, *tile, &mut aka);
+        if sorted.get(i + 1).is_none_or(|next| next.suffix() != tile.suffix()) {
+            out.push(tile.suffix());
+        }
+    }
+    out
+}
+
+/// Push the number of `tile`, `0` while `aka` has red fives of its suit
+/// left.
+fn push_tile(out: &mut String, tile: Tile, aka: &mut AkaDora) {
+    match aka.count_mut(tile.kind()) {
+        Some(n) if *n > 0 && tile.number() == Some(5) => {
+            *n -= 1;
+            out.push('0');
+        }
+        _ => out.push_str(&tile.ord().to_string()),
+    }
+}
+
+/// A meld in brackets, the first `aka` of its fives written as red.
+pub fn format_meld(meld: &Meld, aka: u8) -> String {
+    let letter = match meld.kind {
+        MeldKind::Chi => 'c',
+        MeldKind::Pon => 'p',
+        MeldKind::Daiminkan => 'm',
+        MeldKind::Kakan => 'k',
+        MeldKind::Ankan => 'a',
+    };
+    let mut tiles = meld.tiles.clone();
+    // the called tile follows the marker, a chi is written called tile first
+    let position = match (meld.called, meld.from) {
+        (Some(called), Some(from)) => {
+            let i = tiles.iter().position(|&t| t == called).unwrap_or(0);
+            let called = tiles.remove(i);
+            let position = match from {
+                Relative::Kamicha => 0,
+                Relative::Toimen => 1,
+                Relative::Shimocha => tiles.len(),
+            };
+            tiles.insert(position, called);
+            position
+        }
+        _ => 0,
+    };
+    let mut red = AkaDora::NONE;
+    if let Some(n) = red.count_mut(meld.first().kind()) {
+        *n = aka;
+    }
+    let mut out = String::from("[");
+    for (i, tile) in tiles.iter().enumerate() {
+        if i == position {
+            out.push(letter);
+        }
+        push_tile(&mut out, *tile, &mut red);
+    }
+    out.push(meld.first().suffix());
+    out.push(']');
+    out
+}
+
+/// Canonical notation of closed tiles followed by the melds in call order,
+/// with red fives as in [`ParsedHand`].
+pub fn format_hand_with_melds(tiles: &[Tile], aka: AkaDora, melds: &[Meld], meld_aka: &[u8]) -> String {
+    let mut out = format_hand(tiles, aka);
+    for (i, meld) in melds.iter().enumerate() {
+        out.push(' ');
+        out.push_str(&format_meld(meld, meld_aka.get(i).copied().unwrap_or(0)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tile::Honor;
+
+    #[test]
+    fn test_parse_hand() {
+        let tiles = parse_hand("123m406p789s11122z").unwrap();
+        assert_eq!(tiles.len(), 14);
+        assert_eq!(tiles[..3], [Tile::Man(1), Tile::Man(2), Tile::Man(3)]);
+        assert_eq!(tiles[4], Tile::Pin(5));
+        let parsed = parse_hand_with_melds("005m [p055s]").unwrap();
+        assert_eq!((parsed.aka, parsed.meld_aka.clone()), (AkaDora { man: 2, pin: 0, sou: 0 }, vec![1]));
+        assert_eq!(parsed.melded_aka(), AkaDora { man: 0, pin: 0, sou: 1 });
+        assert_eq!(tiles[13], Tile::Honor(Honor::South));
+        assert_eq!(parse_hand("🀇🀈🀉 1z").unwrap(), parse_hand("123m1z").unwrap());
+
+        assert!(parse_hand("123").is_err());
+        assert!(parse_hand("8z").is_err());
+        assert!(parse_hand("123m [p555s]").is_err());
+    }
+
+    #[test]
+    fn test_format_hand() {
+        let tiles = parse_hand("1z9m1m 55p 1m").unwrap();
+        assert_eq!(format_hand(&tiles, AkaDora::NONE), "119m55p1z");
+        assert_eq!(format_hand(&parse_hand("5f1f").unwrap(), AkaDora::NONE), "15f");
+        assert_eq!(format_hand(&[], AkaDora::NONE), "");
+
+        // red fives survive a round trip
+        for s in ["406p", "0055m055p5s", "123m [p055s] [c406m]"] {
+            let parsed = parse_hand_with_melds(s).unwrap();
+            assert_eq!(format_hand_with_melds(&parsed.tiles, parsed.aka, &parsed.melds, &parsed.meld_aka), s);
+        }
+    }
+
+    #[test]
+    fn test_melds() {
+        let parsed = parse_hand_with_melds("11m [c312s] [55p5p] [5m555p] [a7777z] [k6666s]").unwrap();
+        assert_eq!(parsed.tiles, [Tile::Man(1), Tile::Man(1)]);
+        let [chi, pon, kan, ankan, kakan] = &parsed.melds[..] else {
+            panic!("expected five melds");
+        };
+        assert_eq!(chi.kind, MeldKind::Chi);
+        assert_eq!(chi.tiles, [Tile::Sou(1), Tile::Sou(2), Tile::Sou(3)]);
+        assert_eq!((chi.called, chi.from), (Some(Tile::Sou(3)), Some(Relative::Kamicha)));
+        assert_eq!((pon.kind, pon.from), (MeldKind::Pon, Some(Relative::Shimocha)));
+        assert_eq!((kan.kind, kan.from), (MeldKind::Daiminkan, Some(Relative::Toimen)));
+        assert_eq!((ankan.kind, ankan.called), (MeldKind::Ankan, None));
+        assert_eq!((kakan.kind, kakan.from), (MeldKind::Kakan, Some(Relative::Kamicha)));
+
+        assert_eq!(
+            format_hand_with_melds(&parsed.tiles, parsed.aka, &parsed.melds, &parsed.meld_aka),
+            "11m [c312s] [55p5p] [5m555p] [a7777z] [k6666s]"
+        );
+
+        assert!(parse_hand_with_melds("[p556m]").is_err());
+        assert!(parse_hand_with_melds("[1c23m]").is_err());
+        assert!(parse_hand_with_melds("[c123z]").is_err());
+        assert!(parse_hand_with_melds("[555m]").is_err());
+        assert_eq!(
+            parse_hand_with_melds("11m [c312s").unwrap_err(),
+            ParseHandError("unclosed '[' in \"11m [c312s\"".to_string())
+        );
+    }
+}
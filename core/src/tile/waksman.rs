@@ -0,0 +1,305 @@
+//! Arbitrary-size Waksman (AS-Waksman) permutation networks.
+//!
+//! A permutation of `n` elements is routed through `O(n log n)` two-input
+//! switches instead of a dense `n x n` permutation matrix. Each switch is
+//! either straight or crossed, so a shuffle circuit only needs one boolean
+//! signal per switch and a conditional swap per ciphertext coordinate.
+//!
+//! The network for `n` inputs is built recursively:
+//!
+//! - `n = 1` has no switch, `n = 2` is a single switch.
+//! - otherwise a column of `n / 2` input switches feeds a top subnetwork of
+//!   size `n / 2` and a bottom subnetwork of size `n - n / 2`, followed by a
+//!   column of output switches. When `n` is odd the last input and output are
+//!   wired straight to the bottom subnetwork; when `n` is even the last output
+//!   pair is wired straight, top to `n - 2` and bottom to `n - 1`.
+//!
+//! Switches are flattened in the order
+//! `[input switches][top subnetwork][bottom subnetwork][output switches]`,
+//! which is the order a recursive circuit template consumes them in.
+
+use serde::Serialize;
+
+use crate::{babyjubjub::PublicKey, bn128::Fr, elgamal::MaskedMessage};
+
+use super::{PermutationMatrix, ShuffleEncryptResult};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct WaksmanNetwork {
+    size: usize,
+    switches: Vec<bool>,
+}
+
+impl WaksmanNetwork {
+    /// Number of switches in a network with `n` inputs.
+    pub fn switch_count(n: usize) -> usize {
+        match n {
+            0 | 1 => 0,
+            2 => 1,
+            _ => {
+                let top = n / 2;
+                let bottom = n - top;
+                let outputs = if n.is_multiple_of(2) { top - 1 } else { top };
+                top + outputs + Self::switch_count(top) + Self::switch_count(bottom)
+            }
+        }
+    }
+
+    /// Route `permutation`, where input `i` ends up at output `permutation[i]`.
+    ///
+    /// Panics if `permutation` is not a permutation of `0..n`.
+    pub fn from_permutation(permutation: &[usize]) -> Self {
+        let n = permutation.len();
+        let mut seen = vec![false; n];
+        for &j in permutation {
+            assert!(j < n && !seen[j], "not a permutation");
+            seen[j] = true;
+        }
+        let mut switches = Vec::with_capacity(Self::switch_count(n));
+        route(permutation, &mut switches);
+        debug_assert_eq!(switches.len(), Self::switch_count(n));
+        Self { size: n, switches }
+    }
+
+    /// Route the permutation described by a permutation matrix, so that
+    /// [`WaksmanNetwork::apply`] agrees with [`PermutationMatrix::apply`].
+    pub fn from_matrix(matrix: &PermutationMatrix) -> Self {
+        Self::from_permutation(&matrix.to_permutation())
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn switches(&self) -> &[bool] {
+        &self.switches
+    }
+
+    /// Evaluate the network natively, moving `v[i]` to `v[permutation[i]]`.
+    pub fn apply<T: Copy>(&self, v: &mut [T]) {
+        assert_eq!(v.len(), self.size);
+        let mut switches = self.switches.iter().copied();
+        evaluate(v, &mut switches);
+        debug_assert!(switches.next().is_none());
+    }
+}
+
+/// Which subnetwork an input is routed through.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Side {
+    Top,
+    Bottom,
+}
+
+impl Side {
+    fn flip(self) -> Side {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+        }
+    }
+}
+
+fn route(permutation: &[usize], switches: &mut Vec<bool>) {
+    let n = permutation.len();
+    match n {
+        0 | 1 => return,
+        2 => {
+            switches.push(permutation[0] == 1);
+            return;
+        }
+        _ => {}
+    }
+
+    let half = n / 2;
+    let mut inverse = vec![0; n];
+    for (i, &j) in permutation.iter().enumerate() {
+        inverse[j] = i;
+    }
+
+    // Inputs `2k, 2k + 1` share a switch and so must take different
+    // subnetworks, and likewise for the inputs feeding outputs `2k, 2k + 1`.
+    // The constraint graph has degree at most two, so it is a union of paths
+    // and even cycles and can be two-coloured by walking it.
+    let input_partner = |i: usize| (i ^ 1 < n).then_some(i ^ 1);
+    let output_partner = |j: usize| {
+        let fixed = if n.is_multiple_of(2) { j >= n - 2 } else { j == n - 1 };
+        (!fixed).then_some(j ^ 1)
+    };
+
+    let mut side: Vec<Option<Side>> = vec![None; n];
+    let walk = |start: usize, colour: Side, side: &mut Vec<Option<Side>>| {
+        let mut stack = vec![(start, colour)];
+        while let Some((i, colour)) = stack.pop() {
+            if let Some(existing) = side[i] {
+                debug_assert!(existing == colour, "inconsistent routing");
+                continue;
+            }
+            side[i] = Some(colour);
+            if let Some(p) = input_partner(i) {
+                stack.push((p, colour.flip()));
+            }
+            if let Some(q) = output_partner(permutation[i]) {
+                stack.push((inverse[q], colour.flip()));
+            }
+        }
+    };
+
+    // Fixed wires pin down the colour of their component first.
+    if !n.is_multiple_of(2) {
+        walk(n - 1, Side::Bottom, &mut side);
+        walk(inverse[n - 1], Side::Bottom, &mut side);
+    } else {
+        walk(inverse[n - 2], Side::Top, &mut side);
+        walk(inverse[n - 1], Side::Bottom, &mut side);
+    }
+    for i in 0..n {
+        if side[i].is_none() {
+            walk(i, Side::Top, &mut side);
+        }
+    }
+
+    let mut top = vec![0; half];
+    let mut bottom = vec![0; n - half];
+    for (i, s) in side.iter().enumerate() {
+        match s.unwrap() {
+            Side::Top => top[i / 2] = permutation[i] / 2,
+            Side::Bottom => bottom[i / 2] = permutation[i] / 2,
+        }
+    }
+
+    for k in 0..half {
+        switches.push(side[2 * k] == Some(Side::Bottom));
+    }
+    route(&top, switches);
+    route(&bottom, switches);
+    let outputs = if n.is_multiple_of(2) { half - 1 } else { half };
+    for k in 0..outputs {
+        switches.push(side[inverse[2 * k]] == Some(Side::Bottom));
+    }
+}
+
+fn evaluate<T: Copy>(v: &mut [T], switches: &mut impl Iterator<Item = bool>) {
+    let n = v.len();
+    match n {
+        0 | 1 => return,
+        2 => {
+            if switches.next().unwrap() {
+                v.swap(0, 1);
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    let half = n / 2;
+    let mut top = Vec::with_capacity(half);
+    let mut bottom = Vec::with_capacity(n - half);
+    for k in 0..half {
+        let (a, b) = (v[2 * k], v[2 * k + 1]);
+        if switches.next().unwrap() {
+            top.push(b);
+            bottom.push(a);
+        } else {
+            top.push(a);
+            bottom.push(b);
+        }
+    }
+    if !n.is_multiple_of(2) {
+        bottom.push(v[n - 1]);
+    }
+
+    evaluate(&mut top, switches);
+    evaluate(&mut bottom, switches);
+
+    let outputs = if n.is_multiple_of(2) { half - 1 } else { half };
+    for k in 0..outputs {
+        if switches.next().unwrap() {
+            v[2 * k] = bottom[k];
+            v[2 * k + 1] = top[k];
+        } else {
+            v[2 * k] = top[k];
+            v[2 * k + 1] = bottom[k];
+        }
+    }
+    if n.is_multiple_of(2) {
+        v[n - 2] = top[half - 1];
+        v[n - 1] = bottom[half - 1];
+    } else {
+        v[n - 1] = bottom[half];
+    }
+}
+
+/// Witness for a switch-network shuffle circuit, with the same signal
+/// names as `shuffle_encrypt.circom` except that the dense `permutation`
+/// matrix is replaced by the flattened `switches` bits.
+#[derive(Clone, Debug, Serialize)]
+pub struct WaksmanShuffleInput {
+    pub agg_pk: [Fr; 2],
+    pub in_c0: Vec<[Fr; 2]>,
+    pub in_c1: Vec<[Fr; 2]>,
+    pub in_r: Vec<Fr>,
+    pub switches: Vec<u8>,
+}
+
+impl WaksmanShuffleInput {
+    /// Build the circuit input for the shuffle step that turned `tiles`
+    /// into `result.tiles`.
+    pub fn new(
+        agg_pk: &PublicKey,
+        tiles: &[MaskedMessage],
+        result: &ShuffleEncryptResult,
+    ) -> Self {
+        assert_eq!(tiles.len(), result.randomness.len());
+        let network = WaksmanNetwork::from_matrix(&result.permutation);
+        Self {
+            agg_pk: [agg_pk.x, agg_pk.y],
+            in_c0: tiles.iter().map(|t| [t.c0.x, t.c0.y]).collect(),
+            in_c1: tiles.iter().map(|t| [t.c1.x, t.c1.y]).collect(),
+            in_r: result.randomness.clone(),
+            switches: network.switches.iter().map(|&s| s as u8).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tile::get_richi_tiles;
+    use rand::seq::SliceRandom;
+
+    fn random_permutation(n: usize) -> Vec<usize> {
+        let mut permutation: Vec<usize> = (0..n).collect();
+        permutation.shuffle(&mut rand::thread_rng());
+        permutation
+    }
+
+    #[test]
+    fn test_route_all_sizes() {
+        for n in 0..=40 {
+            for _ in 0..20 {
+                let permutation = random_permutation(n);
+                let network = WaksmanNetwork::from_permutation(&permutation);
+                assert_eq!(network.switches().len(), WaksmanNetwork::switch_count(n));
+                let mut v: Vec<usize> = (0..n).collect();
+                network.apply(&mut v);
+                for (i, &j) in permutation.iter().enumerate() {
+                    assert_eq!(v[j], i);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_permutation_matrix() {
+        let matrix = PermutationMatrix::new(136);
+        let network = WaksmanNetwork::from_matrix(&matrix);
+        assert!(network.switches().len() < 136 * 8);
+
+        let mut expected = get_richi_tiles();
+        let mut tiles = expected.clone();
+        matrix.apply(&mut expected);
+        network.apply(&mut tiles);
+        assert_eq!(tiles, expected);
+    }
+}
@@ -0,0 +1,32 @@
+use wasm_bindgen::prelude::*;
+use zk_mahjong_core::{hand::{shanten, ukeire, waits, Hand}, tile::parse_hand};
+
+fn parse(hand: &str) -> Result<Hand, JsValue> {
+    hand.parse::<Hand>().map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[wasm_bindgen(js_name = "calcShanten")]
+pub fn calc_shanten(hand: &str) -> Result<i8, JsValue> {
+    Ok(shanten(&parse(hand)?))
+}
+
+#[wasm_bindgen(js_name = "calcWaits")]
+pub fn calc_waits(hand: &str) -> Result<JsValue, JsValue> {
+    let waits: Vec<String> = waits(&parse(hand)?).iter().map(|t| t.to_string()).collect();
+    Ok(serde_wasm_bindgen::to_value(&waits).unwrap())
+}
+
+#[wasm_bindgen(js_name = "calcUkeire")]
+pub fn calc_ukeire(hand: &str, visible: &str) -> Result<JsValue, JsValue> {
+    let mut counts = [0; 34];
+    for tile in parse_hand(visible).map_err(|e| JsValue::from_str(&e.to_string()))? {
+        if let Some(i) = tile.index() {
+            counts[i] += 1;
+        }
+    }
+    let ukeire: Vec<(String, u8)> = ukeire(&parse(hand)?, &counts)
+        .into_iter()
+        .map(|(t, n)| (t.to_string(), n))
+        .collect();
+    Ok(serde_wasm_bindgen::to_value(&ukeire).unwrap())
+}
@@ -1,8 +1,6 @@
-#![feature(lazy_cell)]
-#[macro_use] extern crate log;
-
 use wasm_bindgen::prelude::*;
 
+pub mod hand;
 pub mod keys;
 pub mod tiles;
 pub(crate) mod utils;
@@ -16,6 +14,6 @@ pub fn init() {
         LOG_INIT.call_once(|| {
             wasm_logger::init(wasm_logger::Config::default());
         });
-        info!("zkMahjong Initialized");
+        log::info!("zkMahjong Initialized");
     }
 }
\ No newline at end of file
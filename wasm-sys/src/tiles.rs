@@ -1,5 +1,5 @@
 use wasm_bindgen::prelude::*;
-use zk_mahjong_core::{babyjubjub::PublicKey, tile::{get_richi_tiles, lookup_tile, shuffle_encrypt_deck}, bn128::Fr, elgamal::MaskedMessage};
+use zk_mahjong_core::{babyjubjub::PublicKey, tile::{get_richi_tiles, get_sanma_tiles, lookup_tile, shuffle_encrypt_deck, ShuffleEncryptResult}, bn128::Fr, elgamal::MaskedMessage, shuffle::ShuffleProof};
 
 
 #[wasm_bindgen(js_name = "genInitTileSet")]
@@ -7,6 +7,11 @@ pub fn gen_init_tile_set() -> Result<JsValue, JsValue> {
     Ok(serde_wasm_bindgen::to_value(&get_richi_tiles()).unwrap())
 }
 
+#[wasm_bindgen(js_name = "genSanmaTileSet")]
+pub fn gen_sanma_tile_set() -> Result<JsValue, JsValue> {
+    Ok(serde_wasm_bindgen::to_value(&get_sanma_tiles()).unwrap())
+}
+
 #[wasm_bindgen(js_name = "shuffleEncryptDeck")]
 pub fn _shuffle_encrypt_deck(agg_pk: JsValue, tiles: JsValue) -> Result<JsValue, JsValue> {
     let agg_pk: PublicKey = serde_wasm_bindgen::from_value(agg_pk)?;
@@ -16,6 +21,25 @@ pub fn _shuffle_encrypt_deck(agg_pk: JsValue, tiles: JsValue) -> Result<JsValue,
     )
 }
 
+#[wasm_bindgen(js_name = "proveShuffle")]
+pub fn prove_shuffle(agg_pk: JsValue, tiles: JsValue, result: JsValue) -> Result<JsValue, JsValue> {
+    let agg_pk: PublicKey = serde_wasm_bindgen::from_value(agg_pk)?;
+    let tiles: Vec<MaskedMessage> = serde_wasm_bindgen::from_value(tiles)?;
+    let result: ShuffleEncryptResult = serde_wasm_bindgen::from_value(result)?;
+    Ok(
+        serde_wasm_bindgen::to_value(&ShuffleProof::prove(&agg_pk, &tiles, &result)).unwrap()
+    )
+}
+
+#[wasm_bindgen(js_name = "verifyShuffle")]
+pub fn verify_shuffle(agg_pk: JsValue, input: JsValue, output: JsValue, proof: JsValue) -> Result<bool, JsValue> {
+    let agg_pk: PublicKey = serde_wasm_bindgen::from_value(agg_pk)?;
+    let input: Vec<MaskedMessage> = serde_wasm_bindgen::from_value(input)?;
+    let output: Vec<MaskedMessage> = serde_wasm_bindgen::from_value(output)?;
+    let proof: ShuffleProof = serde_wasm_bindgen::from_value(proof)?;
+    Ok(proof.verify(&agg_pk, &input, &output))
+}
+
 #[wasm_bindgen(js_name = "lookupTile")]
 pub fn _lookup_tile(tile: JsValue) -> Result<JsValue, JsValue> {
     let tile: Fr = serde_wasm_bindgen::from_value(tile)?;
@@ -0,0 +1,17 @@
+import {
+    calcShanten as calcShantenWasm,
+    calcWaits as calcWaitsWasm,
+    calcUkeire as calcUkeireWasm,
+} from 'zk-mahjong-wasm-sys'
+
+/** A hand in MPSZ notation, e.g. `123m456p789s1122z [p777z]`. */
+export type HandNotation = string
+
+/** `0` is tenpai, `-1` a complete hand. */
+export const calcShanten = (hand: HandNotation) => calcShantenWasm(hand) as number
+
+export const calcWaits = (hand: HandNotation) => calcWaitsWasm(hand) as string[]
+
+/** Tiles that improve the hand with how many are left unseen. */
+export const calcUkeire = (hand: HandNotation, visible: HandNotation = '') =>
+    calcUkeireWasm(hand, visible) as [string, number][]
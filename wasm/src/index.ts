@@ -1,3 +1,4 @@
 export { zkMahjongInit } from 'zk-mahjong-wasm-sys'
+export * from './hand.js'
 export * from './key.js'
 export * from './tile.js'
@@ -1,6 +1,8 @@
 import {
     genInitTileSet as genInitTileSetWasm,
     shuffleEncryptDeck as shuffleEncryptDeckWasm,
+    proveShuffle as proveShuffleWasm,
+    verifyShuffle as verifyShuffleWasm,
 } from 'zk-mahjong-wasm-sys'
 import {
     MaskedMessage,
@@ -31,3 +33,18 @@ export const shuffleEncryptDeck = (
         ),
     } as ShuffleResult
 }
+
+export type ShuffleProof = unknown
+
+export const proveShuffle = (
+    agg_pk: AggregatedMahjongPubkey,
+    tiles: MaskedMessage[],
+    result: ShuffleResult
+) => proveShuffleWasm(agg_pk.key, tiles, result) as ShuffleProof
+
+export const verifyShuffle = (
+    agg_pk: AggregatedMahjongPubkey,
+    input: MaskedMessage[],
+    output: MaskedMessage[],
+    proof: ShuffleProof
+) => verifyShuffleWasm(agg_pk.key, input, output, proof) as boolean